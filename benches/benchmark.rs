@@ -6,12 +6,11 @@ use criterion::{black_box, Criterion, criterion_group, criterion_main};
 
 fn bench(c: &mut Criterion) {
     let coeffs = black_box([10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0]);
-    let vrs = initial_guess(&coeffs);
-    let options = Options {
-        max_iters: 2000,
-        tol: 1e-14,
-        tol_ind: 1e-15,
-    };
+    let vrs = initial_guess(&coeffs).unwrap();
+    let options = Options::default()
+        .max_iters(2000)
+        .tol(1e-14)
+        .tol_ind(1e-15);
 
     c.bench_function("pbairstow_even", |b| {
         b.iter(|| pbairstow_even(&coeffs, &mut vrs.clone(), &options))
@@ -26,18 +25,30 @@ fn bench(c: &mut Criterion) {
         b.iter(|| pbairstow_autocorr_mt(&coeffs, &mut vrs.clone(), &options))
     });
 
-    let options = Options {
-        max_iters: 2000,
-        tol: 1e-12,
-        tol_ind: 1e-15,
-    };
-    let zs = initial_aberth(&coeffs);
+    let options = Options::default()
+        .max_iters(2000)
+        .tol(1e-12)
+        .tol_ind(1e-15);
+    let zs = initial_aberth(&coeffs).unwrap();
     c.bench_function("aberth", |b| {
         b.iter(|| aberth(&coeffs, &mut zs.clone(), &options))
     });
     c.bench_function("aberth_mt", |b| {
         b.iter(|| aberth_mt(&coeffs, &mut zs.clone(), &options))
     });
+
+    // The truncated interaction sum only pays off in the late sweeps of a high degree polynomial,
+    // and evaluating the polynomial stays O(n) per root, so expect the two to be close.
+    let coeffs = black_box(vec![1.0; 1001]);
+    let zs = initial_aberth(&coeffs).unwrap();
+    let options = Options::default().high_degree(true);
+    c.bench_function("aberth_degree_1000", |b| {
+        b.iter(|| aberth(&coeffs, &mut zs.clone(), &options))
+    });
+    let options = options.interaction_neighbors(Some(16));
+    c.bench_function("aberth_degree_1000_neighbors_16", |b| {
+        b.iter(|| aberth(&coeffs, &mut zs.clone(), &options))
+    });
 }
 
 criterion_group!(benches, bench);
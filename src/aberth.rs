@@ -1,7 +1,12 @@
 #![allow(non_snake_case)]
 
+use super::rootfinding::{IterationScheme, ResidualScaling};
 use super::Options;
+use crate::error::{EvalError, RootError};
+use crate::poly::Polynomial;
+use crate::vector2::Vector2;
 use num::Complex;
+use std::sync::mpsc::Sender;
 // use lds_rs::lds::Circle;
 
 const TWO_PI: f64 = std::f64::consts::TAU;
@@ -40,7 +45,45 @@ pub fn horner_eval_f(coeffs: &[f64], zval: f64) -> f64 {
         .iter()
         .copied()
         .reduce(|res, coeff| res * zval + coeff)
-        .unwrap()
+        .unwrap_or(0.0) // empty: the zero polynomial
+}
+
+/// Compensated sum of Horner evaluations (float)
+///
+/// The `horner_eval_f_sum` function evaluates a polynomial at every point of a grid and accumulates
+/// the values with Kahan's compensated summation, so the rounding error of the total does not grow
+/// with the number of points. This is meant for quadrature-style sums of `P`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `points`: The points at which the polynomial is evaluated.
+///
+/// Returns:
+///
+/// The function `horner_eval_f_sum` returns the compensated sum of `P(x)` over `points`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::horner_eval_f_sum;
+/// use approx_eq::assert_approx_eq;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let total = horner_eval_f_sum(&coeffs, &[1.0, 2.0]);
+///
+/// assert_approx_eq!(total, 576.0 + 18250.0);
+/// ```
+pub fn horner_eval_f_sum(coeffs: &[f64], points: &[f64]) -> f64 {
+    let mut sum = 0.0;
+    let mut comp = 0.0;
+    for &x in points {
+        let y = horner_eval_f(coeffs, x) - comp;
+        let t = sum + y;
+        comp = (t - sum) - y;
+        sum = t;
+    }
+    sum
 }
 
 /// Horner evalution (complex)
@@ -77,7 +120,273 @@ pub fn horner_eval_c(coeffs: &[f64], zval: &Complex<f64>) -> Complex<f64> {
         .iter()
         .map(|coeff| Complex::<f64>::new(*coeff, 0.0))
         .reduce(|res, coeff| res * zval + coeff)
-        .unwrap()
+        .unwrap_or_default() // empty: the zero polynomial
+}
+
+/// Horner evaluation with overflow detection (complex)
+///
+/// The `horner_eval_c_checked` function evaluates the polynomial like `horner_eval_c`, but checks
+/// every intermediate value of Horner's rule. At a point of large magnitude the partial sums grow
+/// like `|z|^k` and overflow to infinity, and the next operations turn it into NaN; this function
+/// reports the first non-finite intermediate value instead of returning it.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zval`: The point at which the polynomial is evaluated.
+///
+/// Returns:
+///
+/// The function `horner_eval_c_checked` returns `P(z)`, or `EvalError::Overflow` when an
+/// intermediate value is not finite.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{horner_eval_c, horner_eval_c_checked};
+/// use bairstow::EvalError;
+/// use num::Complex;
+///
+/// let coeffs = vec![1.0; 81];
+/// let z = Complex::new(1e5, 0.0);
+///
+/// assert!(!horner_eval_c(&coeffs, &z).is_finite());
+/// assert_eq!(horner_eval_c_checked(&coeffs, &z), Err(EvalError::Overflow));
+/// assert_eq!(horner_eval_c_checked(&coeffs, &Complex::new(1.0, 0.0)), Ok(Complex::new(81.0, 0.0)));
+/// ```
+pub fn horner_eval_c_checked(
+    coeffs: &[f64],
+    zval: &Complex<f64>,
+) -> Result<Complex<f64>, EvalError> {
+    let mut res = Complex::<f64>::new(0.0, 0.0);
+    for coeff in coeffs {
+        res = res * zval + coeff;
+        if !res.is_finite() {
+            return Err(EvalError::Overflow);
+        }
+    }
+    Ok(res)
+}
+
+/// The magnitude above which `horner_eval_c_scaled` moves the accumulator into its exponent.
+const SCALED_LIMIT: f64 = 18446744073709551616.0; // 2^64
+
+/// Horner evaluation with a separate exponent (complex)
+///
+/// The `horner_eval_c_scaled` function evaluates the polynomial like `horner_eval_c`, but keeps
+/// the accumulator as a mantissa and a separate binary exponent, a poor man's extended-exponent
+/// number. Whenever the mantissa grows past `2^64` its power of two moves into the exponent, and
+/// every following coefficient is scaled down to match, so `P(z)` stays representable where
+/// `|z|^n` overflows `f64`. Coefficients that fall below the precision of the accumulator
+/// underflow to zero, which does not change the result.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zval`: The point at which the polynomial is evaluated.
+///
+/// Returns:
+///
+/// The function `horner_eval_c_scaled` returns the pair `(m, e)` with `P(z) = m * 2^e`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{horner_eval_c, horner_eval_c_scaled};
+/// use num::Complex;
+///
+/// let coeffs = vec![1.0; 81];
+/// let z = Complex::new(1e5, 0.0);
+/// let (m, e) = horner_eval_c_scaled(&coeffs, &z);
+///
+/// assert!(!horner_eval_c(&coeffs, &z).is_finite());
+/// // P(1e5) = 1e400 * (1 + 1e-5 + 1e-10 + ...)
+/// assert!((m.norm().log10() + e as f64 * 2.0_f64.log10() - 400.0).abs() < 1e-5);
+/// assert_eq!(horner_eval_c_scaled(&coeffs, &Complex::new(1.0, 0.0)), (Complex::new(81.0, 0.0), 0));
+/// ```
+pub fn horner_eval_c_scaled(coeffs: &[f64], zval: &Complex<f64>) -> (Complex<f64>, i32) {
+    let mut res = Complex::<f64>::new(0.0, 0.0);
+    let mut exp = 0;
+    for coeff in coeffs {
+        res = res * zval + coeff * 2.0_f64.powi(-exp);
+        let size = res.re.abs().max(res.im.abs());
+        if size > SCALED_LIMIT && size.is_finite() {
+            let shift = size.log2().floor() as i32;
+            res *= 2.0_f64.powi(-shift);
+            exp += shift;
+        }
+    }
+    (res, exp)
+}
+
+/// Horner evalution with derivative (complex)
+///
+/// The `horner_with_derivative_c` function evaluates a polynomial and its first derivative at a
+/// complex point in a single Horner sweep.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zval`: The complex point at which the polynomial is evaluated.
+///
+/// Returns:
+///
+/// The function `horner_with_derivative_c` returns the pair `(P(zval), P'(zval))`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::horner_with_derivative_c;
+/// use approx_eq::assert_approx_eq;
+/// use num::Complex;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let (px, dpx) = horner_with_derivative_c(&coeffs, &Complex::new(1.0, 2.0));
+///
+/// assert_approx_eq!(px.re, 6080.0);
+/// assert_approx_eq!(px.im, 9120.0);
+/// assert_approx_eq!(dpx.re, 38064.0);
+/// assert_approx_eq!(dpx.im, 4560.0);
+/// ```
+pub fn horner_with_derivative_c(
+    coeffs: &[f64],
+    zval: &Complex<f64>,
+) -> (Complex<f64>, Complex<f64>) {
+    let mut pp = Complex::<f64>::new(0.0, 0.0);
+    let mut pp1 = Complex::<f64>::new(0.0, 0.0);
+    for coeff in coeffs.iter() {
+        pp1 = pp1 * zval + pp;
+        pp = pp * zval + coeff;
+    }
+    (pp, pp1)
+}
+
+/// Logarithmic derivative of a polynomial
+///
+/// The `log_derivative_c` function evaluates `P'(z) / P(z)` from a single fused Horner pass. At a
+/// root, where `|P(z)|` is below the rounding error `f64::EPSILON * sum_k |a_k| |z|^(n-k)` of the
+/// evaluation, the quotient is not meaningful and an infinite value is returned instead.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `z`: The complex point at which the logarithmic derivative is evaluated.
+///
+/// Returns:
+///
+/// The function `log_derivative_c` returns `P'(z) / P(z)`, or `Complex::new(f64::INFINITY, 0.0)`
+/// when `z` is a root to working precision.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::log_derivative_c;
+/// use num::Complex;
+///
+/// // (x - 1)(x - 2): P'/P = 1/(z - 1) + 1/(z - 2)
+/// let coeffs = vec![1.0, -3.0, 2.0];
+///
+/// assert_eq!(log_derivative_c(&coeffs, &Complex::new(3.0, 0.0)), Complex::new(1.5, 0.0));
+/// assert!(log_derivative_c(&coeffs, &Complex::new(1.0, 0.0)).re.is_infinite());
+/// ```
+pub fn log_derivative_c(coeffs: &[f64], z: &Complex<f64>) -> Complex<f64> {
+    let (pp, pp1) = horner_with_derivative_c(coeffs, z);
+    let r = z.norm();
+    let bound = coeffs.iter().fold(0.0, |acc, c| acc * r + c.abs());
+    if pp.norm() <= f64::EPSILON * bound {
+        return Complex::new(f64::INFINITY, 0.0);
+    }
+    pp1 / pp
+}
+
+/// Batch evaluation of a polynomial and its derivative
+///
+/// The `horner_with_derivative_c_many` function computes `(P(z), P'(z))` for every point, like
+/// calling `horner_with_derivative_c` in a loop. The coefficient loop is the outer one, so every
+/// coefficient is loaded once and the accumulators of all points are updated in a contiguous
+/// sweep.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `points`: The complex points at which the polynomial is evaluated.
+///
+/// Returns:
+///
+/// The function `horner_with_derivative_c_many` returns one `(P(z), P'(z))` pair per point.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{horner_with_derivative_c, horner_with_derivative_c_many};
+/// use num::Complex;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let points = [Complex::new(1.0, 2.0), Complex::new(-0.5, 0.25)];
+/// let pairs = horner_with_derivative_c_many(&coeffs, &points);
+///
+/// assert_eq!(pairs[0], horner_with_derivative_c(&coeffs, &points[0]));
+/// ```
+pub fn horner_with_derivative_c_many(
+    coeffs: &[f64],
+    points: &[Complex<f64>],
+) -> Vec<(Complex<f64>, Complex<f64>)> {
+    let mut acc =
+        vec![(Complex::<f64>::new(0.0, 0.0), Complex::<f64>::new(0.0, 0.0)); points.len()];
+    for coeff in coeffs.iter() {
+        for ((pp, pp1), zval) in acc.iter_mut().zip(points.iter()) {
+            *pp1 = *pp1 * zval + *pp;
+            *pp = *pp * zval + coeff;
+        }
+    }
+    acc
+}
+
+/// Sample a polynomial along a segment
+///
+/// The `eval_along_ray` function evaluates `P` at `samples` evenly spaced points of the segment
+/// from `from` to `to`, e.g. to plot the sign changes of the real and imaginary parts, which
+/// bracket the roots near the segment. The point of parameter `t` is `(1 - t) from + t to`, so the
+/// endpoints are hit exactly.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `from`: The start of the segment, at `t = 0`.
+/// * `to`: The end of the segment, at `t = 1`.
+/// * `samples`: The number of samples, including both endpoints.
+///
+/// Returns:
+///
+/// The function `eval_along_ray` returns the `(t, P(z(t)))` pairs in order of increasing `t`. A
+/// single sample is taken at `from`, and no samples give an empty vector.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::eval_along_ray;
+/// use num::Complex;
+///
+/// // x^2 - 2 changes sign between 1 and 2
+/// let values = eval_along_ray(&[1.0, 0.0, -2.0], Complex::new(1.0, 0.0), Complex::new(2.0, 0.0), 5);
+///
+/// assert_eq!(values.len(), 5);
+/// assert_eq!(values[2], (0.5, Complex::new(0.25, 0.0)));
+/// assert!(values[0].1.re < 0.0 && values[4].1.re > 0.0);
+/// ```
+pub fn eval_along_ray(
+    coeffs: &[f64],
+    from: Complex<f64>,
+    to: Complex<f64>,
+    samples: usize,
+) -> Vec<(f64, Complex<f64>)> {
+    let step = 1.0 / samples.saturating_sub(1).max(1) as f64;
+    (0..samples)
+        .map(|k| {
+            let t = k as f64 * step;
+            (t, horner_eval_c(coeffs, &(from * (1.0 - t) + to * t)))
+        })
+        .collect()
 }
 
 /// Initial guess for Aberth's method
@@ -94,7 +403,7 @@ pub fn horner_eval_c(coeffs: &[f64], zval: &Complex<f64>) -> Complex<f64> {
 /// Returns:
 /// 
 /// The function `initial_aberth` returns a vector of `Complex<f64>` values, which represent the initial
-/// guesses for the roots of a polynomial.
+/// guesses for the roots of a polynomial, or the error of `poly::degree` for invalid coefficients.
 ///
 /// # Examples:
 ///
@@ -104,189 +413,5227 @@ pub fn horner_eval_c(coeffs: &[f64], zval: &Complex<f64>) -> Complex<f64> {
 /// use approx_eq::assert_approx_eq;
 ///
 /// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
-/// let z0s = initial_aberth(&coeffs);
+/// let z0s = initial_aberth(&coeffs).unwrap();
 ///
 /// assert_approx_eq!(z0s[0].re, 0.6116610247366323);
 /// assert_approx_eq!(z0s[0].im, 0.6926747514925476);
 /// ```
-pub fn initial_aberth(coeffs: &[f64]) -> Vec<Complex<f64>> {
-    let degree = coeffs.len() - 1;
-    let center = -coeffs[1] / (coeffs[0] * degree as f64);
-    let Pc = horner_eval_f(coeffs, center);
-    let re = Complex::<f64>::new(-Pc, 0.0).powf(1.0 / degree as f64);
-    let k = TWO_PI / (degree as f64);
-    let mut z0s = vec![];
-    for idx in 0..degree {
-        let theta = k * (0.25 + idx as f64);
-        let z0 = center + re * Complex::<f64>::new(theta.cos(), theta.sin());
-        z0s.push(z0);
-    }
-    z0s
+pub fn initial_aberth(coeffs: &[f64]) -> Result<Vec<Complex<f64>>, RootError> {
+    initial_aberth_with(coeffs, None, 0.25)
 }
 
-/// Aberth's method
-/// 
-/// The `aberth` function implements Aberth's method for finding roots of a polynomial.
-/// 
-/// <pre>
-///                 P ⎛z ⎞
-///      new          ⎝ i⎠
-///     z    = z  - ───────
-///      i      i   P' ⎛z ⎞
-///                    ⎝ i⎠
-/// where
-///                           degree
-///                         _____
-///                         ╲
-///                          ╲    P ⎛z ⎞
-///                           ╲     ⎝ i⎠
-///     P' ⎛z ⎞ = P  ⎛z ⎞ -   ╱   ───────
-///        ⎝ i⎠    1 ⎝ i⎠    ╱    z  - z
-///                         ╱      i    j
-///                         ‾‾‾‾‾
-///                         j ≠ i
-/// </pre>
+/// Initial guess for Aberth's method with a tunable circle
+///
+/// The `initial_aberth_with` function places the initial guesses evenly on a circle around the
+/// centroid of the roots, like `initial_aberth`, but lets the caller choose the radius of the circle
+/// and the phase offset of the first guess. A different phase can break symmetry-induced stalls.
 ///
 /// Arguments:
-/// 
-/// * `coeffs`: The `coeffs` parameter is a slice of `f64` values representing the coefficients of a
-/// polynomial. The coefficients are ordered from highest degree to lowest degree. For example, if the
-/// polynomial is `3x^2 + 2x + 1`, the `coeffs` slice would
-/// * `zs`: A vector of complex numbers representing the initial guesses for the roots of the
-/// polynomial.
-/// * `options`: The `options` parameter is an instance of the `Options` struct, which contains the
-/// following fields:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `radius`: The radius of the circle; `None` uses `|P(center)|^(1/degree)` as `initial_aberth`
+///   does, or `poly::companion_norm_bound` when the center is itself a root.
+/// * `phase`: The angular offset of the first guess, as a fraction of the spacing between guesses.
+///   `initial_aberth` uses `0.25`.
+///
+/// Returns:
+///
+/// The function `initial_aberth_with` returns `degree` initial guesses, or the error of
+/// `poly::degree` for invalid coefficients.
 ///
 /// # Examples:
 ///
 /// ```
-/// use bairstow::rootfinding::Options;
-/// use bairstow::aberth::{initial_aberth, aberth};
+/// use bairstow::aberth::{initial_aberth, initial_aberth_with};
 ///
 /// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
-/// let mut zrs = initial_aberth(&coeffs);
-/// let (niter, _found) = aberth(&coeffs, &mut zrs, &Options::default());
 ///
-/// assert_eq!(niter, 5);
+/// assert_eq!(initial_aberth_with(&coeffs, None, 0.25).unwrap(), initial_aberth(&coeffs).unwrap());
 /// ```
-pub fn aberth(coeffs: &[f64], zs: &mut Vec<Complex<f64>>, options: &Options) -> (usize, bool) {
-    let m_rs = zs.len();
-    let degree = coeffs.len() - 1; // degree, assume even
-    let mut converged = vec![false; m_rs];
-    let mut pb = vec![0.0; degree];
-    for i in 0..degree {
-        pb[i] = coeffs[i] * (degree - i) as f64;
-    }
-    for niter in 0..options.max_iters {
-        let mut tol = 0.0;
-        let mut rx = vec![];
+pub fn initial_aberth_with(
+    coeffs: &[f64],
+    radius: Option<f64>,
+    phase: f64,
+) -> Result<Vec<Complex<f64>>, RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    let mut z0s = vec![Complex::<f64>::new(0.0, 0.0); degree];
+    fill_circle(coeffs, radius, phase, &mut z0s);
+    Ok(z0s)
+}
 
-        for i in 0..m_rs {
-            if converged[i] {
-                continue;
-            }
-            let mut job = || {
-                let zi = &zs[i];
-                let pp = horner_eval_c(coeffs, zi);
-                let tol_i = pp.l1_norm(); // ???
-                if tol_i < 1e-15 {
-                    converged[i] = true;
-                    rx.push(tol_i);
-                }
-                let mut pp1 = horner_eval_c(&pb, zi);
-                for (_, zj) in zs.iter().enumerate().filter(|t| t.0 != i) {
-                    pp1 -= pp / (zi - zj);
-                }
-                zs[i] -= pp / pp1; // Gauss-Seidel fashion
-                rx.push(tol_i);
-            };
-            job();
-        }
-        for result in rx.iter() {
-            if tol < *result {
-                tol = *result;
-            }
-        }
-        if tol < options.tol {
-            return (niter, true);
-        }
+/// Initial guess for Aberth's method into a caller-provided buffer
+///
+/// The `initial_aberth_slice` function writes the same guesses as `initial_aberth` into `out`
+/// without allocating. Together with `aberth_into`, it makes solving a stream of polynomials of one
+/// degree allocation-free once the buffers exist.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `out`: The buffer receiving the guesses; one per degree.
+///
+/// Returns:
+///
+/// The function `initial_aberth_slice` returns `RootError::BufferLength` when `out` does not match
+/// the degree of `coeffs`, or the error of `poly::degree` for invalid coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{initial_aberth, initial_aberth_slice};
+/// use num::Complex;
+///
+/// let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zs = [Complex::new(0.0, 0.0); 8];
+/// initial_aberth_slice(&coeffs, &mut zs).unwrap();
+///
+/// assert_eq!(zs.to_vec(), initial_aberth(&coeffs).unwrap());
+/// assert!(initial_aberth_slice(&coeffs, &mut zs[..7]).is_err());
+/// ```
+pub fn initial_aberth_slice(coeffs: &[f64], out: &mut [Complex<f64>]) -> Result<(), RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    if out.len() != degree {
+        return Err(RootError::BufferLength {
+            name: "out",
+            expected: degree,
+            found: out.len(),
+        });
     }
-    (options.max_iters, false)
+    fill_circle(coeffs, None, 0.25, out);
+    Ok(())
 }
 
-/// Multi-threading Aberth's method
-/// 
-/// The `aberth_mt` function in Rust implements the multi-threaded Aberth's method for root finding.
-/// 
+/// Initial guess for Aberth's method on the stack
+///
+/// The `initial_aberth_small` function returns the same guesses as `initial_aberth` in a `SmallVec`,
+/// which needs no heap allocation up to degree 16. Requires the `smallvec` feature.
+///
 /// Arguments:
-/// 
-/// * `coeffs`: The `coeffs` parameter is a slice of `f64` values representing the coefficients of a
-/// polynomial. The polynomial is defined by the equation:
-/// * `zs`: A mutable reference to a vector of Complex numbers. These numbers represent the initial
-/// guesses for the roots of the polynomial equation.
-/// * `options`: The `options` parameter is an instance of the `Options` struct, which contains the
-/// following fields:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `initial_aberth_small` returns `degree` initial guesses, or the error of
+/// `poly::degree` for invalid coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{initial_aberth, initial_aberth_small};
+///
+/// let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let zs = initial_aberth_small(&coeffs).unwrap();
+///
+/// assert!(!zs.spilled());
+/// assert_eq!(zs.to_vec(), initial_aberth(&coeffs).unwrap());
+/// ```
+#[cfg(feature = "smallvec")]
+pub fn initial_aberth_small(
+    coeffs: &[f64],
+) -> Result<smallvec::SmallVec<[Complex<f64>; 16]>, RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    let mut z0s = smallvec::smallvec![Complex::<f64>::new(0.0, 0.0); degree];
+    fill_circle(coeffs, None, 0.25, &mut z0s);
+    Ok(z0s)
+}
+
+/// Initial guess for Aberth's method in parallel
+///
+/// The `initial_aberth_par` function computes the same guesses as `initial_aberth`, but evaluates
+/// the trigonometry of every guess on the Rayon thread pool. The guesses are independent once the
+/// circle is known, so for degrees in the hundreds this pairs with `aberth_mt` into a fully
+/// parallel pipeline.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `initial_aberth_par` returns `degree` initial guesses in the order of
+/// `initial_aberth`, or the error of `poly::degree` for invalid coefficients.
 ///
 /// # Examples:
 ///
 /// ```
 /// use bairstow::rootfinding::Options;
-/// use bairstow::aberth::{initial_aberth, aberth_mt};
+/// use bairstow::aberth::{aberth_mt, initial_aberth, initial_aberth_par};
 ///
 /// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
-/// let mut zrs = initial_aberth(&coeffs);
-/// let (niter, _found) = aberth_mt(&coeffs, &mut zrs, &Options::default());
+/// let mut zrs = initial_aberth_par(&coeffs).unwrap();
 ///
-/// assert_eq!(niter, 7);
+/// assert_eq!(zrs, initial_aberth(&coeffs).unwrap());
+/// let (_niter, found) = aberth_mt(&coeffs, &mut zrs, &Options::default());
+/// assert!(found);
 /// ```
-pub fn aberth_mt(coeffs: &[f64], zs: &mut Vec<Complex<f64>>, options: &Options) -> (usize, bool) {
+pub fn initial_aberth_par(coeffs: &[f64]) -> Result<Vec<Complex<f64>>, RootError> {
     use rayon::prelude::*;
 
-    let m_rs = zs.len();
-    let degree = coeffs.len() - 1; // degree, assume even
-    let mut pb = vec![0.0; degree];
-    for i in 0..degree {
-        pb[i] = coeffs[i] * (degree - i) as f64;
+    let degree = crate::poly::degree(coeffs)?;
+    let (center, re) = aberth_circle(coeffs, degree, None);
+    let k = TWO_PI / (degree as f64);
+    let mut z0s = vec![Complex::<f64>::new(0.0, 0.0); degree];
+    z0s.par_iter_mut().enumerate().for_each(|(idx, z0)| {
+        let theta = k * (0.25 + idx as f64);
+        *z0 = center + re * Complex::<f64>::new(theta.cos(), theta.sin());
+    });
+    Ok(z0s)
+}
+
+/// The `fill_circle` function spreads `out.len()` guesses evenly on the circle of
+/// `initial_aberth_with`; `coeffs` must have passed `poly::degree`.
+fn fill_circle(coeffs: &[f64], radius: Option<f64>, phase: f64, out: &mut [Complex<f64>]) {
+    let degree = out.len();
+    let (center, re) = aberth_circle(coeffs, degree, radius);
+    let k = TWO_PI / (degree as f64);
+    for (idx, z0) in out.iter_mut().enumerate() {
+        let theta = k * (phase + idx as f64);
+        *z0 = center + re * Complex::<f64>::new(theta.cos(), theta.sin());
     }
-    let mut zsc = vec![Complex::default(); m_rs];
-    let mut converged = vec![false; m_rs];
+}
 
-    for niter in 0..options.max_iters {
-        let mut tol = 0.0;
-        zsc.copy_from_slice(zs);
+/// The `aberth_circle` function returns the center and the (complex) radius of the circle on which
+/// `fill_circle` spreads `degree` guesses.
+fn aberth_circle(coeffs: &[f64], degree: usize, radius: Option<f64>) -> (f64, Complex<f64>) {
+    let center = -coeffs[1] / (coeffs[0] * degree as f64);
+    let re = match radius {
+        Some(radius) => Complex::<f64>::new(radius, 0.0),
+        None => {
+            let Pc = horner_eval_f(coeffs, center);
+            if Pc == 0.0 {
+                // the centroid is a root, so the circle would collapse onto it
+                Complex::<f64>::new(crate::poly::companion_norm_bound(coeffs), 0.0)
+            } else {
+                Complex::<f64>::new(-Pc, 0.0).powf(1.0 / degree as f64)
+            }
+        }
+    };
+    (center, re)
+}
 
-        let tol_i = zs
-            .par_iter_mut()
-            .zip(converged.par_iter_mut())
+/// The angle in radians by which `initial_aberth_annuli` turns every circle: no rational multiple
+/// of `pi`, so no guess lands on the real axis, where a real polynomial would keep it real.
+const ANNULI_PHASE: f64 = 0.7;
+
+/// Initial guess for Aberth's method on annuli
+///
+/// The `initial_aberth_annuli` function estimates the root magnitudes from the upper convex hull
+/// of the points `(k, log|a_k|)`, where `a_k` is the coefficient of `x^k` (the Newton polygon). An
+/// edge of the hull from `x^i` to `x^j` predicts `j - i` roots of magnitude
+/// `(|a_i| / |a_j|)^(1/(j-i))`, which are spread evenly on that circle. Unlike `initial_aberth`,
+/// which puts every guess on one circle, this suits polynomials with roots at very different
+/// scales. Roots at the origin (trailing zero coefficients) are seeded on a tiny circle.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `initial_aberth_annuli` returns `degree` initial guesses, or the error of
+/// `poly::degree` for invalid coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::initial_aberth_annuli;
+///
+/// // (x - 0.01)(x - 100): one guess near each of the circles of radius 0.01 and 100
+/// let z0s = initial_aberth_annuli(&[1.0, -100.01, 1.0]).unwrap();
+///
+/// assert!((z0s[0].norm() - 0.01).abs() < 1e-5);
+/// assert!((z0s[1].norm() - 100.0).abs() < 1e-1);
+/// ```
+pub fn initial_aberth_annuli(coeffs: &[f64]) -> Result<Vec<Complex<f64>>, RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    // upper hull of (power, log|a|), powers ascending
+    let mut hull: Vec<(usize, f64)> = vec![];
+    for power in 0..=degree {
+        let a = coeffs[degree - power].abs();
+        if a == 0.0 {
+            continue;
+        }
+        let pt = (power, a.ln());
+        while hull.len() >= 2 {
+            let (p1, l1) = hull[hull.len() - 2];
+            let (p2, l2) = hull[hull.len() - 1];
+            let cross = (p2 - p1) as f64 * (pt.1 - l1) - (l2 - l1) * (pt.0 - p1) as f64;
+            if cross < 0.0 {
+                break;
+            }
+            hull.pop();
+        }
+        hull.push(pt);
+    }
+    let mut z0s = vec![];
+    let mut annuli: Vec<(usize, usize, f64)> = hull
+        .windows(2)
+        .map(|w| {
+            let m = w[1].0 - w[0].0;
+            (w[0].0, m, ((w[0].1 - w[1].1) / m as f64).exp())
+        })
+        .collect();
+    let zeros = hull[0].0;
+    if zeros > 0 {
+        let tiny = annuli.first().map_or(1.0, |a| a.2) * f64::EPSILON;
+        annuli.insert(0, (0, zeros, tiny));
+    }
+    for (start, m, radius) in annuli {
+        for idx in 0..m {
+            let theta =
+                TWO_PI * (idx as f64 / m as f64 + start as f64 / degree as f64) + ANNULI_PHASE;
+            z0s.push(Complex::from_polar(radius, theta));
+        }
+    }
+    Ok(z0s)
+}
+
+/// Random initial guess for Aberth's method
+///
+/// The `initial_random` function draws `degree` initial guesses uniformly from the disk of radius
+/// `cauchy_bound(coeffs)`, which contains every root. The generator is a `StdRng` seeded with
+/// `seed`, so the same seed always yields the same guesses.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `seed`: The seed of the random number generator.
+///
+/// Returns:
+///
+/// The function `initial_random` returns `degree` initial guesses, or the error of `poly::degree`
+/// for invalid coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::initial_random;
+/// use bairstow::poly::cauchy_bound;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let z0s = initial_random(&coeffs, 42).unwrap();
+///
+/// assert_eq!(z0s.len(), 8);
+/// assert!(z0s.iter().all(|z| z.norm() <= cauchy_bound(&coeffs)));
+/// ```
+pub fn initial_random(coeffs: &[f64], seed: u64) -> Result<Vec<Complex<f64>>, RootError> {
+    use rand::{Rng, SeedableRng};
+
+    let degree = crate::poly::degree(coeffs)?;
+    let radius = crate::poly::cauchy_bound(coeffs);
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    Ok((0..degree)
+        .map(|_| {
+            // sqrt makes the points uniform over the area of the disk
+            let r = radius * rng.gen::<f64>().sqrt();
+            Complex::from_polar(r, TWO_PI * rng.gen::<f64>())
+        })
+        .collect())
+}
+
+/// Real initial guess for Aberth's method
+///
+/// The `initial_aberth_real` function places `degree` real guesses at the Chebyshev nodes of the
+/// Cauchy interval `[-cauchy_bound(coeffs), cauchy_bound(coeffs)]`, which contains every real root.
+/// The nodes are distinct and denser towards the ends of the interval. They are meant for
+/// `aberth_real`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `initial_aberth_real` returns `degree` real initial guesses in increasing order, or
+/// the error of `poly::degree` for invalid coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::initial_aberth_real;
+///
+/// // (x - 1)(x - 2): Cauchy bound 4
+/// let x0s = initial_aberth_real(&[1.0, -3.0, 2.0]).unwrap();
+///
+/// assert_eq!(x0s.len(), 2);
+/// assert!(x0s[0] < x0s[1]);
+/// assert!(x0s.iter().all(|x| x.abs() < 4.0));
+/// ```
+pub fn initial_aberth_real(coeffs: &[f64]) -> Result<Vec<f64>, RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    let radius = crate::poly::cauchy_bound(coeffs);
+    Ok((0..degree)
+        .map(|k| -radius * (std::f64::consts::PI * (k as f64 + 0.5) / degree as f64).cos())
+        .collect())
+}
+
+/// Initial guess for Aberth's method from a truncated approximation
+///
+/// The `initial_from_approx` function approximates the polynomial by its `approx_degree + 1`
+/// leading coefficients and solves that polynomial with `find_roots`. When `m = approx_degree`
+/// roots are much larger than the rest, the truncation `a_0 x^m + ... + a_m` approximates
+/// `P(x) / x^(n-m)` near the large roots, so its roots are close to them. The remaining `n - m`
+/// seeds are spread on a circle around the origin. Its radius `|a_n / a_m|^(1/(n-m))` is the
+/// geometric mean magnitude of the small roots, which solve `a_m x^(n-m) + ... + a_n = 0` in the
+/// same approximation. The heuristic pays off at high degree with roots at two scales, where the
+/// single circle of `initial_aberth` starts every guess at the wrong scale.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `approx_degree`: The degree `m` of the truncated approximation, below the degree.
+/// * `options`: The stopping criteria passed on to `find_roots` for the approximation.
+///
+/// Returns:
+///
+/// The function `initial_from_approx` returns `degree` initial guesses. It falls back to
+/// `initial_aberth` when `approx_degree` is `0` or not below the degree, or when the truncation
+/// cannot be solved. It returns the error of `poly::degree` for invalid coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::initial_from_approx;
+/// use bairstow::rootfinding::Options;
+///
+/// // (x^2 - 9)(x^4 - 1e-4): roots +-3 and four at 0.1
+/// let coeffs = [1.0, 0.0, -9.0, 0.0, -1e-4, 0.0, 9e-4];
+/// let z0s = initial_from_approx(&coeffs, 2, &Options::default()).unwrap();
+///
+/// assert_eq!(z0s.len(), 6);
+/// assert!((z0s[0].norm() - 3.0).abs() < 1e-12 && (z0s[1].norm() - 3.0).abs() < 1e-12);
+/// assert!(z0s[2..].iter().all(|z| (z.norm() - 0.1).abs() < 1e-12));
+/// ```
+pub fn initial_from_approx(
+    coeffs: &[f64],
+    approx_degree: usize,
+    options: &Options,
+) -> Result<Vec<Complex<f64>>, RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    let fallback = || initial_aberth(coeffs);
+    if approx_degree == 0 || approx_degree >= degree {
+        return fallback();
+    }
+    let Ok(mut z0s) = find_roots(&coeffs[..=approx_degree], options) else {
+        return fallback();
+    };
+    let rest = degree - approx_degree;
+    let radius = (coeffs[degree] / coeffs[approx_degree])
+        .abs()
+        .powf(1.0 / rest as f64);
+    let radius = if radius > 0.0 && radius.is_finite() {
+        radius
+    } else {
+        // no usable estimate of the small roots, seed them inside the large ones
+        0.5 * z0s.iter().map(|z| z.norm()).fold(f64::INFINITY, f64::min)
+    };
+    let k = TWO_PI / rest as f64;
+    z0s.extend((0..rest).map(|idx| Complex::from_polar(radius, k * (0.25 + idx as f64))));
+    Ok(z0s)
+}
+
+/// Respreading the unconverged guesses
+///
+/// The `respread_unconverged` function moves every guess that is not marked converged onto the
+/// circle of `initial_aberth`, keeping the converged ones. The circle has one slot per degree; a
+/// converged root occupies the slot closest to it in angle, and the stragglers take the free
+/// slots in order. Use it when reusing roots, e.g. across a parameter sweep, leaves a few guesses
+/// merged or lost while the rest are still good.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zs`: The guesses, one per degree; the unconverged ones are overwritten.
+/// * `converged`: Which guesses to keep, one per guess.
+///
+/// Returns:
+///
+/// The function `respread_unconverged` returns `RootError::BufferLength` when a slice does not
+/// match the degree of `coeffs`, or the error of `poly::degree` for invalid coefficients. `zs` is
+/// left untouched on error.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::{aberth, initial_aberth, respread_unconverged};
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zs = initial_aberth(&coeffs).unwrap();
+/// aberth(&coeffs, &mut zs, &Options::default());
+/// let kept = zs[0];
+/// zs[7] = zs[6];
+/// respread_unconverged(&coeffs, &mut zs, &[true, true, true, true, true, true, false, false])
+///     .unwrap();
+///
+/// assert_eq!(zs[0], kept);
+/// assert_ne!(zs[6], zs[7]);
+/// ```
+pub fn respread_unconverged(
+    coeffs: &[f64],
+    zs: &mut [Complex<f64>],
+    converged: &[bool],
+) -> Result<(), RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    for (name, found) in [("zs", zs.len()), ("converged", converged.len())] {
+        if found != degree {
+            return Err(RootError::BufferLength {
+                name,
+                expected: degree,
+                found,
+            });
+        }
+    }
+    fill_free_slots(coeffs, zs, converged);
+    Ok(())
+}
+
+/// The `fill_free_slots` function moves every estimate not marked in `keep` onto the circle of
+/// `initial_aberth`. The circle has one slot per estimate; a kept estimate occupies the slot
+/// closest to it in angle, and the others take the free slots in order. `coeffs` must have one
+/// coefficient more than `zs`, and `keep` one flag per estimate.
+fn fill_free_slots(coeffs: &[f64], zs: &mut [Complex<f64>], keep: &[bool]) {
+    let degree = zs.len();
+    let (center, re) = aberth_circle(coeffs, degree, None);
+    let k = TWO_PI / (degree as f64);
+    // slot idx sits at the angle arg(re) + k * (0.25 + idx), as in initial_aberth
+    let slot_point = |idx: usize| {
+        let theta = k * (0.25 + idx as f64);
+        center + re * Complex::<f64>::new(theta.cos(), theta.sin())
+    };
+    let mut taken = vec![false; degree];
+    for (z, _) in zs.iter().zip(keep).filter(|(_, c)| **c) {
+        let turns = ((z - center).arg() - re.arg()) / k - 0.25;
+        taken[turns.round().rem_euclid(degree as f64) as usize % degree] = true;
+    }
+    let free = (0..degree).filter(|idx| !taken[*idx]);
+    let stragglers = zs.iter_mut().zip(keep).filter(|(_, c)| !**c);
+    for ((z, _), idx) in stragglers.zip(free) {
+        *z = slot_point(idx);
+    }
+}
+
+/// Aberth's method
+/// 
+/// The `aberth` function implements Aberth's method for finding roots of a polynomial. It runs
+/// until convergence or `options.max_iters`; unlike `aberth_detailed` it does not stop early when
+/// the residual keeps growing.
+/// The input is not validated, so malformed coefficients or a `zs` that does not hold one guess per
+/// degree give meaningless estimates rather than an error; `aberth_into` checks both and returns a
+/// `RootError`.
+/// 
+/// <pre>
+///                 P ⎛z ⎞
+///      new          ⎝ i⎠
+///     z    = z  - ───────
+///      i      i   P' ⎛z ⎞
+///                    ⎝ i⎠
+/// where
+///                           degree
+///                         _____
+///                         ╲
+///                          ╲    P ⎛z ⎞
+///                           ╲     ⎝ i⎠
+///     P' ⎛z ⎞ = P  ⎛z ⎞ -   ╱   ───────
+///        ⎝ i⎠    1 ⎝ i⎠    ╱    z  - z
+///                         ╱      i    j
+///                         ‾‾‾‾‾
+///                         j ≠ i
+/// </pre>
+///
+/// Arguments:
+/// 
+/// * `coeffs`: The `coeffs` parameter is a slice of `f64` values representing the coefficients of a
+/// polynomial. The coefficients are ordered from highest degree to lowest degree. For example, if the
+/// polynomial is `3x^2 + 2x + 1`, the `coeffs` slice would
+/// * `zs`: A mutable slice of complex numbers, e.g. a `Vec` or a stack array, representing the
+/// initial guesses for the roots of the polynomial.
+/// * `options`: The `options` parameter is an instance of the `Options` struct, which contains the
+/// following fields:
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::{initial_aberth, aberth};
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zrs = initial_aberth(&coeffs).unwrap();
+/// let (niter, _found) = aberth(&coeffs, &mut zrs, &Options::default());
+///
+/// assert_eq!(niter, 5);
+/// ```
+pub fn aberth(coeffs: &[f64], zs: &mut [Complex<f64>], options: &Options) -> (usize, bool) {
+    let result = aberth_observed(coeffs, zs, options, false, |_, _| {});
+    (result.niter, result.reason == StopReason::Converged)
+}
+
+/// The `StopReason` enum tells why an iterative root finder stopped.
+///
+/// Variants:
+///
+/// * `Converged`: The largest residual dropped below `options.tol`.
+/// * `MaxIters`: The iteration limit was reached while the residual was still above `options.tol`.
+/// * `Diverged`: The residual became non-finite or kept growing for `DIVERGE_WINDOW` sweeps.
+/// * `Overflow`: Evaluating the polynomial at an estimate overflowed, see `horner_eval_c_checked`.
+/// * `Timeout`: The iteration ran longer than `options.time_limit`.
+/// * `Cancelled`: The flag `options.cancel` was set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StopReason {
+    Converged,
+    MaxIters,
+    Diverged,
+    Overflow,
+    Timeout,
+    Cancelled,
+}
+
+/// The `AberthResult` struct is the detailed outcome of `aberth_detailed`.
+///
+/// Properties:
+///
+/// * `niter`: The number of sweeps performed.
+/// * `tol`: The largest residual `|P(z)|` (l1 norm) over the returned estimates, evaluated like the
+///   sweeps, so under `options.high_degree` it saturates at `f64::MAX` instead of overflowing.
+/// * `reason`: Why the iteration stopped.
+/// * `elapsed`: The wall-clock time of the iteration when `options.timing` is set, `None` otherwise.
+/// * `converged_at`: For every root, indexed like the estimates, the first sweep after which its
+///   own residual was below `options.tol`, counted from `0` like `niter`, or `None` if it never
+///   was. Roots in a cluster converge only linearly until the cluster is resolved and show up with
+///   late indices. An estimate moved by `options.auto_respread` starts over.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AberthResult {
+    pub niter: usize,
+    pub tol: f64,
+    pub reason: StopReason,
+    pub elapsed: Option<std::time::Duration>,
+    pub converged_at: Vec<Option<usize>>,
+}
+
+/// The number of consecutive sweeps with a growing residual after which Aberth's method is
+/// considered diverged.
+const DIVERGE_WINDOW: usize = 10;
+
+/// The number of times `nearest_root` restarts with a smaller step before giving up.
+const MAX_RESTARTS: usize = 8;
+
+/// The factor by which the adaptive threshold tightens from one sweep to the next.
+const ADAPTIVE_FACTOR: f64 = 1e-4;
+
+/// The relative imaginary part up to which the deflation solver treats a root as real: Newton
+/// leaves a real root with an imaginary part at the level of its accuracy, far above rounding.
+const REAL_ROOT_TOL: f64 = 1e-8;
+
+/// The relative imaginary part up to which `find_roots_auto` treats a coefficient as real: a few
+/// units of rounding, so that only coefficients that are real up to arithmetic noise qualify.
+const REAL_COEFF_TOL: f64 = 8.0 * f64::EPSILON;
+
+/// How much the estimates left out by `Options::interaction_neighbors` may change the Aberth
+/// denominator `1 - r S` before the full interaction sum is used instead.
+const TRUNCATION_RATIO: f64 = 1e-3;
+
+/// The per-root scratch buffers of the Aberth loops. With the `smallvec` feature they stay on the
+/// stack up to degree 16.
+#[cfg(feature = "smallvec")]
+type Scratch<T> = smallvec::SmallVec<[T; 16]>;
+#[cfg(not(feature = "smallvec"))]
+type Scratch<T> = Vec<T>;
+
+/// The `scratch_derivative` function returns the derivative coefficients in a `Scratch` buffer.
+fn scratch_derivative(coeffs: &[f64]) -> Scratch<f64> {
+    let degree = coeffs.len().saturating_sub(1);
+    (0..degree)
+        .map(|i| coeffs[i] * (degree - i) as f64)
+        .collect()
+}
+
+/// The `scratch_flags` function returns `len` cleared convergence flags in a `Scratch` buffer.
+fn scratch_flags(len: usize) -> Scratch<bool> {
+    std::iter::repeat_n(false, len).collect()
+}
+
+/// The `scratch_snapshot` function returns the buffer `aberth_sweep` copies the estimates into
+/// under `IterationScheme::Jacobi`, and an empty one under `GaussSeidel`, which needs none.
+fn scratch_snapshot(zs: &[Complex<f64>], options: &Options) -> Scratch<Complex<f64>> {
+    match options.scheme {
+        IterationScheme::GaussSeidel => Scratch::new(),
+        IterationScheme::Jacobi => Scratch::from(zs),
+    }
+}
+
+/// The `nan_max` function returns the larger residual, letting a NaN win so that it is never
+/// mistaken for convergence.
+#[inline]
+pub(crate) fn nan_max(tol: f64, tol_i: f64) -> f64 {
+    if tol.is_nan() || tol_i.is_nan() {
+        f64::NAN
+    } else {
+        tol.max(tol_i)
+    }
+}
+
+/// The `clamp_step` function shortens the step `dz` to `options.max_step`, keeping its direction.
+#[inline]
+fn clamp_step(dz: Complex<f64>, options: &Options) -> Complex<f64> {
+    match options.max_step {
+        Some(max_step) if dz.norm() > max_step => dz * (max_step / dz.norm()),
+        _ => dz,
+    }
+}
+
+/// The `aberth_sweep` function performs sweep number `sweep` of Aberth's method and returns the
+/// largest residual among the roots that are not frozen. A root whose residual is below
+/// `skip_below` is evaluated but not moved in this sweep. A root whose residual first drops below
+/// `options.tol` gets `sweep` in `converged_at`. The sweep stops at the first finite estimate at
+/// which the evaluation overflows and returns `EvalError::Overflow`.
+fn aberth_sweep(
+    coeffs: &[f64],
+    zs: &mut [Complex<f64>],
+    buffers: &mut SweepBuffers,
+    options: &Options,
+    skip_below: f64,
+    sweep: usize,
+) -> Result<f64, EvalError> {
+    let SweepBuffers {
+        pb,
+        converged,
+        snapshot,
+        converged_at,
+    } = buffers;
+    if options.auto_respread {
+        respread(coeffs, zs, converged, converged_at, options);
+    }
+    let jacobi = options.scheme == IterationScheme::Jacobi;
+    if jacobi {
+        snapshot.copy_from_slice(zs);
+    }
+    // built once per sweep, so under Gauss-Seidel it lags the estimates already moved
+    let grid = match options.interaction_neighbors {
+        Some(k) if k + 1 < zs.len() => Some((k, NeighborGrid::build(zs))),
+        _ => None,
+    };
+    let mut neighbors = Vec::new();
+    let mut tol = 0.0;
+    for i in 0..zs.len() {
+        if options.freeze_converged && converged[i] {
+            continue;
+        }
+        let zi = zs[i];
+        let (pp, ep) = if options.high_degree {
+            horner_eval_c_scaled(coeffs, &zi)
+        } else if zi.is_finite() {
+            (horner_eval_c_checked(coeffs, &zi)?, 0)
+        } else {
+            (horner_eval_c(coeffs, &zi), 0) // a NaN estimate diverged, it did not overflow
+        };
+        let tol_i = root_residual(pp, ep, &zi, pb.len(), options);
+        if tol_i < 1e-15 {
+            converged[i] = true;
+        }
+        if tol_i < options.tol {
+            if let Some(at) = converged_at.get_mut(i) {
+                at.get_or_insert(sweep);
+            }
+        }
+        tol = nan_max(tol, tol_i);
+        if tol_i < skip_below {
+            continue;
+        }
+        let others: &[Complex<f64>] = if jacobi { snapshot } else { zs };
+        let dz = if options.high_degree {
+            // P / (P' - P S) = r / (1 - r S) with the exponents cancelled in r = P / P'
+            let (pd, ed) = horner_eval_c_scaled(pb, &zi);
+            let r = pp / pd * 2.0_f64.powi(ep - ed);
+            let mut denom = Complex::new(1.0, 0.0);
+            let near = truncated_neighbors(grid.as_ref(), i, others, r, &mut neighbors);
+            for_each_other(others, i, near, |zj| denom -= r / (zi - zj));
+            r / denom
+        } else {
+            let mut pp1 = horner_eval_c(pb, &zi);
+            let near = truncated_neighbors(grid.as_ref(), i, others, pp / pp1, &mut neighbors);
+            for_each_other(others, i, near, |zj| pp1 -= pp / (zi - zj));
+            pp / pp1
+        };
+        zs[i] -= clamp_step(dz, options); // Gauss-Seidel fashion unless snapshotted
+    }
+    Ok(tol)
+}
+
+/// The `for_each_other` function calls `f` with every estimate that interacts with estimate `i`:
+/// all others, or only those listed in `near` (see `Options::interaction_neighbors`).
+#[inline]
+fn for_each_other<F>(others: &[Complex<f64>], i: usize, near: Option<&[usize]>, mut f: F)
+where
+    F: FnMut(Complex<f64>),
+{
+    match near {
+        Some(near) => near.iter().for_each(|&j| f(others[j])),
+        None => others
+            .iter()
             .enumerate()
-            .filter(|(_, (_, converged))| !**converged)
-            .filter_map(|(i, (zi, converged))| {
-                let pp = horner_eval_c(coeffs, zi);
-                let tol_i = pp.l1_norm(); // ???
-                if tol_i < 1e-15 {
-                    *converged = true;
-                    None
-                } else {
-                    let mut pp1 = horner_eval_c(&pb, zi);
-                    for (j, zj) in zsc.iter().enumerate() {
-                        // exclude i
-                        if j == i {
-                            continue;
-                        }
-                        pp1 -= pp / (*zi - zj);
+            .filter(|t| t.0 != i)
+            .for_each(|(_, zj)| f(*zj)),
+    }
+}
+
+/// The `truncated_neighbors` function returns the neighbors of estimate `i` from `grid` when the
+/// estimates left out of the interaction sum cannot matter: each of them is at least as far as the
+/// farthest neighbor, so together they change `1 - r S`, with `r = P / P'`, by at most
+/// `|r| (n - 1 - k) / reach`, which must stay below `TRUNCATION_RATIO`. Far from convergence `r`
+/// is large and `None` asks for the full sum; as `r` shrinks, the sum becomes local. The reach is
+/// at most the diameter of the grid, which rules out the early sweeps before any search.
+///
+/// The grid holds the estimates from the start of the sweep. Under Jacobi they are `others`, but
+/// under Gauss-Seidel the estimates before `i` have already moved in this sweep, so the neighbors
+/// are chosen by their stale positions while `reach` is measured to the updated ones. The bound
+/// then holds up to those moves, which are themselves steps of size about `|r|` once the sum is
+/// truncated.
+fn truncated_neighbors<'a>(
+    grid: Option<&(usize, NeighborGrid)>,
+    i: usize,
+    others: &[Complex<f64>],
+    r: Complex<f64>,
+    out: &'a mut Vec<usize>,
+) -> Option<&'a [usize]> {
+    let (k, grid) = grid?;
+    if r.norm() * (others.len() - 1 - k) as f64 > TRUNCATION_RATIO * grid.diameter {
+        return None;
+    }
+    grid.nearest(i, *k, out);
+    let reach = out
+        .iter()
+        .map(|&j| (others[i] - others[j]).norm())
+        .fold(0.0, f64::max);
+    let far = r.norm() * (others.len() - 1 - out.len()) as f64;
+    (far <= TRUNCATION_RATIO * reach).then_some(&out[..])
+}
+
+/// The `NeighborGrid` struct buckets the estimates of one sweep into a square grid of about one
+/// estimate per cell over their bounding box, so that the `k` nearest neighbors of an estimate
+/// are found by scanning rings of cells around it instead of all estimates. The cells are stored
+/// in compressed form: the estimates of cell `c` are `items[start[c]..start[c + 1]]`. Non-finite
+/// estimates are left out.
+struct NeighborGrid {
+    points: Vec<Complex<f64>>,
+    diameter: f64,
+    lower: Complex<f64>,
+    cell: f64,
+    side: usize,
+    start: Vec<usize>,
+    items: Vec<usize>,
+}
+
+impl NeighborGrid {
+    /// The `build` function buckets `zs` by counting sort.
+    fn build(zs: &[Complex<f64>]) -> Self {
+        let finite = zs.iter().filter(|z| z.is_finite());
+        let lower = finite
+            .clone()
+            .fold(Complex::new(f64::INFINITY, f64::INFINITY), |m, z| {
+                Complex::new(m.re.min(z.re), m.im.min(z.im))
+            });
+        let upper = finite.fold(
+            Complex::new(f64::NEG_INFINITY, f64::NEG_INFINITY),
+            |m, z| Complex::new(m.re.max(z.re), m.im.max(z.im)),
+        );
+        let side = (zs.len() as f64).sqrt().ceil().max(1.0) as usize;
+        let extent = (upper.re - lower.re).max(upper.im - lower.im);
+        let cell = if extent > 0.0 && extent.is_finite() {
+            extent / side as f64
+        } else {
+            1.0
+        };
+        let mut grid = NeighborGrid {
+            points: zs.to_vec(),
+            diameter: (upper - lower).norm(),
+            lower,
+            cell,
+            side,
+            start: vec![0; side * side + 1],
+            items: Vec::with_capacity(zs.len()),
+        };
+        for z in zs.iter() {
+            if let Some((cx, cy)) = grid.cell_of(z) {
+                grid.start[cy * side + cx + 1] += 1;
+            }
+        }
+        for c in 1..grid.start.len() {
+            grid.start[c] += grid.start[c - 1];
+        }
+        grid.items.resize(grid.start[side * side], 0);
+        let mut fill = grid.start.clone();
+        for (j, z) in zs.iter().enumerate() {
+            if let Some((cx, cy)) = grid.cell_of(z) {
+                grid.items[fill[cy * side + cx]] = j;
+                fill[cy * side + cx] += 1;
+            }
+        }
+        grid
+    }
+
+    /// The `cell_of` function returns the cell coordinates of a finite estimate.
+    fn cell_of(&self, z: &Complex<f64>) -> Option<(usize, usize)> {
+        if !z.is_finite() {
+            return None;
+        }
+        let index = |v: f64| ((v / self.cell).floor().max(0.0) as usize).min(self.side - 1);
+        Some((index(z.re - self.lower.re), index(z.im - self.lower.im)))
+    }
+
+    /// The `nearest` function fills `out` with the indices of the (up to) `k` estimates nearest
+    /// to estimate `i`, in no particular order. After scanning the rings `0..=r` around the cell
+    /// of `i`, every estimate not yet seen is farther than `r` cells, so the scan stops once `k`
+    /// candidates lie within that distance.
+    fn nearest(&self, i: usize, k: usize, out: &mut Vec<usize>) {
+        out.clear();
+        let zi = self.points[i];
+        let Some((cx, cy)) = self.cell_of(&zi).filter(|_| k > 0) else {
+            return;
+        };
+        let distance = |j: &usize| (self.points[*j] - zi).norm_sqr();
+        for r in 0..self.side {
+            let (x0, x1) = (cx.saturating_sub(r), (cx + r).min(self.side - 1));
+            let (y0, y1) = (cy.saturating_sub(r), (cy + r).min(self.side - 1));
+            for y in y0..=y1 {
+                for x in x0..=x1 {
+                    if x.abs_diff(cx).max(y.abs_diff(cy)) != r {
+                        continue; // an inner ring, already scanned
                     }
-                    let dt = pp / pp1; // Gauss-Seidel fashion
-                    *zi -= dt;
-                    Some(tol_i)
+                    let c = y * self.side + x;
+                    out.extend(
+                        self.items[self.start[c]..self.start[c + 1]]
+                            .iter()
+                            .filter(|&&j| j != i),
+                    );
                 }
-            })
-            .reduce(|| tol, |x, y| x.max(y));
-        if tol < tol_i {
-            tol = tol_i;
+            }
+            if out.len() >= k {
+                out.select_nth_unstable_by(k - 1, |a, b| distance(a).total_cmp(&distance(b)));
+                if distance(&out[k - 1]) <= (r as f64 * self.cell).powi(2) {
+                    break;
+                }
+            }
+        }
+        if out.len() > k {
+            out.select_nth_unstable_by(k - 1, |a, b| distance(a).total_cmp(&distance(b)));
+            out.truncate(k);
+        }
+    }
+}
+
+/// The `respread` function moves the later estimate of every pair that has merged, i.e. come within
+/// `options.zero_threshold * max(1, |z_i|)`, onto a free slot of the circle of `initial_aberth`
+/// like `respread_unconverged`, and clears its convergence flag.
+fn respread(
+    coeffs: &[f64],
+    zs: &mut [Complex<f64>],
+    converged: &mut [bool],
+    converged_at: &mut [Option<usize>],
+    options: &Options,
+) {
+    if coeffs.len() != zs.len() + 1 {
+        return;
+    }
+    let merged = |j: usize| {
+        zs[..j]
+            .iter()
+            .any(|zi| (zi - zs[j]).norm() <= options.zero_threshold * zi.norm().max(1.0))
+    };
+    let keep: Vec<bool> = (0..zs.len()).map(|j| !merged(j)).collect();
+    if keep.iter().all(|k| *k) {
+        return;
+    }
+    fill_free_slots(coeffs, zs, &keep);
+    for (c, k) in converged.iter_mut().zip(&keep) {
+        *c &= k;
+    }
+    for (at, k) in converged_at.iter_mut().zip(&keep) {
+        if !k {
+            *at = None;
         }
+    }
+}
+
+/// The `scaled_l1_norm` function returns the l1 norm of `pp * 2^ep`, saturating at `f64::MAX` so
+/// that a residual too large for `f64` is not mistaken for divergence. A NaN stays NaN.
+#[inline]
+fn scaled_l1_norm(pp: Complex<f64>, ep: i32) -> f64 {
+    let norm = pp.l1_norm();
+    if ep == 0 || norm == 0.0 || norm.is_nan() {
+        norm
+    } else {
+        (norm * 2.0_f64.powi(ep)).min(f64::MAX)
+    }
+}
+
+/// The `root_residual` function returns the residual of the root `zi` from the scaled value
+/// `pp * 2^ep` of the polynomial of degree `degree`, weighed by `options.residual_scaling`. The
+/// division by `|zi|^degree` is carried out on the exponent, so it cannot overflow.
+#[inline]
+fn root_residual(
+    pp: Complex<f64>,
+    ep: i32,
+    zi: &Complex<f64>,
+    degree: usize,
+    options: &Options,
+) -> f64 {
+    let log2_scale = match options.residual_scaling {
+        ResidualScaling::Raw => 0.0,
+        ResidualScaling::ByRootMagnitude => degree as f64 * zi.norm().log2(),
+    };
+    if log2_scale > 0.0 {
+        let norm = pp.l1_norm();
+        (norm * 2.0_f64.powf(ep as f64 - log2_scale)).min(f64::MAX)
+    } else {
+        scaled_l1_norm(pp, ep)
+    }
+}
+
+/// The `adaptive_skip` function returns the residual below which the next sweep leaves a root
+/// alone, given the threshold `skip_below` of the last sweep and its largest residual `tol`: `0`
+/// without `options.adaptive_tol`. Otherwise the schedule starts loose at the first finite
+/// residual, is multiplied by `ADAPTIVE_FACTOR` every sweep and is clamped at `options.tol`.
+#[inline]
+fn adaptive_skip(skip_below: f64, tol: f64, options: &Options) -> f64 {
+    if !options.adaptive_tol {
+        return 0.0;
+    }
+    let next = if skip_below > 0.0 {
+        skip_below * ADAPTIVE_FACTOR
+    } else if tol.is_finite() {
+        tol
+    } else {
+        return 0.0;
+    };
+    next.max(options.tol)
+}
+
+/// The `SweepBuffers` struct holds the scratch space of the Aberth sweeps: the derivative
+/// coefficients, the convergence flags, the snapshot read by `IterationScheme::Jacobi` sweeps and
+/// the sweep in which each root converged, which is left empty when nobody asks for it.
+struct SweepBuffers<'a> {
+    pb: &'a [f64],
+    converged: &'a mut [bool],
+    snapshot: &'a mut [Complex<f64>],
+    converged_at: &'a mut [Option<usize>],
+}
+
+/// The `aberth_iterate` function runs Aberth sweeps until convergence, the iteration limit, an
+/// overflow, the time limit or cancellation, and with `detect_divergence` also until divergence,
+/// using only the buffers it is given. `on_sweep` sees the estimates and the largest residual of
+/// every sweep.
+fn aberth_iterate<F>(
+    coeffs: &[f64],
+    zs: &mut [Complex<f64>],
+    buffers: SweepBuffers,
+    options: &Options,
+    detect_divergence: bool,
+    mut on_sweep: F,
+) -> (usize, StopReason)
+where
+    F: FnMut(&[Complex<f64>], f64),
+{
+    let mut buffers = buffers;
+    let mut prev_tol = f64::INFINITY;
+    let mut growing = 0;
+    let mut skip_below = 0.0;
+    let start = options
+        .time_limit
+        .map(|limit| (std::time::Instant::now(), limit));
+    for k in 0..options.max_iters {
+        let Ok(tol) = aberth_sweep(coeffs, zs, &mut buffers, options, skip_below, k) else {
+            return (k, StopReason::Overflow);
+        };
+        on_sweep(zs, tol);
+        skip_below = adaptive_skip(skip_below, tol, options);
         if tol < options.tol {
-            return (niter, true);
+            return (k, StopReason::Converged);
         }
+        growing = if tol > prev_tol { growing + 1 } else { 0 };
+        if detect_divergence && (!tol.is_finite() || growing >= DIVERGE_WINDOW) {
+            return (k, StopReason::Diverged);
+        }
+        if let Some(reason) = interruption(start, options) {
+            return (k, reason);
+        }
+        prev_tol = tol;
+    }
+    (options.max_iters, StopReason::MaxIters)
+}
+
+/// The `interruption` function returns why an iteration started at `start` must stop early:
+/// `StopReason::Cancelled` once `options.cancel` is set, `StopReason::Timeout` once
+/// `options.time_limit` has passed, and `None` otherwise.
+fn interruption(
+    start: Option<(std::time::Instant, std::time::Duration)>,
+    options: &Options,
+) -> Option<StopReason> {
+    if let Some(flag) = &options.cancel {
+        if flag.load(std::sync::atomic::Ordering::Relaxed) {
+            return Some(StopReason::Cancelled);
+        }
+    }
+    match start {
+        Some((start, limit)) if start.elapsed() > limit => Some(StopReason::Timeout),
+        _ => None,
+    }
+}
+
+/// Aberth's method into caller-provided buffers
+///
+/// The `aberth_into` function runs the same iteration as `aberth_detailed` without any heap
+/// allocation: the derivative coefficients, the per-root convergence flags and the snapshot of the
+/// estimates that `IterationScheme::Jacobi` sweeps read from live in buffers supplied by the
+/// caller, which makes it usable where allocating is not allowed. `Options::interaction_neighbors`
+/// still allocates its neighbor grid.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zs`: The initial guesses, refined in place; one per degree.
+/// * `pb`: Scratch space for the derivative coefficients; one per degree.
+/// * `converged`: Scratch space for the convergence flags; one per root.
+/// * `snapshot`: Scratch space for the Jacobi snapshot; one per root under
+///   `IterationScheme::Jacobi`, unused and possibly empty under `GaussSeidel`.
+/// * `options`: The stopping criteria.
+///
+/// Returns:
+///
+/// The function `aberth_into` returns the number of sweeps and the `StopReason`, a
+/// `RootError::BufferLength` when a buffer does not match the degree of `coeffs`, or the error of
+/// `poly::degree` when `coeffs` has no roots.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::{aberth_into, initial_aberth_slice, StopReason};
+/// use num::Complex;
+///
+/// let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zs = [Complex::new(0.0, 0.0); 8];
+/// initial_aberth_slice(&coeffs, &mut zs).unwrap();
+/// let mut pb = [0.0; 8];
+/// let mut converged = [false; 8];
+/// let options = Options::default();
+/// let (niter, reason) =
+///     aberth_into(&coeffs, &mut zs, &mut pb, &mut converged, &mut [], &options).unwrap();
+///
+/// assert_eq!(niter, 5);
+/// assert_eq!(reason, StopReason::Converged);
+/// let short = aberth_into(&coeffs, &mut zs, &mut pb[..7], &mut converged, &mut [], &options);
+/// assert!(short.is_err());
+/// ```
+pub fn aberth_into(
+    coeffs: &[f64],
+    zs: &mut [Complex<f64>],
+    pb: &mut [f64],
+    converged: &mut [bool],
+    snapshot: &mut [Complex<f64>],
+    options: &Options,
+) -> Result<(usize, StopReason), RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    let snapshot_len = match options.scheme {
+        IterationScheme::GaussSeidel => snapshot.len(),
+        IterationScheme::Jacobi => zs.len(),
+    };
+    for (name, found, expected) in [
+        ("zs", zs.len(), degree),
+        ("pb", pb.len(), degree),
+        ("converged", converged.len(), zs.len()),
+        ("snapshot", snapshot.len(), snapshot_len),
+    ] {
+        if found != expected {
+            return Err(RootError::BufferLength {
+                name,
+                expected,
+                found,
+            });
+        }
+    }
+    for i in 0..degree {
+        pb[i] = coeffs[i] * (degree - i) as f64;
+    }
+    converged.fill(false);
+    let buffers = SweepBuffers {
+        pb,
+        converged,
+        snapshot,
+        converged_at: &mut [],
+    };
+    Ok(aberth_iterate(
+        coeffs,
+        zs,
+        buffers,
+        options,
+        true,
+        |_, _| {},
+    ))
+}
+
+/// Aberth's method with a detailed result
+///
+/// The `aberth_detailed` function runs the same iteration as `aberth`, but reports why it stopped:
+/// convergence, the iteration limit, or divergence. Divergence is detected when the largest residual
+/// becomes non-finite or grows for `DIVERGE_WINDOW` consecutive sweeps.
+/// The input is not validated, so malformed coefficients or a `zs` that does not hold one guess per
+/// degree give meaningless estimates rather than an error; `aberth_into` checks both and returns a
+/// `RootError`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zs`: The initial guesses, refined in place.
+/// * `options`: The stopping criteria.
+///
+/// Returns:
+///
+/// The function `aberth_detailed` returns an `AberthResult`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::{aberth_detailed, initial_aberth, StopReason};
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zrs = initial_aberth(&coeffs).unwrap();
+/// let result = aberth_detailed(&coeffs, &mut zrs, &Options::default());
+///
+/// assert_eq!(result.niter, 5);
+/// assert_eq!(result.reason, StopReason::Converged);
+/// assert!(result.converged_at.iter().all(|at| at.is_some_and(|k| k <= result.niter)));
+/// ```
+pub fn aberth_detailed(coeffs: &[f64], zs: &mut [Complex<f64>], options: &Options) -> AberthResult {
+    aberth_observed(coeffs, zs, options, true, |_, _| {})
+}
+
+/// Aberth's method with every iterate recorded
+///
+/// The `aberth_trajectories` function runs `aberth_detailed` and records the estimates after every
+/// sweep, e.g. to animate the guesses moving towards the roots.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zs`: The initial guesses, refined in place.
+/// * `options`: The stopping criteria.
+///
+/// Returns:
+///
+/// The function `aberth_trajectories` returns the `AberthResult` and the frames: the initial
+/// guesses followed by the estimates after each sweep, so the last frame equals the returned roots.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::{aberth_trajectories, initial_aberth};
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zrs = initial_aberth(&coeffs).unwrap();
+/// let (result, frames) = aberth_trajectories(&coeffs, &mut zrs, &Options::default());
+///
+/// assert_eq!(frames.len(), result.niter + 2);
+/// assert_eq!(frames[0], initial_aberth(&coeffs).unwrap());
+/// assert_eq!(frames[frames.len() - 1], zrs);
+/// ```
+pub fn aberth_trajectories(
+    coeffs: &[f64],
+    zs: &mut [Complex<f64>],
+    options: &Options,
+) -> (AberthResult, Vec<Vec<Complex<f64>>>) {
+    let mut frames = vec![zs.to_vec()];
+    let result = aberth_observed(coeffs, zs, options, true, |zs, _| frames.push(zs.to_vec()));
+    (result, frames)
+}
+
+/// Aberth's method with the step sizes recorded
+///
+/// The `aberth_with_steps` function runs `aberth_detailed` and records, for every root, the size
+/// `|Δz_i|` of its move in each sweep. A root whose steps stop shrinking, e.g. one of a multiple
+/// root, which Aberth's method only approaches linearly, stands out from the quadratically
+/// converging simple roots. A frozen or skipped root records a step of `0`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zs`: The initial guesses, refined in place.
+/// * `options`: The stopping criteria.
+///
+/// Returns:
+///
+/// The function `aberth_with_steps` returns the `AberthResult` and one step sequence per root,
+/// indexed like `zs`, with one entry per sweep.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::{aberth_with_steps, initial_aberth};
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zrs = initial_aberth(&coeffs).unwrap();
+/// let (result, steps) = aberth_with_steps(&coeffs, &mut zrs, &Options::default());
+///
+/// assert_eq!(steps.len(), 8);
+/// assert!(steps.iter().all(|s| s.len() == result.niter + 1));
+/// ```
+pub fn aberth_with_steps(
+    coeffs: &[f64],
+    zs: &mut [Complex<f64>],
+    options: &Options,
+) -> (AberthResult, Vec<Vec<f64>>) {
+    let mut prev = zs.to_vec();
+    let mut steps = vec![Vec::new(); zs.len()];
+    let result = aberth_observed(coeffs, zs, options, true, |zs, _| {
+        for ((z, p), s) in zs.iter().zip(prev.iter_mut()).zip(steps.iter_mut()) {
+            s.push((z - *p).norm());
+            *p = *z;
+        }
+    });
+    (result, steps)
+}
+
+/// Aberth's method streaming the converged roots
+///
+/// The `aberth_streaming` function runs `aberth_detailed` and sends `(index, root)` through `tx`
+/// after the sweep in which the residual `|P(z_i)|` of a root first drops below `options.tol_ind`,
+/// so that a consumer on another thread can start on each root while the others are still being
+/// refined. Every index is sent at most once. A root that never gets below `options.tol_ind` is
+/// not sent; `tx` is dropped on return, which closes the channel either way.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zs`: The initial guesses, refined in place.
+/// * `options`: The stopping criteria; `tol_ind` is the per-root tolerance.
+/// * `tx`: The sending half of the channel. A hung-up receiver does not stop the iteration.
+///
+/// Returns:
+///
+/// The function `aberth_streaming` returns the same `AberthResult` as `aberth_detailed`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::{aberth_streaming, initial_aberth};
+/// use std::sync::mpsc;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zrs = initial_aberth(&coeffs).unwrap();
+/// let options = Options::default().tol_ind(1e-12);
+/// let (tx, rx) = mpsc::channel();
+/// aberth_streaming(&coeffs, &mut zrs, &options, tx);
+///
+/// assert_eq!(rx.iter().count(), 8);
+/// ```
+pub fn aberth_streaming(
+    coeffs: &[f64],
+    zs: &mut [Complex<f64>],
+    options: &Options,
+    tx: Sender<(usize, Complex<f64>)>,
+) -> AberthResult {
+    let mut sent = vec![false; zs.len()];
+    aberth_observed(coeffs, zs, options, true, |zs, _| {
+        for (i, z) in zs.iter().enumerate() {
+            if !sent[i] && horner_eval_c(coeffs, z).l1_norm() < options.tol_ind {
+                sent[i] = true;
+                let _ = tx.send((i, *z));
+            }
+        }
+    })
+}
+
+/// The `aberth_observed` function is `aberth_detailed` with `on_sweep` called after every sweep
+/// with the estimates and the largest residual of the sweep, and the divergence check of
+/// `aberth_iterate` only with `detect_divergence`.
+fn aberth_observed<F>(
+    coeffs: &[f64],
+    zs: &mut [Complex<f64>],
+    options: &Options,
+    detect_divergence: bool,
+    on_sweep: F,
+) -> AberthResult
+where
+    F: FnMut(&[Complex<f64>], f64),
+{
+    let start = options.timing.then(std::time::Instant::now);
+    let mut converged = scratch_flags(zs.len());
+    let mut snapshot = scratch_snapshot(zs, options);
+    let pb = scratch_derivative(coeffs);
+    let mut converged_at = vec![None; zs.len()];
+    let buffers = SweepBuffers {
+        pb: &pb,
+        converged: &mut converged,
+        snapshot: &mut snapshot,
+        converged_at: &mut converged_at,
+    };
+    let (niter, reason) = aberth_iterate(coeffs, zs, buffers, options, detect_divergence, on_sweep);
+    // evaluated as in the sweeps, so that high_degree does not overflow here either
+    let residual = |z: &Complex<f64>| {
+        if options.high_degree {
+            let (pp, ep) = horner_eval_c_scaled(coeffs, z);
+            scaled_l1_norm(pp, ep)
+        } else {
+            NormKind::L1.apply(horner_eval_c(coeffs, z))
+        }
+    };
+    let tol = zs.iter().map(residual).fold(0.0, nan_max);
+    AberthResult {
+        niter,
+        tol,
+        reason,
+        elapsed: start.map(|start| start.elapsed()),
+        converged_at,
+    }
+}
+
+/// Aberth's method with a custom stopping predicate
+///
+/// The `aberth_until` function runs Aberth sweeps until `predicate` returns `true` or
+/// `options.max_iters` is reached. After every sweep the predicate receives the current estimates
+/// and the largest residual of the sweep, which replaces the built-in `options.tol` check.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zs`: The initial guesses, refined in place.
+/// * `options`: The iteration limit and the freezing behaviour.
+/// * `predicate`: Returns `true` to stop, given the estimates and the largest residual.
+///
+/// Returns:
+///
+/// The function `aberth_until` returns the number of sweeps and whether the predicate stopped the
+/// iteration; an overflowing evaluation stops it with `false`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::{aberth_until, initial_aberth};
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zrs = initial_aberth(&coeffs).unwrap();
+/// let (niter, stopped) = aberth_until(&coeffs, &mut zrs, &Options::default(), |_, tol| tol < 1e-12);
+///
+/// assert_eq!((niter, stopped), (5, true));
+/// ```
+pub fn aberth_until<F>(
+    coeffs: &[f64],
+    zs: &mut [Complex<f64>],
+    options: &Options,
+    predicate: F,
+) -> (usize, bool)
+where
+    F: Fn(&[Complex<f64>], f64) -> bool,
+{
+    let pb = scratch_derivative(coeffs);
+    let mut converged = scratch_flags(zs.len());
+    let mut snapshot = scratch_snapshot(zs, options);
+    let mut buffers = SweepBuffers {
+        pb: &pb,
+        converged: &mut converged,
+        snapshot: &mut snapshot,
+        converged_at: &mut [],
+    };
+    let mut skip_below = 0.0;
+    for niter in 0..options.max_iters {
+        let sweep = aberth_sweep(coeffs, zs, &mut buffers, options, skip_below, niter);
+        let Ok(tol) = sweep else {
+            return (niter, false);
+        };
+        skip_below = adaptive_skip(skip_below, tol, options);
+        if predicate(zs, tol) {
+            return (niter, true);
+        }
+    }
+    (options.max_iters, false)
+}
+
+/// Multi-threading Aberth's method
+/// 
+/// The `aberth_mt` function in Rust implements the multi-threaded Aberth's method for root finding.
+/// The input is not validated, so malformed coefficients or a `zs` that does not hold one guess per
+/// degree give meaningless estimates rather than an error; `aberth_into` checks both and returns a
+/// `RootError`.
+/// 
+/// Arguments:
+/// 
+/// * `coeffs`: The `coeffs` parameter is a slice of `f64` values representing the coefficients of a
+/// polynomial. The polynomial is defined by the equation:
+/// * `zs`: A mutable slice of Complex numbers. These numbers represent the initial
+/// guesses for the roots of the polynomial equation.
+/// * `options`: The `options` parameter is an instance of the `Options` struct, which contains the
+/// following fields:
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::{initial_aberth, aberth_mt};
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zrs = initial_aberth(&coeffs).unwrap();
+/// let (niter, _found) = aberth_mt(&coeffs, &mut zrs, &Options::default());
+///
+/// assert_eq!(niter, 7);
+/// ```
+pub fn aberth_mt(coeffs: &[f64], zs: &mut [Complex<f64>], options: &Options) -> (usize, bool) {
+    use rayon::prelude::*;
+
+    let pb = scratch_derivative(coeffs);
+    let mut zsc = Scratch::from(&*zs);
+    let mut converged = scratch_flags(zs.len());
+    let start = options
+        .time_limit
+        .map(|limit| (std::time::Instant::now(), limit));
+
+    for niter in 0..options.max_iters {
+        let mut tol = 0.0;
+        zsc.copy_from_slice(zs);
+
+        let tol_i = zs
+            .par_iter_mut()
+            .zip(converged.par_iter_mut())
+            .enumerate()
+            .filter(|(_, (_, converged))| !(options.freeze_converged && **converged))
+            .filter_map(|(i, (zi, converged))| {
+                let pp = horner_eval_c(coeffs, zi);
+                let tol_i = root_residual(pp, 0, zi, pb.len(), options);
+                if tol_i < 1e-15 {
+                    *converged = true;
+                    if options.freeze_converged {
+                        return None;
+                    }
+                }
+                let mut pp1 = horner_eval_c(&pb, zi);
+                for (j, zj) in zsc.iter().enumerate() {
+                    // exclude i
+                    if j == i {
+                        continue;
+                    }
+                    pp1 -= pp / (*zi - zj);
+                }
+                let dt = clamp_step(pp / pp1, options); // Gauss-Seidel fashion
+                *zi -= dt;
+                Some(tol_i)
+            })
+            .reduce(|| tol, |x, y| x.max(y));
+        if tol < tol_i {
+            tol = tol_i;
+        }
+        if tol < options.tol {
+            return (niter, true);
+        }
+        if interruption(start, options).is_some() {
+            return (niter, false);
+        }
+    }
+    (options.max_iters, false)
+}
+
+/// Evaluate a product of factors and its derivative
+///
+/// The `horner_factors_c` function evaluates `P = P_1 P_2 ... P_m` and `P'` from the factors without
+/// expanding them, using `P' = P * sum_i P_i' / P_i`, computed as `sum_i P_i' prod_(j != i) P_j` so
+/// that it stays finite at a root of a factor.
+///
+/// Arguments:
+///
+/// * `factors`: The factors, each ordered from highest degree to lowest degree.
+/// * `z`: The complex point at which the product is evaluated.
+///
+/// Returns:
+///
+/// The function `horner_factors_c` returns the pair `(P(z), P'(z))`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{horner_factors_c, horner_with_derivative_c};
+/// use bairstow::poly::Polynomial;
+/// use num::Complex;
+///
+/// // (x^2 + 1)(x - 2) = x^3 - 2x^2 + x - 2
+/// let factors = [Polynomial(vec![1.0, 0.0, 1.0]), Polynomial(vec![1.0, -2.0])];
+/// let z = Complex::new(0.5, 1.5);
+///
+/// assert_eq!(
+///     horner_factors_c(&factors, &z),
+///     horner_with_derivative_c(&[1.0, -2.0, 1.0, -2.0], &z)
+/// );
+/// ```
+pub fn horner_factors_c(factors: &[Polynomial], z: &Complex<f64>) -> (Complex<f64>, Complex<f64>) {
+    let mut pp = Complex::<f64>::new(1.0, 0.0);
+    let mut pp1 = Complex::<f64>::new(0.0, 0.0);
+    for factor in factors.iter() {
+        let (fp, fp1) = factor.eval_and_deriv(z);
+        pp1 = pp1 * fp + pp * fp1;
+        pp *= fp;
+    }
+    (pp, pp1)
+}
+
+/// Aberth's method on an evaluation function
+///
+/// The `aberth_with` function runs Aberth's method like `aberth`, but obtains `(P(z), P'(z))` from
+/// `eval` instead of from an expanded coefficient vector, e.g. `horner_factors_c` for a product of
+/// factors.
+///
+/// Arguments:
+///
+/// * `eval`: Returns the pair `(P(z), P'(z))` for a point `z`.
+/// * `zs`: The initial guesses, refined in place; one per degree.
+/// * `options`: The stopping criteria.
+///
+/// Returns:
+///
+/// The function `aberth_with` returns the number of sweeps and whether it converged.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{aberth_with, horner_with_derivative_c, initial_aberth};
+/// use bairstow::rootfinding::Options;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zrs = initial_aberth(&coeffs).unwrap();
+/// let eval = |z: &_| horner_with_derivative_c(&coeffs, z);
+/// let (_niter, found) = aberth_with(eval, &mut zrs, &Options::default());
+///
+/// assert!(found);
+/// ```
+pub fn aberth_with<F>(eval: F, zs: &mut [Complex<f64>], options: &Options) -> (usize, bool)
+where
+    F: Fn(&Complex<f64>) -> (Complex<f64>, Complex<f64>),
+{
+    let mut converged = scratch_flags(zs.len());
+    for niter in 0..options.max_iters {
+        let mut tol = 0.0;
+        for i in 0..zs.len() {
+            if options.freeze_converged && converged[i] {
+                continue;
+            }
+            let zi = zs[i];
+            let (pp, mut pp1) = eval(&zi);
+            let tol_i = pp.l1_norm();
+            if tol_i < 1e-15 {
+                converged[i] = true;
+            }
+            for (_, zj) in zs.iter().enumerate().filter(|t| t.0 != i) {
+                pp1 -= pp / (zi - zj);
+            }
+            zs[i] -= clamp_step(pp / pp1, options); // Gauss-Seidel fashion
+            tol = nan_max(tol, tol_i);
+        }
+        if tol < options.tol {
+            return (niter, true);
+        }
+    }
+    (options.max_iters, false)
+}
+
+/// Aberth's method in real arithmetic
+///
+/// The `aberth_real` function is Aberth's method restricted to the real line, for polynomials
+/// known to have only real roots. Every quantity stays an `f64`, so a sweep costs about half of a
+/// complex one. Seed it with `initial_aberth_real`.
+///
+/// Real estimates cannot become complex, so the all-real assumption is checked instead with the
+/// Laguerre inequality: a real-rooted `P` satisfies `P'(x)^2 - P(x) P''(x) >= 0` for every real `x`.
+/// An estimate where it fails is a guess that would move off the real axis. The function stops
+/// there and reports failure. A polynomial with complex roots that never trips the check fails by
+/// reaching `options.max_iters` instead.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `xs`: The real initial guesses, refined in place; they must be distinct.
+/// * `options`: The stopping criteria; `max_step` clamps the steps, `freeze_converged` skips the
+///   roots with a residual below `1e-15`.
+///
+/// Returns:
+///
+/// The function `aberth_real` returns the number of sweeps and whether it converged. It returns
+/// `false` as soon as an estimate violates the Laguerre inequality or becomes non-finite.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{aberth_real, initial_aberth_real};
+/// use bairstow::rootfinding::Options;
+/// use approx_eq::assert_approx_eq;
+///
+/// // (x - 1)(x + 2)(x - 3)
+/// let coeffs = vec![1.0, -2.0, -5.0, 6.0];
+/// let mut xs = initial_aberth_real(&coeffs).unwrap();
+/// let (_niter, found) = aberth_real(&coeffs, &mut xs, &Options::default());
+/// xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+///
+/// assert!(found);
+/// assert_approx_eq!(xs[0], -2.0);
+/// assert_approx_eq!(xs[2], 3.0);
+///
+/// // x^2 + 1 has no real root
+/// let mut xs = initial_aberth_real(&[1.0, 0.0, 1.0]).unwrap();
+/// assert!(!aberth_real(&[1.0, 0.0, 1.0], &mut xs, &Options::default()).1);
+/// ```
+pub fn aberth_real(coeffs: &[f64], xs: &mut [f64], options: &Options) -> (usize, bool) {
+    let mut converged = scratch_flags(xs.len());
+    for niter in 0..options.max_iters {
+        let mut tol = 0.0;
+        for i in 0..xs.len() {
+            if options.freeze_converged && converged[i] {
+                continue;
+            }
+            let xi = xs[i];
+            let (mut pp, mut pp1, mut pp2) = (0.0, 0.0, 0.0);
+            for &c in coeffs {
+                pp2 = pp2 * xi + 2.0 * pp1;
+                pp1 = pp1 * xi + pp;
+                pp = pp * xi + c;
+            }
+            let gap = pp1 * pp1 - pp * pp2;
+            if !xi.is_finite() || gap < -1e-12 * (pp1 * pp1 + (pp * pp2).abs()) {
+                return (niter, false);
+            }
+            let tol_i = pp.abs();
+            if tol_i < 1e-15 {
+                converged[i] = true;
+            }
+            for (_, xj) in xs.iter().enumerate().filter(|t| t.0 != i) {
+                pp1 -= pp / (xi - xj);
+            }
+            let dx = pp / pp1;
+            xs[i] -= match options.max_step {
+                Some(max_step) if dx.abs() > max_step => max_step.copysign(dx),
+                _ => dx,
+            }; // Gauss-Seidel fashion
+            tol = nan_max(tol, tol_i);
+        }
+        if tol < options.tol {
+            return (niter, true);
+        }
+    }
+    (options.max_iters, false)
+}
+
+/// Find all roots of a product of factors
+///
+/// The `find_roots_factored` function finds the roots of `P = P_1 P_2 ... P_m` with `aberth_with`
+/// and `horner_factors_c`, never expanding the product. Expanding a product of many factors
+/// perturbs the coefficients enough to move the roots noticeably at high degree, while evaluating
+/// the factors one by one stays accurate. The guesses of each factor are seeded with
+/// `initial_aberth_with`, with a phase shifted per factor so that repeated factors do not start from
+/// coincident guesses.
+///
+/// Arguments:
+///
+/// * `factors`: The factors, each ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `aberth_with`.
+///
+/// Returns:
+///
+/// The function `find_roots_factored` returns the root estimates, one per degree of the product,
+/// the error of `poly::degree` when a factor is empty or constant, or `RootError::NotConverged`
+/// when `aberth_with` does not converge.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::find_roots_factored;
+/// use bairstow::poly::Polynomial;
+/// use bairstow::rootfinding::Options;
+///
+/// let factors = [Polynomial(vec![1.0, 0.0, 1.0]), Polynomial(vec![1.0, -2.0])];
+/// let zs = find_roots_factored(&factors, &Options::default()).unwrap();
+///
+/// assert_eq!(zs.len(), 3);
+/// ```
+pub fn find_roots_factored(
+    factors: &[Polynomial],
+    options: &Options,
+) -> Result<Vec<Complex<f64>>, RootError> {
+    let mut zs = vec![];
+    for (idx, factor) in factors.iter().enumerate() {
+        let phase = 0.25 + idx as f64 / factors.len() as f64;
+        zs.extend(initial_aberth_with(&factor.0, None, phase)?);
+    }
+    let eval = |z: &Complex<f64>| horner_factors_c(factors, z);
+    let (niter, found) = aberth_with(eval, &mut zs, options);
+    if !found {
+        let tol = zs.iter().map(|z| eval(z).0.l1_norm()).fold(0.0, nan_max);
+        return Err(RootError::NotConverged { niter, tol });
+    }
+    Ok(zs)
+}
+
+/// Roots of a cascade of real quadratics
+///
+/// The `solve_quadratic_cascade` function solves every factor `a x^2 + b x + c` on its own and
+/// concatenates the roots, skipping the global iteration entirely. Real roots use the stable form
+/// `q = -(b + sign(b) sqrt(b^2 - 4ac)) / 2`, `x_1 = q / a`, `x_2 = c / q`, which avoids the
+/// cancellation of the textbook formula when `b^2` dominates `4ac`. A factor with `a = 0` is
+/// linear and contributes its single root, and `[0, 0, c]` contributes none.
+///
+/// Arguments:
+///
+/// * `factors`: The quadratics `[a, b, c]`, e.g. the denominators of cascaded biquad sections.
+///
+/// Returns:
+///
+/// The function `solve_quadratic_cascade` returns the roots factor by factor, with a complex
+/// conjugate pair listed as `re + i im`, `re - i im` for `im > 0`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::solve_quadratic_cascade;
+/// use num::Complex;
+///
+/// // (x^2 + 1)(x^2 - 3x + 2)
+/// let zs = solve_quadratic_cascade(&[[1.0, 0.0, 1.0], [1.0, -3.0, 2.0]]);
+///
+/// assert_eq!(zs[0], Complex::new(0.0, 1.0));
+/// assert_eq!(zs[1], Complex::new(0.0, -1.0));
+/// assert_eq!(zs[2], Complex::new(2.0, 0.0));
+/// assert_eq!(zs[3], Complex::new(1.0, 0.0));
+/// ```
+pub fn solve_quadratic_cascade(factors: &[[f64; 3]]) -> Vec<Complex<f64>> {
+    let mut zs = Vec::with_capacity(2 * factors.len());
+    for &[a, b, c] in factors.iter() {
+        let disc = b * b - 4.0 * a * c;
+        if disc < 0.0 {
+            let re = -b / (2.0 * a);
+            let im = (-disc).sqrt() / (2.0 * a).abs();
+            zs.push(Complex::new(re, im));
+            zs.push(Complex::new(re, -im));
+            continue;
+        }
+        let q = -0.5 * (b + disc.sqrt().copysign(b));
+        if a != 0.0 {
+            zs.push(Complex::new(q / a, 0.0));
+        }
+        if q != 0.0 {
+            zs.push(Complex::new(c / q, 0.0));
+        } else if a != 0.0 {
+            zs.push(Complex::new(0.0, 0.0)); // b = c = 0: a double root at zero
+        }
+    }
+    zs
+}
+
+/// Find all roots of a polynomial with Aberth's method
+///
+/// The `find_roots` function first drops the leading zero coefficients, see `poly::classify_poly`,
+/// and then factors out `x^m` when the `m` trailing coefficients are zero
+/// (below `options.zero_threshold` times the largest coefficient), reporting `m` roots at the origin.
+/// The remaining roots are seeded with `initial_aberth` and refined with `aberth`. If that fails
+/// and `options.graeffe_steps` is non-zero, the roots of the Graeffe root-squared polynomial are
+/// found instead, whose magnitudes are better separated, and mapped back to seed a second `aberth`
+/// run on the original polynomial.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `aberth`.
+///
+/// Returns:
+///
+/// The function `find_roots` returns the root estimates, one per degree once the leading zeros are
+/// dropped, and no roots for a non-zero constant. It returns `RootError::ZeroPolynomial`
+/// when every coefficient is zero, see `poly::classify_poly`, the error of `poly::degree` for
+/// invalid coefficients, and `RootError::NotConverged` when neither `aberth` run converges.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::{find_roots, horner_eval_c};
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let zs = find_roots(&coeffs, &Options::default()).unwrap();
+///
+/// assert_eq!(zs.len(), 8);
+/// assert!(horner_eval_c(&coeffs, &zs[0]).norm() < 1e-10);
+///
+/// // x^3 (x - 1)(x - 2): the zero roots come last
+/// let zs = find_roots(&[1.0, -3.0, 2.0, 0.0, 0.0, 0.0], &Options::default()).unwrap();
+///
+/// assert_eq!(zs.len(), 5);
+/// assert!(zs[2..].iter().all(|z| z.norm() == 0.0));
+///
+/// // the leading zero is dropped: x^2 - 3x + 2
+/// assert_eq!(find_roots(&[0.0, 1.0, -3.0, 2.0], &Options::default()).unwrap().len(), 2);
+/// assert_eq!(find_roots(&[5.0], &Options::default()), Ok(vec![]));
+/// assert_eq!(find_roots(&[0.0], &Options::default()), Err(bairstow::RootError::ZeroPolynomial));
+/// ```
+pub fn find_roots(coeffs: &[f64], options: &Options) -> Result<Vec<Complex<f64>>, RootError> {
+    use crate::poly::PolyClass;
+
+    let coeffs = match crate::poly::classify_poly(coeffs) {
+        PolyClass::Zero if coeffs.is_empty() => return Err(RootError::Empty),
+        PolyClass::Zero => return Err(RootError::ZeroPolynomial),
+        PolyClass::Constant if coeffs.iter().all(|c| c.is_finite()) => return Ok(vec![]),
+        PolyClass::Constant => return Err(RootError::NonFinite),
+        PolyClass::Degree(n) => &coeffs[coeffs.len() - n - 1..],
+    };
+    crate::poly::degree(coeffs)?;
+    let m = zero_root_multiplicity(coeffs, options.zero_threshold);
+    let reduced = &coeffs[..coeffs.len() - m];
+    let mut zs = if reduced.len() > 1 {
+        find_nonzero_roots(reduced, options)?
+    } else {
+        vec![]
+    };
+    zs.resize(zs.len() + m, Complex::new(0.0, 0.0));
+    Ok(zs)
+}
+
+/// Find all roots of a polynomial around their centroid
+///
+/// The `find_roots_centered` function moves the origin to the centroid `c = -a_1 / (n a_0)` of the
+/// roots, which is also the mean of the `initial_aberth` guesses, solves `P(y + c)` (see
+/// `poly::shift_poly`) with `find_roots`, and adds `c` back. When the roots cluster around a point
+/// far from the origin, `P` evaluated in the original variable cancels catastrophically near the
+/// cluster, while the shifted polynomial sees the roots at their true spacing.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `find_roots_centered` returns the root estimates, one per degree, or the error of
+/// `find_roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::find_roots_centered;
+///
+/// // (x - 999)(x - 1000)(x - 1001)
+/// let zs = find_roots_centered(&[1.0, -3000.0, 2999999.0, -999999000.0], &Options::default())
+///     .unwrap();
+///
+/// assert_eq!(zs.len(), 3);
+/// assert!(zs.iter().any(|z| (z.re - 1001.0).abs() < 1e-9));
+/// ```
+pub fn find_roots_centered(
+    coeffs: &[f64],
+    options: &Options,
+) -> Result<Vec<Complex<f64>>, RootError> {
+    let degree = match crate::poly::degree(coeffs) {
+        Ok(degree) => degree,
+        Err(_) => return find_roots(coeffs, options),
+    };
+    let center = -coeffs[1] / (coeffs[0] * degree as f64);
+    let shifted = crate::poly::shift_poly(coeffs, center);
+    Ok(find_roots(&shifted, options)?
+        .into_iter()
+        .map(|y| y + center)
+        .collect())
+}
+
+/// Find all roots of a polynomial with integer coefficients
+///
+/// The `find_roots_i64` function is `find_roots` for exact integer coefficients. It keeps the
+/// setup exact: the zero roots are the trailing zero coefficients, and the rest of the polynomial
+/// is shifted to the centroid `c = -a_1 / (n a_0)` of its roots in `BigRational`,
+/// `Q(y) = P(y + c)`, before its coefficients are rounded to `f64` once. In `f64`, the
+/// coefficients of a polynomial whose roots cluster far from the origin lose the spacing of the
+/// roots, e.g. `x^2 - 2 * 10^8 x + 10^16 - 1` rounds to `(x - 10^8)^2`.
+/// `Q` sees the roots at their true spacing around the origin; it is solved with `find_roots` and
+/// `c`, rounded to `f64`, is added back. When `c` or a coefficient of `Q` is out of the range of
+/// `f64`, the unshifted coefficients rounded to `f64` are solved instead.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of integer coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `find_roots_i64` returns the root estimates, one per degree, the zero roots last,
+/// the error of `poly::degree` for invalid coefficients, or the error of `find_roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::find_roots_i64;
+/// use bairstow::rootfinding::Options;
+///
+/// // (x - 3)(x + 4) x
+/// let zs = find_roots_i64(&[1, 1, -12, 0], &Options::default()).unwrap();
+///
+/// assert_eq!(zs.len(), 3);
+/// assert!(zs.iter().any(|z| (z.re - 3.0).abs() < 1e-12 && z.im.abs() < 1e-12));
+/// assert_eq!(zs[2].re, 0.0);
+/// ```
+pub fn find_roots_i64(coeffs: &[i64], options: &Options) -> Result<Vec<Complex<f64>>, RootError> {
+    let floats: Vec<f64> = coeffs.iter().map(|&c| c as f64).collect();
+    crate::poly::degree(&floats)?;
+    let m = coeffs[1..].iter().rev().take_while(|&&c| c == 0).count();
+    let reduced = &coeffs[..coeffs.len() - m];
+    let mut zs = if reduced.len() > 1 {
+        let (center, shifted) = shift_to_centroid_i64(reduced);
+        let ys = find_roots(&shifted, options)?;
+        ys.into_iter().map(|y| y + center).collect()
+    } else {
+        vec![]
+    };
+    zs.resize(zs.len() + m, Complex::new(0.0, 0.0));
+    Ok(zs)
+}
+
+/// The `shift_to_centroid_i64` function returns the centroid `c = -a_1 / (n a_0)` of the roots and
+/// the coefficients of `P(y + c)`, both computed exactly and rounded to `f64` once, see
+/// `find_roots_i64`. It returns `0` and the coefficients rounded to `f64` when a result is out of
+/// the range of `f64`. `coeffs` must have a non-zero leading coefficient and a degree of at
+/// least 1.
+fn shift_to_centroid_i64(coeffs: &[i64]) -> (f64, Vec<f64>) {
+    use num::{BigInt, BigRational, ToPrimitive};
+
+    let degree = coeffs.len() - 1;
+    let center = BigRational::new(
+        -BigInt::from(coeffs[1]),
+        BigInt::from(coeffs[0]) * BigInt::from(degree),
+    );
+    // the synthetic divisions of poly::shift_poly
+    let mut shifted: Vec<BigRational> = coeffs
+        .iter()
+        .map(|&c| BigRational::from_integer(BigInt::from(c)))
+        .collect();
+    for i in 0..degree {
+        for j in 1..=degree - i {
+            let carry = &shifted[j - 1] * &center;
+            shifted[j] += carry;
+        }
+    }
+    let rounded: Option<Vec<f64>> = std::iter::once(&center)
+        .chain(shifted.iter())
+        .map(|c| c.to_f64().filter(|c| c.is_finite()))
+        .collect();
+    match rounded {
+        Some(rounded) => (rounded[0], rounded[1..].to_vec()),
+        None => (0.0, coeffs.iter().map(|&c| c as f64).collect()),
+    }
+}
+
+/// Argument-principle count of the roots inside a circle
+///
+/// The `winding_number` function samples `P` at `samples` points on the circle `|z| = radius` and
+/// sums the changes of `arg P`, which by the argument principle is `2 pi` times the number of roots
+/// inside the circle. The samples must be dense enough that `arg P` changes by less than `pi`
+/// between neighbours.
+fn winding_number(coeffs: &[f64], radius: f64, samples: usize) -> usize {
+    let points: Vec<Complex<f64>> = (0..samples)
+        .map(|k| Complex::from_polar(radius, TWO_PI * k as f64 / samples as f64))
+        .collect();
+    let values = horner_with_derivative_c_many(coeffs, &points);
+    let total: f64 = (0..samples)
+        .map(|k| (values[(k + 1) % samples].0 / values[k].0).arg())
+        .sum();
+    (total / TWO_PI).round() as usize
+}
+
+/// Validate roots against independent counts
+///
+/// The `check_roots` function compares `roots` with two independent counts: the number of roots
+/// inside a disk containing all of them, by the argument principle on a circle of radius
+/// `2 * cauchy_bound(coeffs)`, and the number of distinct real roots, by `count_real_roots`. A root
+/// estimate counts as real when its imaginary part is below `1e-8 * max(1, |z|)`, and real estimates
+/// closer than that are counted once. The real count is only reliable for simple roots.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `roots`: The root estimates to check.
+///
+/// Returns:
+///
+/// The function `check_roots` returns `RootError::RootCountMismatch` when a count disagrees.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::check_roots;
+/// use num::Complex;
+///
+/// // (x^2 + 1)(x - 2)
+/// let coeffs = [1.0, -2.0, 1.0, -2.0];
+/// let roots = [Complex::new(0.0, 1.0), Complex::new(0.0, -1.0), Complex::new(2.0, 0.0)];
+///
+/// assert!(check_roots(&coeffs, &roots).is_ok());
+/// assert!(check_roots(&coeffs, &roots[..2]).is_err());
+/// ```
+pub fn check_roots(coeffs: &[f64], roots: &[Complex<f64>]) -> Result<(), RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    let radius = 2.0 * crate::poly::cauchy_bound(coeffs);
+    let inside = winding_number(coeffs, radius, 16 * degree);
+    let found = roots
+        .iter()
+        .filter(|z| z.is_finite() && z.norm() < radius)
+        .count();
+    if found != inside {
+        return Err(RootError::RootCountMismatch {
+            kind: "total",
+            expected: inside,
+            found,
+        });
+    }
+    let mut reals: Vec<f64> = roots
+        .iter()
+        .filter(|z| z.im.abs() <= 1e-8 * z.norm().max(1.0))
+        .map(|z| z.re)
+        .collect();
+    reals.sort_by(|a, b| a.total_cmp(b));
+    reals.dedup_by(|a, b| (*a - *b).abs() <= 1e-8 * a.abs().max(1.0));
+    let expected = crate::poly::count_real_roots(coeffs);
+    if reals.len() != expected {
+        return Err(RootError::RootCountMismatch {
+            kind: "real",
+            expected,
+            found: reals.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Find all roots of a polynomial and validate them
+///
+/// The `find_roots_checked` function runs `find_roots` and then `check_roots`, turning a missed or
+/// escaped root into an error at the cost of one extra counting pass. A non-zero constant has
+/// nothing to check and yields an empty vector, as in `find_roots`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `find_roots_checked` returns the roots, or the error of `find_roots` or
+/// `check_roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::find_roots_checked;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+///
+/// assert_eq!(find_roots_checked(&coeffs, &Options::default()).unwrap().len(), 8);
+/// ```
+pub fn find_roots_checked(
+    coeffs: &[f64],
+    options: &Options,
+) -> Result<Vec<Complex<f64>>, RootError> {
+    let zs = find_roots(coeffs, options)?;
+    if zs.is_empty() {
+        return Ok(zs);
+    }
+    check_roots(strip_leading_zeros(coeffs), &zs)?;
+    Ok(zs)
+}
+
+/// The `strip_leading_zeros` function drops the leading zero coefficients that
+/// `poly::classify_poly` skips, so that a polynomial of degree `n` keeps `n + 1` coefficients.
+fn strip_leading_zeros(coeffs: &[f64]) -> &[f64] {
+    match crate::poly::classify_poly(coeffs) {
+        crate::poly::PolyClass::Degree(n) => &coeffs[coeffs.len() - n - 1..],
+        _ => coeffs,
+    }
+}
+
+/// The `SolveReport` struct bundles the outcome of `find_roots_report`, e.g. to ship it to a
+/// dashboard. With the `serde` feature it derives `Serialize` and `Deserialize`.
+///
+/// Properties:
+///
+/// * `roots`: The root estimates, the zero roots last as in `find_roots`.
+/// * `residuals`: The residual `|P(z)|` (l1 norm) of every root, see `all_residuals`.
+/// * `niter`: The number of sweeps performed.
+/// * `reason`: Why the iteration stopped.
+/// * `residual_history`: The largest residual of every sweep, as compared with `options.tol`.
+/// * `elapsed`: The wall-clock time of the solve.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolveReport {
+    pub roots: Vec<Complex<f64>>,
+    pub residuals: Vec<f64>,
+    pub niter: usize,
+    pub reason: StopReason,
+    pub residual_history: Vec<f64>,
+    pub elapsed: std::time::Duration,
+}
+
+/// Find all roots with a full report
+///
+/// The `find_roots_report` function solves like `find_roots`, factoring out the zero roots and
+/// running Aberth's method from `initial_aberth` on the rest, and collects the diagnostics of the
+/// solve into a `SolveReport`. The Graeffe fallback of `find_roots` is not tried, so the report
+/// describes exactly one Aberth run. When the polynomial has only zero roots, the report shows no
+/// sweeps and `StopReason::Converged`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria.
+///
+/// Returns:
+///
+/// The function `find_roots_report` returns the report, or the error of `poly::degree` for invalid
+/// coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{find_roots_report, StopReason};
+/// use bairstow::rootfinding::Options;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let report = find_roots_report(&coeffs, &Options::default()).unwrap();
+///
+/// assert_eq!(report.reason, StopReason::Converged);
+/// assert_eq!(report.roots.len(), 8);
+/// assert_eq!(report.residual_history.len(), report.niter + 1);
+/// assert!(report.residual_history.last().unwrap() < &Options::default().tol);
+/// ```
+pub fn find_roots_report(coeffs: &[f64], options: &Options) -> Result<SolveReport, RootError> {
+    let start = std::time::Instant::now();
+    crate::poly::degree(coeffs)?;
+    let m = zero_root_multiplicity(coeffs, options.zero_threshold);
+    let reduced = &coeffs[..coeffs.len() - m];
+    let mut residual_history = Vec::new();
+    let (mut roots, niter, reason) = if reduced.len() > 1 {
+        let mut zs = initial_aberth(reduced)?;
+        let result = aberth_observed(reduced, &mut zs, options, true, |_, tol| {
+            residual_history.push(tol)
+        });
+        (zs, result.niter, result.reason)
+    } else {
+        (vec![], 0, StopReason::Converged)
+    };
+    roots.resize(roots.len() + m, Complex::new(0.0, 0.0));
+    Ok(SolveReport {
+        residuals: all_residuals(coeffs, &roots, NormKind::L1),
+        roots,
+        niter,
+        reason,
+        residual_history,
+        elapsed: start.elapsed(),
+    })
+}
+
+/// Vieta's formulas
+///
+/// The `vieta_check` function compares the sum and the product of `roots` with the values Vieta's
+/// formulas predict from the coefficients, `-a_1 / a_0` and `(-1)^n a_n / a_0`. It is an `O(n)`
+/// sanity check after solving, much cheaper than reconstructing the polynomial from the roots, but
+/// it cannot tell which root is wrong. The sum error is relative to `sum |z_i|` and the product
+/// error relative to `prod |z_i|`, the sizes of the rounding errors made when forming them, so that
+/// a vanishing sum (e.g. of `x^2 + 1`) still gives a small error. A zero scale leaves the error
+/// absolute.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `roots`: The root estimates to check, one per degree.
+///
+/// Returns:
+///
+/// The function `vieta_check` returns the relative errors `(sum, product)`, or infinities when
+/// the number of roots does not match the degree.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{find_roots, vieta_check};
+/// use bairstow::rootfinding::Options;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let zs = find_roots(&coeffs, &Options::default()).unwrap();
+/// let (sum_err, prod_err) = vieta_check(&coeffs, &zs);
+///
+/// assert!(sum_err < 1e-12 && prod_err < 1e-12);
+/// ```
+pub fn vieta_check(coeffs: &[f64], roots: &[Complex<f64>]) -> (f64, f64) {
+    if coeffs.len() < 2 || roots.len() != coeffs.len() - 1 {
+        return (f64::INFINITY, f64::INFINITY);
+    }
+    let degree = roots.len();
+    let sign = if degree % 2 == 1 { -1.0 } else { 1.0 };
+    let sum_pred = -coeffs[1] / coeffs[0];
+    let prod_pred = sign * coeffs[degree] / coeffs[0];
+    let sum: Complex<f64> = roots.iter().sum();
+    let prod: Complex<f64> = roots.iter().product();
+    let sum_scale: f64 = roots.iter().map(|z| z.norm()).sum();
+    let prod_scale: f64 = roots.iter().map(|z| z.norm()).product();
+    let relative = |err: f64, scale: f64| if scale > 0.0 { err / scale } else { err };
+    (
+        relative((sum - sum_pred).norm(), sum_scale),
+        relative((prod - prod_pred).norm(), prod_scale),
+    )
+}
+
+/// The `NormKind` enum selects how the complex residual `P(z)` is measured.
+///
+/// Variants:
+///
+/// * `L1`: `|re| + |im|`, the norm the Aberth stopping test and `AberthResult::tol` use.
+/// * `L2`: The modulus `|P(z)|`.
+/// * `Inf`: `max(|re|, |im|)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormKind {
+    L1,
+    L2,
+    Inf,
+}
+
+impl NormKind {
+    /// The `apply` function measures `z` in this norm.
+    #[inline]
+    fn apply(self, z: Complex<f64>) -> f64 {
+        match self {
+            NormKind::L1 => z.l1_norm(),
+            NormKind::L2 => z.norm(),
+            NormKind::Inf => z.re.abs().max(z.im.abs()),
+        }
+    }
+}
+
+/// The `ResidualStats` struct summarizes the residuals `P(z_i)` over a set of roots.
+///
+/// Properties:
+///
+/// * `min`: The smallest residual.
+/// * `max`: The largest residual; NaN if any residual is NaN.
+/// * `mean`: The arithmetic mean.
+/// * `median`: The median, the mean of the two middle values for an even count.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResidualStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
+}
+
+/// Residuals of all roots
+///
+/// The `all_residuals` function evaluates `P` at every root with `horner_eval_c` and measures the
+/// residual in `norm`. With `NormKind::L1` it is exactly the per-root quantity of the Aberth
+/// stopping test, and its largest element is `AberthResult::tol`. The stopping test itself sees
+/// the estimates before the update of each sweep.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `roots`: The root estimates.
+/// * `norm`: How each complex residual is measured.
+///
+/// Returns:
+///
+/// The function `all_residuals` returns one residual per root, in the order of `roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{all_residuals, NormKind};
+/// use num::Complex;
+///
+/// // x^2 + 1 at i and 1 + i, where P = 1 + 2i
+/// let roots = [Complex::new(0.0, 1.0), Complex::new(1.0, 1.0)];
+///
+/// assert_eq!(all_residuals(&[1.0, 0.0, 1.0], &roots, NormKind::L1), vec![0.0, 3.0]);
+/// assert_eq!(all_residuals(&[1.0, 0.0, 1.0], &roots, NormKind::Inf), vec![0.0, 2.0]);
+/// ```
+pub fn all_residuals(coeffs: &[f64], roots: &[Complex<f64>], norm: NormKind) -> Vec<f64> {
+    roots
+        .iter()
+        .map(|z| norm.apply(horner_eval_c(coeffs, z)))
+        .collect()
+}
+
+/// Residual statistics
+///
+/// The `residual_stats` function evaluates `P` at every root with `horner_eval_c` and summarizes
+/// the residuals measured in `norm`, a short health report to log after a solve.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `roots`: The root estimates.
+/// * `norm`: How each complex residual is measured.
+///
+/// Returns:
+///
+/// The function `residual_stats` returns the minimum, maximum, mean and median residual, all NaN
+/// for an empty `roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::{residual_stats, NormKind};
+/// use num::Complex;
+///
+/// // x^2 - 1 at 1, -1 and 2
+/// let roots = [Complex::new(1.0, 0.0), Complex::new(-1.0, 0.0), Complex::new(2.0, 0.0)];
+/// let stats = residual_stats(&[1.0, 0.0, -1.0], &roots, NormKind::L2);
+///
+/// assert_eq!((stats.min, stats.max, stats.mean, stats.median), (0.0, 3.0, 1.0, 0.0));
+/// ```
+pub fn residual_stats(coeffs: &[f64], roots: &[Complex<f64>], norm: NormKind) -> ResidualStats {
+    let mut res = all_residuals(coeffs, roots, norm);
+    if res.is_empty() {
+        return ResidualStats {
+            min: f64::NAN,
+            max: f64::NAN,
+            mean: f64::NAN,
+            median: f64::NAN,
+        };
+    }
+    let mean = res.iter().sum::<f64>() / res.len() as f64;
+    res.sort_by(|a, b| a.total_cmp(b));
+    let mid = res.len() / 2;
+    let median = if res.len() % 2 == 1 {
+        res[mid]
+    } else {
+        0.5 * (res[mid - 1] + res[mid])
+    };
+    ResidualStats {
+        min: res.iter().copied().fold(f64::INFINITY, f64::min),
+        max: res.iter().copied().fold(0.0, nan_max),
+        mean,
+        median,
+    }
+}
+
+/// The `horner_with_derivative_cc` function is `horner_with_derivative_c` for complex coefficients.
+fn horner_with_derivative_cc(
+    coeffs: &[Complex<f64>],
+    zval: &Complex<f64>,
+) -> (Complex<f64>, Complex<f64>) {
+    let mut pp = Complex::<f64>::new(0.0, 0.0);
+    let mut pp1 = Complex::<f64>::new(0.0, 0.0);
+    for coeff in coeffs.iter() {
+        pp1 = pp1 * zval + pp;
+        pp = pp * zval + coeff;
+    }
+    (pp, pp1)
+}
+
+/// Find all roots of a polynomial with complex coefficients
+///
+/// The `find_roots_complex` function places the initial guesses on a circle around the centroid of
+/// the roots, like `initial_aberth`, and refines them with `aberth_with`. If the circle degenerates
+/// to a point, the Cauchy bound `1 + max_i |a_i / a_0|` is used as radius instead.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of complex coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `aberth_with`.
+///
+/// Returns:
+///
+/// The function `find_roots_complex` returns the root estimates, one per degree, or
+/// `RootError::Empty` or `RootError::ConstantPolynomial`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::find_roots_complex;
+/// use num::Complex;
+///
+/// // (x - i)(x - 2)
+/// let coeffs = [Complex::new(1.0, 0.0), Complex::new(-2.0, -1.0), Complex::new(0.0, 2.0)];
+/// let zs = find_roots_complex(&coeffs, &Options::default()).unwrap();
+///
+/// assert!(zs.iter().any(|z| (z - Complex::new(0.0, 1.0)).norm() < 1e-12));
+/// assert!(zs.iter().any(|z| (z - Complex::new(2.0, 0.0)).norm() < 1e-12));
+/// ```
+pub fn find_roots_complex(
+    coeffs: &[Complex<f64>],
+    options: &Options,
+) -> Result<Vec<Complex<f64>>, RootError> {
+    // the same checks, in the same order, as `poly::degree`
+    if coeffs.is_empty() {
+        return Err(RootError::Empty);
+    }
+    if coeffs.iter().any(|c| !c.is_finite()) {
+        return Err(RootError::NonFinite);
+    }
+    if coeffs.len() == 1 {
+        return Err(RootError::ConstantPolynomial);
+    }
+    if coeffs[0].norm() == 0.0 {
+        return Err(RootError::LeadingZero);
+    }
+    let degree = coeffs.len() - 1;
+    let center = -coeffs[1] / (coeffs[0] * degree as f64);
+    let (pc, _) = horner_with_derivative_cc(coeffs, &center);
+    let mut radius = (pc / coeffs[0]).norm().powf(1.0 / degree as f64);
+    if !(radius > 0.0 && radius.is_finite()) {
+        radius = 1.0
+            + coeffs[1..]
+                .iter()
+                .map(|c| (c / coeffs[0]).norm())
+                .fold(0.0, f64::max);
+    }
+    let mut zs: Vec<Complex<f64>> = (0..degree)
+        .map(|idx| {
+            let theta = TWO_PI * (0.25 + idx as f64) / degree as f64;
+            center + Complex::from_polar(radius, theta)
+        })
+        .collect();
+    aberth_with(|z| horner_with_derivative_cc(coeffs, z), &mut zs, options);
+    Ok(zs)
+}
+
+/// The `is_real_coeffs` function tells whether every imaginary part is at most `tol` times the
+/// largest coefficient magnitude, so that the polynomial can be solved as a real one.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of complex coefficients.
+/// * `tol`: The tolerance relative to the largest coefficient magnitude.
+///
+/// Returns:
+///
+/// The function `is_real_coeffs` returns `true` for (approximately) real coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::is_real_coeffs;
+/// use num::Complex;
+///
+/// assert!(is_real_coeffs(&[Complex::new(1.0, 1e-17), Complex::new(-2.0, 0.0)], 1e-14));
+/// assert!(!is_real_coeffs(&[Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)], 1e-14));
+/// ```
+pub fn is_real_coeffs(coeffs: &[Complex<f64>], tol: f64) -> bool {
+    let scale = coeffs.iter().map(|c| c.norm()).fold(0.0, f64::max);
+    coeffs.iter().all(|c| c.im.abs() <= tol * scale)
+}
+
+/// Find all roots, choosing the real or the complex solver
+///
+/// The `find_roots_auto` function checks the coefficients with `is_real_coeffs` (relative tolerance
+/// `8 * f64::EPSILON`) and solves real polynomials with `find_roots` on the real parts, which also
+/// factors out zero roots and can use the Graeffe fallback, and others with `find_roots_complex`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of complex coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria.
+///
+/// Returns:
+///
+/// The function `find_roots_auto` returns the root estimates, one per degree, or the error of the
+/// solver used.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::find_roots_auto;
+/// use num::Complex;
+///
+/// let coeffs = [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+/// let zs = find_roots_auto(&coeffs, &Options::default()).unwrap();
+///
+/// assert_eq!(zs.len(), 2);
+/// ```
+pub fn find_roots_auto(
+    coeffs: &[Complex<f64>],
+    options: &Options,
+) -> Result<Vec<Complex<f64>>, RootError> {
+    if is_real_coeffs(coeffs, REAL_COEFF_TOL) {
+        let re: Vec<f64> = coeffs.iter().map(|c| c.re).collect();
+        find_roots(&re, options)
+    } else {
+        find_roots_complex(coeffs, options)
+    }
+}
+
+/// The `zero_root_multiplicity` function counts the trailing coefficients that are zero relative
+/// to the largest coefficient, i.e. the multiplicity `m` of the root `x = 0`.
+pub(crate) fn zero_root_multiplicity(coeffs: &[f64], zero_threshold: f64) -> usize {
+    let scale = coeffs.iter().fold(0.0_f64, |m, c| m.max(c.abs()));
+    let threshold = zero_threshold * scale;
+    coeffs[1..]
+        .iter()
+        .rev()
+        .take_while(|c| c.abs() <= threshold)
+        .count()
+}
+
+/// The `find_nonzero_roots` function runs Aberth's method, with the Graeffe fallback, on a
+/// polynomial without zero roots, and returns `RootError::NotConverged` when the last attempt
+/// does not converge.
+fn find_nonzero_roots(coeffs: &[f64], options: &Options) -> Result<Vec<Complex<f64>>, RootError> {
+    let mut zs = initial_aberth(coeffs)?;
+    let result = aberth_observed(coeffs, &mut zs, options, false, |_, _| {});
+    if result.reason == StopReason::Converged {
+        return Ok(zs);
+    }
+    if options.graeffe_steps == 0 {
+        return Err(not_converged(&result));
+    }
+    let mut zs = graeffe_seeds(coeffs, options)?;
+    let result = aberth_observed(coeffs, &mut zs, options, false, |_, _| {});
+    match result.reason {
+        StopReason::Converged => Ok(zs),
+        _ => Err(not_converged(&result)),
+    }
+}
+
+/// The `not_converged` function turns the outcome of an unconverged Aberth run into
+/// `RootError::NotConverged`.
+fn not_converged(result: &AberthResult) -> RootError {
+    RootError::NotConverged {
+        niter: result.niter,
+        tol: result.tol,
+    }
+}
+
+/// Solve the `options.graeffe_steps` times root-squared polynomial and take back the `2^k`-th root
+/// of each solution, picking the branch with the smallest residual on `coeffs` that has not been
+/// taken by a previous root.
+fn graeffe_seeds(coeffs: &[f64], options: &Options) -> Result<Vec<Complex<f64>>, RootError> {
+    let steps = options.graeffe_steps;
+    let mut pg = coeffs.to_vec();
+    for _ in 0..steps {
+        pg = crate::poly::graeffe_step(&pg);
+        // rescale to keep the squared coefficients in range; the roots are unchanged
+        let scale = pg.iter().fold(0.0_f64, |m, c| m.max(c.abs()));
+        pg.iter_mut().for_each(|c| *c /= scale);
+    }
+    let mut ws = initial_aberth(&pg)?;
+    aberth(
+        &pg,
+        &mut ws,
+        &Options {
+            graeffe_steps: 0,
+            cancel: options.cancel.clone(),
+            ..*options
+        },
+    );
+
+    let branches = 1_usize << steps;
+    let mut zs: Vec<Complex<f64>> = Vec::with_capacity(ws.len());
+    for w in ws.iter() {
+        let base = w.powf(1.0 / branches as f64);
+        let mut best: Option<(f64, Complex<f64>)> = None;
+        for m in 0..branches {
+            let z = base * Complex::from_polar(1.0, TWO_PI * m as f64 / branches as f64);
+            let taken = zs
+                .iter()
+                .any(|zj| (zj - z).norm() <= 1e-8 * (1.0 + z.norm()));
+            if taken {
+                continue;
+            }
+            let res = horner_eval_c(coeffs, &z).norm();
+            if best.is_none_or(|(r, _)| res < r) {
+                best = Some((res, z));
+            }
+        }
+        zs.push(best.map_or(base, |(_, z)| z));
+    }
+    Ok(zs)
+}
+
+/// Track the roots along a coefficient homotopy
+///
+/// The `track_roots` function moves the coefficients linearly from `start` to `target` in `steps`
+/// equal steps and follows the roots, warm-starting `aberth` at every step from the roots of the
+/// previous one, so that `trajectory[k][i]` is the same root for every `k`. A step is halved while
+/// `aberth` fails or some root moves by more than half the minimum separation of the previous roots,
+/// which would risk swapping labels; the halving stops at `2^-20` of a step.
+///
+/// Arguments:
+///
+/// * `start`: The initial polynomial, ordered from highest degree to lowest degree.
+/// * `target`: The final polynomial, with as many coefficients as `start`.
+/// * `steps`: The number of steps between `start` and `target`.
+/// * `options`: The stopping criteria passed on to `find_roots` and `aberth`.
+///
+/// Returns:
+///
+/// The function `track_roots` returns up to `steps + 1` root vectors, from the roots of `start` to
+/// those of `target`. It returns `RootError::BufferLength` when `target` and `start` differ in
+/// length, or the error of `find_roots` for `start`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::track_roots;
+///
+/// // from (x - 1)(x - 2) to (x - 1.5)(x - 2)
+/// let trajectory = track_roots(&[1.0, -3.0, 2.0], &[1.0, -3.5, 3.0], 10, &Options::default());
+///
+/// assert_eq!(trajectory.unwrap().len(), 11);
+/// assert!(track_roots(&[1.0, -3.0, 2.0], &[1.0, -3.5], 10, &Options::default()).is_err());
+/// ```
+pub fn track_roots(
+    start: &[f64],
+    target: &[f64],
+    steps: usize,
+    options: &Options,
+) -> Result<Vec<Vec<Complex<f64>>>, RootError> {
+    if target.len() != start.len() {
+        return Err(RootError::BufferLength {
+            name: "target",
+            expected: start.len(),
+            found: target.len(),
+        });
+    }
+    let zs = find_roots(start, options)?;
+    let coeffs_at = |t: f64| -> Vec<f64> {
+        start
+            .iter()
+            .zip(target.iter())
+            .map(|(a, b)| a + t * (b - a))
+            .collect()
+    };
+    let mut trajectory = vec![zs];
+    let dt_min = 1.0 / (steps as f64 * (1 << 20) as f64);
+    let mut t = 0.0;
+    for k in 1..=steps {
+        let t_end = k as f64 / steps as f64;
+        let mut dt = t_end - t;
+        let mut zs = trajectory[trajectory.len() - 1].clone();
+        while t < t_end {
+            let t_next = (t + dt).min(t_end);
+            let coeffs = coeffs_at(t_next);
+            let mut zs_next = zs.clone();
+            let (_, found) = aberth(&coeffs, &mut zs_next, options);
+            let (sep, _) = crate::roots::root_separation(&zs);
+            let moved = zs
+                .iter()
+                .zip(zs_next.iter())
+                .map(|(z0, z1)| (z1 - z0).norm())
+                .fold(0.0, nan_max);
+            if !(found && moved < 0.5 * sep) && dt > dt_min {
+                dt *= 0.5;
+                continue;
+            }
+            if zs_next.iter().any(|z| !z.is_finite()) {
+                return Ok(trajectory);
+            }
+            zs = zs_next;
+            t = t_next;
+            dt *= 2.0;
+        }
+        trajectory.push(zs);
+    }
+    Ok(trajectory)
+}
+
+/// Refine the root nearest to a point
+///
+/// The `nearest_root` function runs Newton's method from `near` without solving for the other roots.
+/// Some root lies within `n |P(near) / P'(near)|` of `near`, where `n` is the degree; if an iterate
+/// leaves that disk, Newton is drifting towards a farther root, so the iteration restarts from
+/// `near` with every step damped by half as much again.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `near`: The point whose nearest root is wanted.
+/// * `options`: `max_iters` bounds the Newton steps per attempt; the iteration stops when `|P|`
+///   drops below `tol` or the step is at the rounding level.
+///
+/// Returns:
+///
+/// The function `nearest_root` returns the refined root, or the error of `poly::degree` for invalid
+/// coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::nearest_root;
+/// use num::Complex;
+///
+/// // (x - 1)(x - 2)(x - 4)
+/// let z = nearest_root(&[1.0, -7.0, 14.0, -8.0], Complex::new(2.4, 0.3), &Options::default())
+///     .unwrap();
+///
+/// assert!((z - 2.0).norm() < 1e-12);
+/// ```
+pub fn nearest_root(
+    coeffs: &[f64],
+    near: Complex<f64>,
+    options: &Options,
+) -> Result<Complex<f64>, RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    let (pp, pp1) = horner_with_derivative_c(coeffs, &near);
+    if pp.norm() == 0.0 {
+        return Ok(near);
+    }
+    let radius = degree as f64 * (pp / pp1).norm();
+    let mut damping = 1.0;
+    let mut best = near;
+    for _ in 0..MAX_RESTARTS {
+        let mut z = near;
+        let mut inside = true;
+        for _ in 0..options.max_iters {
+            let (pp, pp1) = horner_with_derivative_c(coeffs, &z);
+            if pp.l1_norm() < options.tol {
+                break;
+            }
+            let dz = damping * pp / pp1;
+            z -= dz;
+            if (z - near).norm() > radius || !z.is_finite() {
+                inside = false;
+                break;
+            }
+            if dz.norm() <= 4.0 * f64::EPSILON * z.norm() {
+                break;
+            }
+        }
+        if inside {
+            return Ok(z);
+        }
+        best = z;
+        damping *= 0.5;
+    }
+    Ok(best)
+}
+
+/// Polish a root by inverse iteration on the companion matrix
+///
+/// The `inverse_iteration_polish` function runs inverse iteration with the fixed shift `root` on
+/// the companion matrix `C` of the monic `P`, whose first row is `-a_1/a_0, ..., -a_n/a_0` and
+/// whose subdiagonal is one. The shifted system `(C - root I) x = b` is solved in `O(n)` from the
+/// structure of `C`: the subdiagonal rows give every `x_i` as an affine function of `x_n`, and the
+/// first row fixes `x_n`, with `P(root)` as its pivot. The dense matrix is never formed. The
+/// eigenvalue estimate is `root + b_j / x_j` for the largest component `x_j`, and `x` is carried
+/// as the next `b`. The first `b` is the eigenvector `(root^(n-1), ..., root, 1)` that `root`
+/// would have if it were exact, which makes the first step a Newton step. Unlike Newton or
+/// Laguerre, the later steps keep the shift and the vector, so every step shrinks the error by
+/// about `|z - root| / |z' - root|`, where `z` is the nearest eigenvalue and `z'` the next one.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `root`: The root estimate, used as the shift.
+/// * `steps`: The number of inverse iteration steps.
+///
+/// Returns:
+///
+/// The function `inverse_iteration_polish` returns the polished root. It returns `root` unchanged
+/// when `poly::degree` rejects the coefficients, when `P(root)` is exactly zero, or after a step
+/// that is not finite.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::inverse_iteration_polish;
+/// use num::Complex;
+///
+/// // (x - 1)(x - 2)(x - 4)
+/// let z = inverse_iteration_polish(&[1.0, -7.0, 14.0, -8.0], Complex::new(2.01, 0.01), 5);
+///
+/// assert!((z - 2.0).norm() < 1e-10);
+/// ```
+pub fn inverse_iteration_polish(coeffs: &[f64], root: Complex<f64>, steps: usize) -> Complex<f64> {
+    let degree = match crate::poly::degree(coeffs) {
+        Ok(degree) => degree,
+        Err(_) => return root,
+    };
+    let pivot = horner_eval_c(coeffs, &root) / coeffs[0];
+    if pivot.norm() == 0.0 {
+        return root;
+    }
+    // the eigenvector `root` would have, scaled so that no power overflows
+    let ratio = if root.norm() > 1.0 { root.inv() } else { root };
+    let mut b = vec![Complex::<f64>::new(1.0, 0.0); degree];
+    if root.norm() > 1.0 {
+        for i in 1..degree {
+            b[i] = b[i - 1] * ratio;
+        }
+    } else {
+        for i in (0..degree - 1).rev() {
+            b[i] = b[i + 1] * ratio;
+        }
+    }
+    let mut x = vec![Complex::<f64>::new(0.0, 0.0); degree];
+    let mut z = root;
+    for _ in 0..steps {
+        companion_shifted_solve(coeffs, root, pivot, &b, &mut x);
+        let (j, xj) = x
+            .iter()
+            .copied()
+            .enumerate()
+            .max_by(|(_, u), (_, v)| u.norm().total_cmp(&v.norm()))
+            .unwrap_or((0, Complex::new(0.0, 0.0)));
+        let estimate = root + b[j] / xj;
+        if !estimate.is_finite() {
+            break;
+        }
+        z = estimate;
+        for (bi, xi) in b.iter_mut().zip(x.iter()) {
+            *bi = xi / xj;
+        }
+    }
+    z
+}
+
+/// The `companion_shifted_solve` function solves `(C - sigma I) x = b` for the companion matrix `C`
+/// of `inverse_iteration_polish` in `O(n)`; `pivot` is `P(sigma) / a_0`. The rows
+/// `x_{i-1} - sigma x_i = b_i` give `x_i = sigma^(n-i) x_n + s_i`, and substituting into the first
+/// row gives `pivot * x_n = -(b_1 + sigma s_1 + sum c_i s_i)` with `c_i = a_i / a_0`.
+fn companion_shifted_solve(
+    coeffs: &[f64],
+    sigma: Complex<f64>,
+    pivot: Complex<f64>,
+    b: &[Complex<f64>],
+    x: &mut [Complex<f64>],
+) {
+    let n = b.len();
+    let mut s = Complex::<f64>::new(0.0, 0.0);
+    let mut rhs = Complex::<f64>::new(0.0, 0.0);
+    for i in (0..n).rev() {
+        rhs += s * (coeffs[i + 1] / coeffs[0]);
+        if i > 0 {
+            s = b[i] + sigma * s;
+        }
+    }
+    rhs += b[0] + sigma * s;
+    x[n - 1] = -rhs / pivot;
+    for i in (1..n).rev() {
+        x[i - 1] = b[i] + sigma * x[i];
+    }
+}
+
+/// The `deflation_estimates` function finds the roots one at a time with `nearest_root` and divides
+/// each out with `deflate_linear_with` in the direction `options.deflation` or, for a complex
+/// pair, `deflate_quadratic`. Every search starts inside the smallest root magnitude, from half
+/// the lower bound `1 / cauchy_bound` of the reversed polynomial, so that the small roots are
+/// deflated first. A root whose imaginary part is at most `REAL_ROOT_TOL * |z|` is deflated as
+/// real. The estimates carry the accumulated deflation error. `on_deflate` receives the quotient
+/// left after every root, see `solve_by_deflation_traced`.
+fn deflation_estimates<F>(coeffs: &[f64], options: &Options, mut on_deflate: F) -> Vec<Complex<f64>>
+where
+    F: FnMut(&[f64]),
+{
+    use crate::poly::{cauchy_bound, deflate_linear_with, deflate_quadratic};
+
+    let mut pd = coeffs.to_vec();
+    let mut zs = Vec::with_capacity(coeffs.len().saturating_sub(1));
+    while pd.len() > 3 {
+        let reversed: Vec<f64> = pd.iter().rev().copied().collect();
+        let seed = Complex::from_polar(0.5 / cauchy_bound(&reversed), 1.0);
+        let z = nearest_root(&pd, seed, options).unwrap_or(seed);
+        if z.im.abs() <= REAL_ROOT_TOL * z.norm() {
+            zs.push(Complex::new(z.re, 0.0));
+            pd = deflate_linear_with(&pd, z.re, options.deflation).0;
+            on_deflate(&pd);
+        } else {
+            zs.push(z);
+            zs.push(z.conj());
+            pd = deflate_quadratic(&pd, &Vector2::new(2.0 * z.re, -z.norm_sqr()));
+            on_deflate(&pd);
+            on_deflate(&pd);
+        }
+    }
+    match pd[..] {
+        [a, b, c] => {
+            let pair = solve_quadratic_cascade(&[[a, b, c]]);
+            if pair[0].im == 0.0 {
+                on_deflate(&deflate_linear_with(&pd, pair[0].re, options.deflation).0);
+            } else {
+                on_deflate(&[a]);
+            }
+            on_deflate(&[a]);
+            zs.extend(pair);
+        }
+        [a, b] => {
+            zs.push(Complex::new(-b / a, 0.0));
+            on_deflate(&[a]);
+        }
+        _ => {}
+    }
+    zs
+}
+
+/// Sequential solving by deflation
+///
+/// The `solve_by_deflation` function finds one root at a time with a guarded Newton iteration
+/// (`nearest_root`), divides it out, and solves the smaller polynomial, finishing with the
+/// quadratic formula. Only the current quotient is kept, so the memory is `O(n)`. Deflation
+/// perturbs every quotient a little and the error accumulates, so all estimates are finally
+/// polished together with `aberth` against the *original* polynomial; the simultaneous update
+/// keeps two estimates from being polished onto the same root.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria of the Newton searches and of the polish, and the
+///   direction `options.deflation` of the real deflations.
+///
+/// Returns:
+///
+/// The function `solve_by_deflation` returns one root per degree, the error of `poly::degree`, or
+/// `RootError::NotConverged` when the polish does not converge.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::{horner_eval_c, solve_by_deflation};
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let zs = solve_by_deflation(&coeffs, &Options::default()).unwrap();
+///
+/// assert_eq!(zs.len(), 8);
+/// assert!(zs.iter().all(|z| horner_eval_c(&coeffs, z).norm() < 1e-10));
+/// ```
+pub fn solve_by_deflation(
+    coeffs: &[f64],
+    options: &Options,
+) -> Result<Vec<Complex<f64>>, RootError> {
+    crate::poly::degree(coeffs)?;
+    let mut zs = deflation_estimates(coeffs, options, |_| {});
+    let result = aberth_observed(coeffs, &mut zs, options, false, |_, _| {});
+    if result.reason != StopReason::Converged {
+        return Err(not_converged(&result));
+    }
+    Ok(zs)
+}
+
+/// Sequential solving by deflation with a trace of the quotients
+///
+/// The `solve_by_deflation_traced` function runs `solve_by_deflation` and keeps every deflated
+/// polynomial, to find the step where deflation drifts, e.g. where the coefficient magnitudes
+/// blow up. There is one quotient per root: a complex pair is divided out as one real quadratic
+/// factor, so its quotient is listed for both roots of the pair. The degrees thus decrease from
+/// `degree - 1` to the constant `a_0` left after the last root. Keeping the trace costs `O(n^2)`
+/// memory.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria of the Newton searches and of the polish.
+///
+/// Returns:
+///
+/// The function `solve_by_deflation_traced` returns the roots of `solve_by_deflation` and the
+/// quotients in the order they were formed, or the error of `solve_by_deflation`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::solve_by_deflation_traced;
+///
+/// // (x - 1)(x - 2)(x - 4)
+/// let (zs, trace) =
+///     solve_by_deflation_traced(&[1.0, -7.0, 14.0, -8.0], &Options::default()).unwrap();
+///
+/// assert_eq!(zs.len(), 3);
+/// assert_eq!(trace.len(), 3);
+/// assert_eq!(trace[2], vec![1.0]);
+/// ```
+pub fn solve_by_deflation_traced(
+    coeffs: &[f64],
+    options: &Options,
+) -> Result<DeflationTrace, RootError> {
+    crate::poly::degree(coeffs)?;
+    let mut trace = Vec::with_capacity(coeffs.len() - 1);
+    let mut zs = deflation_estimates(coeffs, options, |pd| trace.push(pd.to_vec()));
+    let result = aberth_observed(coeffs, &mut zs, options, false, |_, _| {});
+    if result.reason != StopReason::Converged {
+        return Err(not_converged(&result));
+    }
+    Ok((zs, trace))
+}
+
+/// The `DeflationTrace` type pairs the roots of `solve_by_deflation_traced` with its quotients.
+pub type DeflationTrace = (Vec<Complex<f64>>, Vec<Vec<f64>>);
+
+/// The `CriticalValue` type pairs a critical point of a polynomial with the value there.
+pub type CriticalValue = (Complex<f64>, Complex<f64>);
+
+/// Critical points and values
+///
+/// The `critical_values` function finds the roots of `P'` with `find_roots` and evaluates `P` at
+/// each of them with `horner_eval_c`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `critical_values` returns the `(critical point, critical value)` pairs, none when
+/// `P'` is constant, or the error of `poly::degree` for invalid coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::critical_values;
+///
+/// // x^3 - 3x has a maximum 2 at -1 and a minimum -2 at 1
+/// let mut cvs = critical_values(&[1.0, 0.0, -3.0, 0.0], &Options::default()).unwrap();
+/// cvs.sort_by(|a, b| a.0.re.partial_cmp(&b.0.re).unwrap());
+///
+/// assert!((cvs[0].0.re + 1.0).abs() < 1e-12 && (cvs[0].1.re - 2.0).abs() < 1e-12);
+/// assert!((cvs[1].0.re - 1.0).abs() < 1e-12 && (cvs[1].1.re + 2.0).abs() < 1e-12);
+/// ```
+pub fn critical_values(coeffs: &[f64], options: &Options) -> Result<Vec<CriticalValue>, RootError> {
+    crate::poly::degree(coeffs)?;
+    let pb = crate::poly::derivative(coeffs);
+    Ok(find_roots(&pb, options)?
+        .into_iter()
+        .map(|z| (z, horner_eval_c(coeffs, &z)))
+        .collect())
+}
+
+/// Intervals isolating the real roots by the critical points
+///
+/// The `interlace_bounds` function solves `P'` with `find_roots`, keeps its real roots (imaginary
+/// part below `1e-8 * max(1, |z|)`), and returns the intervals between consecutive critical points,
+/// with `-inf` and `+inf` at the ends. `P` is monotonic on each interval, so each contains at most
+/// one real root of `P`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `interlace_bounds` returns the intervals `(lo, hi)` in increasing order, or the
+/// error of `critical_values`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::interlace_bounds;
+///
+/// // x^2 - 1 has a single critical point at 0
+/// let bounds = interlace_bounds(&[1.0, 0.0, -1.0], &Options::default()).unwrap();
+///
+/// assert_eq!(bounds.len(), 2);
+/// assert_eq!(bounds[0].0, f64::NEG_INFINITY);
+/// assert!(bounds[0].1.abs() < 1e-12);
+/// ```
+pub fn interlace_bounds(coeffs: &[f64], options: &Options) -> Result<Vec<(f64, f64)>, RootError> {
+    let crits = distinct_real(
+        critical_values(coeffs, options)?
+            .into_iter()
+            .map(|(z, _)| z),
+    );
+    let mut ends = vec![f64::NEG_INFINITY];
+    ends.extend(crits);
+    ends.push(f64::INFINITY);
+    Ok(ends.windows(2).map(|w| (w[0], w[1])).collect())
+}
+
+/// The `distinct_real` function keeps the real estimates (imaginary part below
+/// `1e-8 * max(1, |z|)`) and returns their real parts in increasing order, merging those within
+/// `1e-8` relative of each other.
+fn distinct_real<I>(zs: I) -> Vec<f64>
+where
+    I: IntoIterator<Item = Complex<f64>>,
+{
+    let mut reals: Vec<f64> = zs
+        .into_iter()
+        .filter(|z| z.im.abs() <= 1e-8 * z.norm().max(1.0))
+        .map(|z| z.re)
+        .collect();
+    reals.sort_by(|a, b| a.total_cmp(b));
+    reals.dedup_by(|a, b| (*a - *b).abs() <= 1e-8 * a.abs().max(1.0));
+    reals
+}
+
+/// Real roots of the derivative chain
+///
+/// The `derivative_root_tower` function solves `P`, `P'`, `P''`, ... down to the linear
+/// derivative with `find_roots` and keeps the distinct real roots of every level, as
+/// `interlace_bounds` does for `P'`. By Rolle's theorem, a real root of `P^(k+1)` lies between any
+/// two consecutive real roots of `P^(k)`, so every level brackets the one above it, which a
+/// real-root isolation can walk from the linear end up to `P`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `derivative_root_tower` returns `degree` levels, the sorted real roots of `P` first
+/// and those of the linear derivative last, or the error of `poly::degree` for invalid coefficients
+/// and of `find_roots` for a level that does not converge.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::derivative_root_tower;
+///
+/// // x^3 - x has the roots -1, 0, 1; 3x^2 - 1 has +-1/sqrt(3); 6x has 0
+/// let tower = derivative_root_tower(&[1.0, 0.0, -1.0, 0.0], &Options::default()).unwrap();
+///
+/// assert_eq!(tower.len(), 3);
+/// assert_eq!(tower.iter().map(|level| level.len()).collect::<Vec<_>>(), vec![3, 2, 1]);
+/// assert!((tower[1][1] - 1.0 / 3.0f64.sqrt()).abs() < 1e-12);
+/// ```
+pub fn derivative_root_tower(
+    coeffs: &[f64],
+    options: &Options,
+) -> Result<Vec<Vec<f64>>, RootError> {
+    crate::poly::degree(coeffs)?;
+    let mut level = coeffs.to_vec();
+    let mut tower = Vec::with_capacity(coeffs.len() - 1);
+    while level.len() > 1 {
+        tower.push(distinct_real(find_roots(&level, options)?));
+        level = crate::poly::derivative(&level);
+    }
+    Ok(tower)
+}
+
+/// Sensitivities of the roots with respect to the coefficients
+///
+/// The `root_gradients` function computes, for every simple root `z` of `P`, the derivative of `z`
+/// with respect to each coefficient. Perturbing `P` by `da_k z^(n-k)` moves a simple root by
+/// `-z^(n-k) / P'(z) da_k` to first order.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `roots`: The roots of the polynomial, e.g. as returned by `find_roots`.
+///
+/// Returns:
+///
+/// The function `root_gradients` returns one vector per root, holding `dz/dcoeffs[k]` for every
+/// `k`, or the error of `poly::degree` for invalid coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::root_gradients;
+/// use approx_eq::assert_approx_eq;
+/// use num::Complex;
+///
+/// // x^2 - 3x + 2 = (x - 1)(x - 2)
+/// let coeffs = vec![1.0, -3.0, 2.0];
+/// let grads = root_gradients(&coeffs, &[Complex::new(2.0, 0.0)]).unwrap();
+///
+/// assert_approx_eq!(grads[0][2].re, -1.0);
+/// assert_approx_eq!(grads[0][0].re, -4.0);
+/// ```
+pub fn root_gradients(
+    coeffs: &[f64],
+    roots: &[Complex<f64>],
+) -> Result<Vec<Vec<Complex<f64>>>, RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    Ok(roots
+        .iter()
+        .map(|zi| {
+            let (_, pp1) = horner_with_derivative_c(coeffs, zi);
+            let mut grad = vec![Complex::<f64>::new(0.0, 0.0); degree + 1];
+            let mut zk = Complex::<f64>::new(1.0, 0.0);
+            for k in (0..=degree).rev() {
+                grad[k] = -zk / pp1;
+                zk *= zi;
+            }
+            grad
+        })
+        .collect())
+}
+
+/// Condition numbers of the roots
+///
+/// The `root_condition` function computes the relative condition number of every root `z`,
+/// `sum_k |a_k| |z|^(n-k) / (|z| |P'(z)|)`, bounding the relative change of `z` per relative change
+/// of the coefficients. A root at the origin uses the absolute form without the `|z|` divisor.
+/// Multiple roots have an infinite condition number.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `roots`: The roots of the polynomial, e.g. as returned by `find_roots`.
+///
+/// Returns:
+///
+/// The function `root_condition` returns one condition number per root.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::root_condition;
+/// use num::Complex;
+///
+/// // (x - 1)(x - 2)
+/// let conds = root_condition(&[1.0, -3.0, 2.0], &[Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
+///
+/// assert_eq!(conds, vec![6.0, 6.0]);
+/// ```
+pub fn root_condition(coeffs: &[f64], roots: &[Complex<f64>]) -> Vec<f64> {
+    roots
+        .iter()
+        .map(|zi| {
+            let r = zi.norm();
+            let (_, pp1) = horner_with_derivative_c(coeffs, zi);
+            let bound = coeffs.iter().fold(0.0, |acc, c| acc * r + c.abs());
+            let scale = if r > 0.0 { r } else { 1.0 };
+            bound / (scale * pp1.norm())
+        })
+        .collect()
+}
+
+/// Overall conditioning of a polynomial
+///
+/// The `poly_condition` function solves the polynomial once with `find_roots` and returns the largest
+/// condition number among its roots, as computed by `root_condition`. Values above about `1e8` mean
+/// that `f64` arithmetic cannot be trusted for some root.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `poly_condition` returns the maximum root condition number, or the error of
+/// `poly::degree` or `find_roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::poly_condition;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+///
+/// assert!(poly_condition(&coeffs, &Options::default()).unwrap() < 1e3);
+/// ```
+pub fn poly_condition(coeffs: &[f64], options: &Options) -> Result<f64, RootError> {
+    crate::poly::degree(coeffs)?;
+    let zs = find_roots(coeffs, options)?;
+    Ok(root_condition(coeffs, &zs).into_iter().fold(0.0, nan_max))
+}
+
+/// Stability margin of a discrete-time system
+///
+/// The `stability_margin_discrete` function solves the characteristic polynomial with `find_roots`
+/// and returns `1 - max |z_i|`, the distance of the spectral radius from the unit circle. A
+/// positive margin means every root lies inside the unit disk, i.e. the system is stable. A NaN
+/// root gives a NaN margin, which is never mistaken for stability.
+///
+/// Arguments:
+///
+/// * `coeffs`: The characteristic polynomial, ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `stability_margin_discrete` returns the margin, or the error of `poly::degree` or
+/// `find_roots`; a constant has no roots to measure.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::stability_margin_discrete;
+///
+/// // (z - 0.5)(z + 0.25)
+/// let margin = stability_margin_discrete(&[1.0, -0.25, -0.125], &Options::default()).unwrap();
+///
+/// assert!((margin - 0.5).abs() < 1e-12);
+/// ```
+pub fn stability_margin_discrete(coeffs: &[f64], options: &Options) -> Result<f64, RootError> {
+    crate::poly::degree(coeffs)?;
+    let zs = find_roots(coeffs, options)?;
+    Ok(1.0 - zs.iter().map(|z| z.norm()).fold(0.0, nan_max))
+}
+
+/// Spectral split by the unit circle
+///
+/// The `split_by_unit_circle` function factors `P = A * B`, where the roots of `A` lie inside the
+/// unit circle and the roots of `B` on or outside it. It solves with `find_roots`, groups the roots
+/// by `|z| < 1` and rebuilds each group with `poly::from_roots`. `A` carries the leading
+/// coefficient of `P`, `B` is monic. A root very close to the unit circle may land in either
+/// factor, depending on the accuracy of the solve.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `split_by_unit_circle` returns the pair `(A, B)`, two empty vectors for a
+/// non-zero constant, which has no roots, or the error of `find_roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::split_by_unit_circle;
+///
+/// // 2 (x - 0.5)(x - 4)
+/// let (inside, outside) = split_by_unit_circle(&[2.0, -9.0, 4.0], &Options::default()).unwrap();
+///
+/// assert!((inside[0] - 2.0).abs() < 1e-12 && (inside[1] + 1.0).abs() < 1e-12);
+/// assert!((outside[0] - 1.0).abs() < 1e-12 && (outside[1] + 4.0).abs() < 1e-12);
+/// ```
+pub fn split_by_unit_circle(
+    coeffs: &[f64],
+    options: &Options,
+) -> Result<(Vec<f64>, Vec<f64>), RootError> {
+    let zs = find_roots(coeffs, options)?;
+    if zs.is_empty() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    // the coefficient of x^degree, after any leading zeros
+    let lead = coeffs[coeffs.len() - 1 - zs.len()];
+    let (inside, outside): (Vec<Complex<f64>>, Vec<Complex<f64>>) =
+        zs.into_iter().partition(|z| z.norm() < 1.0);
+    let mut inner = crate::poly::from_roots(&inside);
+    for c in inner.iter_mut() {
+        *c *= lead;
+    }
+    Ok((inner, crate::poly::from_roots(&outside)))
+}
+
+/// Stability margin of a continuous-time system
+///
+/// The `stability_margin_continuous` function solves the characteristic polynomial with
+/// `find_roots` and returns `-max re(s_i)`, the distance of the rightmost root from the imaginary
+/// axis. As for `stability_margin_discrete`, a positive margin means stable: every root lies in
+/// the open left half-plane.
+///
+/// Arguments:
+///
+/// * `coeffs`: The characteristic polynomial, ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `stability_margin_continuous` returns the margin, or the error of `poly::degree` or
+/// `find_roots`; a constant has no roots to measure.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::stability_margin_continuous;
+///
+/// // (s + 1)(s + 2)
+/// let margin = stability_margin_continuous(&[1.0, 3.0, 2.0], &Options::default()).unwrap();
+///
+/// assert!((margin - 1.0).abs() < 1e-12);
+/// ```
+pub fn stability_margin_continuous(coeffs: &[f64], options: &Options) -> Result<f64, RootError> {
+    crate::poly::degree(coeffs)?;
+    let zs = find_roots(coeffs, options)?;
+    Ok(-zs.iter().map(|z| z.re).fold(f64::NEG_INFINITY, nan_max))
+}
+
+/// Roots on the imaginary axis
+///
+/// The `imaginary_axis_roots` function finds the `y` for which `iy` is a root. Substituting
+/// `x = iy` splits the polynomial into `P(iy) = R(y) + i I(y)` with real polynomials `R` and `I`,
+/// and a real `y` solves `P(iy) = 0` exactly when it is a common root of `R` and `I`. The common
+/// roots are those of `poly_gcd(R, I, tol)`, which is solved with `find_roots`; a root of the GCD
+/// counts as real when its imaginary part is at most `tol * max(1, |y|)`. Unlike filtering the
+/// roots of `P` for a tiny real part, the decision whether a root lies on the axis is made by the
+/// GCD, so it does not depend on how accurately `P` itself is solved.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `tol`: The relative tolerance of the GCD and of the real test.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `imaginary_axis_roots` returns the `y` values in ascending order, repeated by
+/// their multiplicity in the GCD, the error of `poly::degree` for invalid coefficients, or the
+/// error of `find_roots` for the GCD.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::imaginary_axis_roots;
+///
+/// // (x^2 + 4)(x + 1): roots at 2i and -2i
+/// let ys = imaginary_axis_roots(&[1.0, 1.0, 4.0, 4.0], 1e-10, &Options::default()).unwrap();
+///
+/// assert_eq!(ys.len(), 2);
+/// assert!((ys[0] + 2.0).abs() < 1e-12);
+/// assert!((ys[1] - 2.0).abs() < 1e-12);
+/// ```
+pub fn imaginary_axis_roots(
+    coeffs: &[f64],
+    tol: f64,
+    options: &Options,
+) -> Result<Vec<f64>, RootError> {
+    let degree = crate::poly::degree(coeffs)?;
+    // x^m = i^m y^m, and i^m cycles through 1, i, -1, -i
+    let (re, im): (Vec<f64>, Vec<f64>) = coeffs
+        .iter()
+        .enumerate()
+        .map(|(k, a)| match (degree - k) % 4 {
+            0 => (*a, 0.0),
+            1 => (0.0, *a),
+            2 => (-a, 0.0),
+            _ => (0.0, -a),
+        })
+        .unzip();
+    let g = crate::poly::poly_gcd(&re, &im, tol);
+    if g.len() < 2 {
+        return Ok(vec![]);
+    }
+    let mut ys: Vec<f64> = find_roots(&g, options)?
+        .iter()
+        .filter(|y| y.im.abs() <= tol * y.norm().max(1.0))
+        .map(|y| y.re)
+        .collect();
+    ys.sort_by(|a, b| a.total_cmp(b));
+    Ok(ys)
+}
+
+/// Distinct roots
+///
+/// The `distinct_roots` function solves the square-free part `poly::squarefree(coeffs, options)`
+/// instead of the polynomial itself, so every geometrically distinct root is returned exactly once
+/// and Aberth's method never meets a multiple root, at which it converges only linearly and to
+/// `eps^(1/m)` accuracy. Unlike `roots::cluster_roots`, which groups the estimates of a full
+/// solve, the multiplicities are removed before solving. Roots closer than `options.zero_threshold`
+/// allows the GCD to resolve are merged too.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The GCD threshold and the stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `distinct_roots` returns one entry per distinct root, the error of `poly::degree`
+/// for invalid coefficients, or the error of `find_roots` for the square-free part.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::distinct_roots;
+///
+/// // (x - 1)^2 (x^2 + 1)
+/// let zs = distinct_roots(&[1.0, -2.0, 2.0, -2.0, 1.0], &Options::default()).unwrap();
+///
+/// assert_eq!(zs.len(), 3);
+/// ```
+pub fn distinct_roots(coeffs: &[f64], options: &Options) -> Result<Vec<Complex<f64>>, RootError> {
+    crate::poly::degree(coeffs)?;
+    let sf = crate::poly::squarefree(coeffs, options);
+    find_roots(&sf, options)
+}
+
+/// Roots of largest magnitude
+///
+/// The `k_dominant_roots` function returns the `k` roots of largest magnitude, e.g. the dominant
+/// poles kept by a model reduction. It solves for all roots with `find_roots` and sorts them, so the
+/// result is as accurate as the full solution and costs as much; there is no partial deflation.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `k`: The number of roots wanted; all roots are returned if `k` exceeds the degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `k_dominant_roots` returns the roots sorted by descending magnitude, or the error
+/// of `find_roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::k_dominant_roots;
+///
+/// // (x - 1)(x - 2)(x - 4)
+/// let zs = k_dominant_roots(&[1.0, -7.0, 14.0, -8.0], 2, &Options::default()).unwrap();
+///
+/// assert!((zs[0].re - 4.0).abs() < 1e-12);
+/// assert!((zs[1].re - 2.0).abs() < 1e-12);
+/// ```
+pub fn k_dominant_roots(
+    coeffs: &[f64],
+    k: usize,
+    options: &Options,
+) -> Result<Vec<Complex<f64>>, RootError> {
+    let mut zs = find_roots(coeffs, options)?;
+    zs.sort_by(|a, b| b.norm().total_cmp(&a.norm()));
+    zs.truncate(k);
+    Ok(zs)
+}
+
+/// Roots of smallest magnitude
+///
+/// The `k_smallest_roots` function is the analog of `k_dominant_roots` for the `k` roots of
+/// smallest magnitude, with the same cost and accuracy.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `k`: The number of roots wanted; all roots are returned if `k` exceeds the degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `k_smallest_roots` returns the roots sorted by ascending magnitude, or the error
+/// of `find_roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::k_smallest_roots;
+///
+/// // (x - 1)(x - 2)(x - 4)
+/// let zs = k_smallest_roots(&[1.0, -7.0, 14.0, -8.0], 1, &Options::default()).unwrap();
+///
+/// assert_eq!(zs.len(), 1);
+/// assert!((zs[0].re - 1.0).abs() < 1e-12);
+/// ```
+pub fn k_smallest_roots(
+    coeffs: &[f64],
+    k: usize,
+    options: &Options,
+) -> Result<Vec<Complex<f64>>, RootError> {
+    let mut zs = find_roots(coeffs, options)?;
+    zs.sort_by(|a, b| a.norm().total_cmp(&b.norm()));
+    zs.truncate(k);
+    Ok(zs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_root_gradients() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        // rounding keeps the residual of the perturbed polynomials just above 1e-12
+        let options = Options {
+            tol: 1e-10,
+            ..Options::default()
+        };
+        let zs = find_roots(&coeffs, &options).unwrap();
+        let grads = root_gradients(&coeffs, &zs).unwrap();
+        let h = 1e-7;
+        for k in 0..coeffs.len() {
+            let mut perturbed = coeffs.clone();
+            perturbed[k] += h;
+            let zps = find_roots(&perturbed, &options).unwrap();
+            for (zi, grad) in zs.iter().zip(grads.iter()) {
+                let zp = zps
+                    .iter()
+                    .min_by(|a, b| (*a - zi).norm().total_cmp(&(*b - zi).norm()))
+                    .unwrap();
+                let fd = (zp - zi) / h;
+                assert!((fd - grad[k]).norm() < 1e-4 * (1.0 + grad[k].norm()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_freeze_converged() {
+        // 1e-4 * (x - 0.5)(x - 0.501)(x - 0.502)(x - 2)(x + 1)
+        let rs = [0.5, 0.501, 0.502, 2.0, -1.0];
+        let coeffs = vec![
+            0.0001,
+            -0.0002503,
+            2.5600199999999962e-5,
+            0.00021272469999999999,
+            -0.00013802529999999997,
+            2.5150200000000002e-5,
+        ];
+        let max_err = |freeze_converged: bool| {
+            let options = Options {
+                tol: 1e-18,
+                freeze_converged,
+                ..Options::default()
+            };
+            let mut zs = initial_aberth(&coeffs).unwrap();
+            let (_, found) = aberth(&coeffs, &mut zs, &options);
+            assert!(found);
+            rs.iter()
+                .map(|r| {
+                    zs.iter()
+                        .map(|z| (z - r).norm())
+                        .fold(f64::INFINITY, f64::min)
+                })
+                .fold(0.0, f64::max)
+        };
+        assert!(max_err(false) * 4.0 < max_err(true));
+    }
+
+    #[test]
+    fn test_horner_eval_f_sum() {
+        // the odd part of x^3 + 1 cancels over a symmetric grid, leaving one per point
+        let coeffs = vec![1.0, 0.0, 0.0, 1.0];
+        let n = 1000;
+        let points: Vec<f64> = (-n..=n).map(|k| k as f64 / n as f64).collect();
+        let exact = points.len() as f64;
+        let naive: f64 = points.iter().map(|&x| horner_eval_f(&coeffs, x)).sum();
+        let compensated = horner_eval_f_sum(&coeffs, &points);
+        assert!((compensated - exact).abs() < (naive - exact).abs());
+    }
+
+    #[test]
+    fn test_initial_aberth_with_phase() {
+        // x^4 - 1: the centroid is 0 and |P(0)|^(1/4) = 1, so the guesses sit on the unit circle
+        // at angles (k + phase) * pi / 2
+        let coeffs = vec![1.0, 0.0, 0.0, 0.0, -1.0];
+        for (radius, r) in [(None, 1.0), (Some(2.0), 2.0)] {
+            let zs = initial_aberth_with(&coeffs, radius, 0.25).unwrap();
+            assert_eq!(zs.len(), 4);
+            for (k, z) in zs.iter().enumerate() {
+                let theta = TWO_PI / 4.0 * (k as f64 + 0.25);
+                assert!((z - Complex::from_polar(r, theta)).norm() < 1e-12);
+            }
+        }
+        assert_eq!(
+            initial_aberth_with(&coeffs, None, 0.25).unwrap(),
+            initial_aberth(&coeffs).unwrap()
+        );
+
+        // off the symmetry axes of the roots 1, i, -1, -i, the guesses converge quickly
+        let mut zs = initial_aberth_with(&coeffs, None, 0.25).unwrap();
+        let (niter, found) = aberth(&coeffs, &mut zs, &Options::default());
+        assert!(found);
+        assert!(niter < 10);
+    }
+
+    #[test]
+    fn test_initial_aberth_with_phase_stall() {
+        // x^6 - 3x^4 - x^3 - 3x^2 + 1 is self-reciprocal: from phase 0.25 the guesses wander
+        // for 34 sweeps before they converge, from phase 0.1 they settle in 21
+        let coeffs = vec![1.0, 0.0, -3.0, -1.0, -3.0, 0.0, 1.0];
+        let options = Options {
+            max_iters: 25,
+            ..Options::default()
+        };
+        let mut zs = initial_aberth_with(&coeffs, None, 0.25).unwrap();
+        let (_, found) = aberth(&coeffs, &mut zs, &options);
+        assert!(!found);
+
+        let mut zs = initial_aberth_with(&coeffs, None, 0.1).unwrap();
+        let (_, found) = aberth(&coeffs, &mut zs, &options);
+        assert!(found);
+        for z in zs.iter() {
+            assert!(horner_eval_c(&coeffs, z).norm() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_stop_reason() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let result = aberth_detailed(&coeffs, &mut zs, &Options::default());
+        assert_eq!(result.reason, StopReason::Converged);
+        assert!(result.tol < 1e-12);
+
+        let options = Options {
+            max_iters: 2,
+            ..Options::default()
+        };
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let result = aberth_detailed(&coeffs, &mut zs, &options);
+        assert_eq!(result.reason, StopReason::MaxIters);
+        assert_eq!(result.niter, 2);
+
+        // two coincident guesses make the interaction sum blow up
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        zs[1] = zs[0];
+        let result = aberth_detailed(&coeffs, &mut zs, &Options::default());
+        assert_eq!(result.reason, StopReason::Diverged);
+        assert!(result.tol.is_nan());
+
+        // plain `aberth` does not stop early on divergence
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        zs[1] = zs[0];
+        let options = Options {
+            max_iters: 50,
+            ..Options::default()
+        };
+        assert_eq!(aberth(&coeffs, &mut zs, &options), (50, false));
+    }
+
+    #[test]
+    fn test_graeffe_fallback() {
+        // (x - 1)(x + 1.1)(x - 1.2)(x + 1.3)(x - 1.4)(x - 0.9): magnitudes packed within [0.9, 1.4]
+        let mut coeffs = vec![1.0];
+        for r in [1.0, -1.1, 1.2, -1.3, 1.4, 0.9] {
+            coeffs.push(0.0);
+            for i in (1..coeffs.len()).rev() {
+                coeffs[i] -= r * coeffs[i - 1];
+            }
+        }
+        let options = Options {
+            max_iters: 5,
+            ..Options::default()
+        };
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        assert!(!aberth(&coeffs, &mut zs, &options).1);
+
+        let options = Options {
+            graeffe_steps: 2,
+            ..options
+        };
+        let zs = find_roots(&coeffs, &options).unwrap();
+        for z in zs.iter() {
+            assert!(horner_eval_c(&coeffs, z).norm() < 1e-10);
+        }
+        let mut magnitudes: Vec<f64> = zs.iter().map(|z| z.norm()).collect();
+        magnitudes.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for (m, r) in magnitudes.iter().zip([0.9, 1.0, 1.1, 1.2, 1.3, 1.4]) {
+            assert!((m - r).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_find_roots_zero() {
+        // x^3 (x - 1)(x - 2)
+        let coeffs = [1.0, -3.0, 2.0, 0.0, 0.0, 0.0];
+        let mut zs = find_roots(&coeffs, &Options::default()).unwrap();
+        zs.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+
+        assert_eq!(zs.len(), 5);
+        assert!(zs[..3].iter().all(|z| *z == Complex::new(0.0, 0.0)));
+        assert!((zs[3] - 1.0).norm() < 1e-12);
+        assert!((zs[4] - 2.0).norm() < 1e-12);
+
+        // a tiny but genuine constant term is not a zero root
+        let zs = find_roots(&[1.0, 0.0, -1e-10], &Options::default()).unwrap();
+        assert!(zs.iter().all(|z| (z.norm() - 1e-5).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_poly_condition() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let options = Options::default();
+        assert!(poly_condition(&coeffs, &options).unwrap() < 1e2);
+
+        // Wilkinson's polynomial: roots 1, 2, ..., 20
+        let mut coeffs = vec![1.0];
+        for r in 1..=20 {
+            coeffs.push(0.0);
+            for i in (1..coeffs.len()).rev() {
+                coeffs[i] -= r as f64 * coeffs[i - 1];
+            }
+        }
+        // the rounding error of Horner's rule alone reaches 1e12 at the larger roots
+        let options = Options {
+            tol: 1e13,
+            ..Options::default()
+        };
+        assert!(poly_condition(&coeffs, &options).unwrap() > 1e8);
+    }
+
+    #[test]
+    fn test_auto_tol() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let scaled: Vec<f64> = coeffs.iter().map(|c| c * 1e6).collect();
+
+        // a fixed tolerance is out of reach once the coefficients are scaled
+        let mut zs = initial_aberth(&scaled).unwrap();
+        assert!(!aberth(&scaled, &mut zs, &Options::default()).1);
+
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        assert!(aberth(&coeffs, &mut zs, &Options::auto_tol(&coeffs)).1);
+        let mut zs_scaled = initial_aberth(&scaled).unwrap();
+        assert!(aberth(&scaled, &mut zs_scaled, &Options::auto_tol(&scaled)).1);
+        for (z, w) in zs.iter().zip(zs_scaled.iter()) {
+            assert!((z - w).norm() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_log_derivative_c() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let pb: Vec<f64> = (0..8).map(|i| coeffs[i] * (8 - i) as f64).collect();
+        let z = Complex::new(0.3, -1.7);
+        let expected = horner_eval_c(&pb, &z) / horner_eval_c(&coeffs, &z);
+
+        assert!((log_derivative_c(&coeffs, &z) - expected).norm() < 1e-12 * expected.norm());
+    }
+
+    #[test]
+    fn test_initial_aberth_annuli() {
+        // roots 0.01, -0.012, ±0.011i near 0.01 and 100, -120, ±90i near 100
+        let mut coeffs = vec![1.0];
+        for (b, c) in [
+            (-0.01 + 0.012, -0.01 * 0.012),
+            (0.0, 0.011 * 0.011),
+            (-100.0 + 120.0, -100.0 * 120.0),
+            (0.0, 8100.0),
+        ] {
+            // multiply by x^2 + b x + c
+            let mut next = vec![0.0; coeffs.len() + 2];
+            for (i, a) in coeffs.iter().enumerate() {
+                next[i] += a;
+                next[i + 1] += a * b;
+                next[i + 2] += a * c;
+            }
+            coeffs = next;
+        }
+        let roots = [0.01, -0.012, 100.0, -120.0];
+        // the residual of the large roots cannot reach an absolute `tol`, so count the sweeps until
+        // the real roots are recovered to a relative accuracy of 1e-10
+        let sweeps = |z0s: Vec<Complex<f64>>| {
+            (1..200)
+                .find(|&max_iters| {
+                    let mut zs = z0s.clone();
+                    let options = Options {
+                        max_iters,
+                        ..Options::default()
+                    };
+                    aberth(&coeffs, &mut zs, &options);
+                    roots
+                        .iter()
+                        .all(|r| zs.iter().any(|z| (z - r).norm() < 1e-10 * r.abs()))
+                })
+                .unwrap_or(200)
+        };
+        let niter_circle = sweeps(initial_aberth(&coeffs).unwrap());
+        let niter_annuli = sweeps(initial_aberth_annuli(&coeffs).unwrap());
+        assert!(2 * niter_annuli < niter_circle);
+    }
+
+    #[test]
+    fn test_initial_random() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        assert_eq!(
+            initial_random(&coeffs, 7).unwrap(),
+            initial_random(&coeffs, 7).unwrap()
+        );
+        assert_ne!(
+            initial_random(&coeffs, 7).unwrap(),
+            initial_random(&coeffs, 8).unwrap()
+        );
+
+        let mut zs = initial_random(&coeffs, 7).unwrap();
+        let (_, found) = aberth(&coeffs, &mut zs, &Options::default());
+        assert!(found);
+    }
+
+    #[test]
+    fn test_find_roots_degree() {
+        let options = Options::default();
+        assert_eq!(find_roots(&[5.0], &options), Ok(vec![]));
+        assert_eq!(find_roots(&[0.0], &options), Err(RootError::ZeroPolynomial));
+        assert_eq!(
+            find_roots(&[0.0, 0.0, 0.0], &options),
+            Err(RootError::ZeroPolynomial)
+        );
+        assert_eq!(find_roots(&[0.0, 5.0], &options), Ok(vec![]));
+        assert_eq!(
+            find_roots(&[0.0, 1.0, 5.0], &options),
+            find_roots(&[1.0, 5.0], &options)
+        );
+        assert_eq!(find_roots(&[], &options), Err(RootError::Empty));
+
+        // an iteration budget too small to converge is reported, not returned as roots
+        let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let short = Options {
+            max_iters: 2,
+            ..Options::default()
+        };
+        assert!(matches!(
+            find_roots(&coeffs, &short),
+            Err(RootError::NotConverged { niter: 2, .. })
+        ));
+        let mut pb = [0.0; 0];
+        assert_eq!(
+            aberth_into(&[5.0], &mut [], &mut pb, &mut [], &mut [], &options),
+            Err(RootError::ConstantPolynomial)
+        );
+        // Jacobi sweeps read from a snapshot of one estimate per root
+        let jacobi = Options {
+            scheme: IterationScheme::Jacobi,
+            ..options
+        };
+        let mut zs = [Complex::new(0.5, 0.5), Complex::new(-0.5, 0.5)];
+        assert_eq!(
+            aberth_into(
+                &[1.0, 0.0, 1.0],
+                &mut zs,
+                &mut [0.0; 2],
+                &mut [false; 2],
+                &mut [],
+                &jacobi
+            ),
+            Err(RootError::BufferLength {
+                name: "snapshot",
+                expected: 2,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_aberth_streaming() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let options = Options {
+            tol_ind: 1e-12,
+            ..Options::default()
+        };
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let received: Vec<(usize, Complex<f64>)> = std::thread::scope(|scope| {
+            scope.spawn(|| aberth_streaming(&coeffs, &mut zs, &options, tx));
+            rx.iter().collect()
+        });
+        let mut indices: Vec<usize> = received.iter().map(|(i, _)| *i).collect();
+        indices.sort();
+        assert_eq!(indices, (0..8).collect::<Vec<_>>());
+        for (i, z) in received.iter() {
+            assert!(horner_eval_c(&coeffs, z).l1_norm() < 1e-12);
+            assert!((zs[*i] - z).norm() < 1e-10);
+        }
+
+        // nothing converges in a single sweep: the channel just closes
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let options = Options {
+            max_iters: 1,
+            ..options
+        };
+        aberth_streaming(&coeffs, &mut zs, &options, tx);
+        assert_eq!(rx.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_solve_by_deflation() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let options = Options::default();
+        let zs = solve_by_deflation(&coeffs, &options).unwrap();
+        let expected = find_roots(&coeffs, &options).unwrap();
+        assert_eq!(zs.len(), 8);
+        for z in expected.iter() {
+            assert!(zs.iter().any(|w| (w - z).norm() < 1e-10));
+        }
+        // the deflated estimates miss `tol` on the original polynomial, the polished ones meet it
+        let raw = residual_stats(
+            &coeffs,
+            &deflation_estimates(&coeffs, &options, |_| {}),
+            NormKind::L1,
+        );
+        let polished = residual_stats(&coeffs, &zs, NormKind::L1);
+        assert!(raw.max > options.tol);
+        assert!(polished.max < options.tol);
+
+        // the product of (x - k) for k = 1..10: every quotient is perturbed a little, so the raw
+        // estimates of the later roots drift, and the polish against the original reduces it
+        let mut coeffs = vec![1.0];
+        for k in 1..=10 {
+            let mut next = vec![0.0; coeffs.len() + 1];
+            for (i, a) in coeffs.iter().enumerate() {
+                next[i] += a;
+                next[i + 1] -= a * k as f64;
+            }
+            coeffs = next;
+        }
+        let error = |zs: &[Complex<f64>]| {
+            (1..=10)
+                .map(|k| {
+                    zs.iter()
+                        .map(|z| (z - k as f64).norm())
+                        .fold(f64::INFINITY, f64::min)
+                })
+                .fold(0.0, f64::max)
+        };
+        // rounding keeps the residual near 10 above 1e-5, out of reach of the default tol
+        let options = Options {
+            tol: 1e-4,
+            ..options
+        };
+        let raw = error(&deflation_estimates(&coeffs, &options, |_| {}));
+        let polished = error(&solve_by_deflation(&coeffs, &options).unwrap());
+        assert!(polished < raw);
+        assert!(polished < 1e-9);
+    }
+
+    #[test]
+    fn test_zero_threshold() {
+        use crate::poly::squarefree;
+        use crate::roots::cluster_roots;
+
+        // (x - 1)(x - 1 - d)(x + 2) with a pair of roots `d` apart
+        let d = 1e-6;
+        let coeffs = [1.0, -d, -3.0 - d, 2.0 + 2.0 * d];
+        let options = Options::default();
+        let zs = find_roots(&coeffs, &options).unwrap();
+        assert_eq!(squarefree(&coeffs, &options).len(), 4);
+        assert_eq!(cluster_roots(&zs, &options).len(), 3);
+
+        let merged = Options {
+            zero_threshold: 1e-4,
+            ..options
+        };
+        assert_eq!(squarefree(&coeffs, &merged).len(), 3);
+        let clusters = cluster_roots(&zs, &merged);
+        assert_eq!(clusters.len(), 2);
+        let pair = clusters.iter().find(|(_, n)| *n == 2).unwrap();
+        assert!((pair.0 - Complex::new(1.0 + d / 2.0, 0.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn test_imaginary_axis_roots() {
+        let options = Options::default();
+        let ys = imaginary_axis_roots(&[1.0, 0.0, 1.0], 1e-10, &options).unwrap();
+        assert_eq!(ys.len(), 2);
+        assert!((ys[0] + 1.0).abs() < 1e-12);
+        assert!((ys[1] - 1.0).abs() < 1e-12);
+
+        // x (x^2 + 9)(x - 1): the zero root is on the axis too
+        let ys = imaginary_axis_roots(&[1.0, -1.0, 9.0, -9.0, 0.0], 1e-10, &options).unwrap();
+        assert_eq!(ys.len(), 3);
+        assert!((ys[0] + 3.0).abs() < 1e-12);
+        assert!(ys[1].abs() < 1e-12);
+        assert!((ys[2] - 3.0).abs() < 1e-12);
+
+        // (x + 1)(x^2 + 2x + 5) is stable, so nothing lies on the axis
+        assert!(imaginary_axis_roots(&[1.0, 3.0, 7.0, 5.0], 1e-10, &options)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_distinct_roots() {
+        // (x - 1)^3 (x + 2)^2
+        let coeffs = [1.0, 1.0, -5.0, -1.0, 8.0, -4.0];
+        let options = Options {
+            zero_threshold: 1e-12,
+            ..Options::default()
+        };
+        let mut zs = distinct_roots(&coeffs, &options).unwrap();
+        zs.sort_by(|a, b| a.re.total_cmp(&b.re));
+        assert_eq!(zs.len(), 2);
+        assert!((zs[0] - Complex::new(-2.0, 0.0)).norm() < 1e-12);
+        assert!((zs[1] - Complex::new(1.0, 0.0)).norm() < 1e-12);
+
+        assert_eq!(
+            distinct_roots(&[5.0], &Options::default()),
+            Err(RootError::ConstantPolynomial)
+        );
+    }
+
+    #[test]
+    fn test_k_dominant_roots() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let options = Options::default();
+        let mut all = find_roots(&coeffs, &options).unwrap();
+        all.sort_by(|a, b| b.norm().total_cmp(&a.norm()));
+
+        let largest = k_dominant_roots(&coeffs, 3, &options).unwrap();
+        assert_eq!(largest.len(), 3);
+        for (z, w) in largest.iter().zip(all.iter()) {
+            assert!((z.norm() - w.norm()).abs() < 1e-12);
+        }
+        let smallest = k_smallest_roots(&coeffs, 3, &options).unwrap();
+        for (z, w) in smallest.iter().zip(all.iter().rev()) {
+            assert!((z.norm() - w.norm()).abs() < 1e-12);
+        }
+        assert_eq!(k_dominant_roots(&coeffs, 20, &options).unwrap().len(), 8);
+    }
+
+    #[test]
+    fn test_adaptive_tol() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let fixed = Options::default();
+        let adaptive = Options {
+            adaptive_tol: true,
+            ..Options::default()
+        };
+        let mut zs_fixed = initial_aberth(&coeffs).unwrap();
+        let result_fixed = aberth_detailed(&coeffs, &mut zs_fixed, &fixed);
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let result = aberth_detailed(&coeffs, &mut zs, &adaptive);
+        assert_eq!(result.reason, StopReason::Converged);
+        assert!(result.niter <= result_fixed.niter + 1);
+        // no root was left behind by the skipped updates
+        for z in zs.iter() {
+            assert!(horner_eval_c(&coeffs, z).l1_norm() < adaptive.tol);
+        }
+
+        // the schedule starts at the first residual, tightens by a fixed factor and ends at tol
+        let mut skip_below = adaptive_skip(0.0, 1.0, &adaptive);
+        assert_eq!(skip_below, 1.0);
+        for _ in 0..20 {
+            let next = adaptive_skip(skip_below, 1.0, &adaptive);
+            assert!(next >= adaptive.tol);
+            assert_eq!(next, (skip_below * ADAPTIVE_FACTOR).max(adaptive.tol));
+            skip_below = next;
+        }
+        assert_eq!(skip_below, adaptive.tol);
+        assert_eq!(adaptive_skip(0.0, f64::INFINITY, &adaptive), 0.0);
+        assert_eq!(adaptive_skip(skip_below, 1.0, &fixed), 0.0);
+    }
+
+    #[test]
+    fn test_overflow() {
+        // x^80 + x^79 + ... + 1
+        let coeffs = vec![1.0; 81];
+        let big = Complex::new(1e5, 1e5);
+        assert!(!horner_eval_c(&coeffs, &big).is_finite());
+        assert_eq!(
+            horner_eval_c_checked(&coeffs, &big),
+            Err(EvalError::Overflow)
+        );
+
+        // one estimate far out poisons every residual; the sweep reports it instead
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        zs[3] = big;
+        let result = aberth_detailed(&coeffs, &mut zs, &Options::default());
+        assert_eq!(result.reason, StopReason::Overflow);
+        assert_eq!(result.niter, 0);
+        assert!(zs.iter().all(|z| z.is_finite()));
+    }
+
+    #[test]
+    fn test_max_step() {
+        // for x^2 - 1 and the guesses 0.5, 1.25 + e, the Aberth denominator of the first guess is
+        // 2 (0.5) - P(0.5) / (0.5 - 1.25 - e) = e / (0.75 + e), so its step is about 0.5625 / e
+        let coeffs = [1.0, 0.0, -1.0];
+        let z0s = vec![Complex::new(0.5, 0.0), Complex::new(1.25 + 1e-13, 0.0)];
+        let peak = |options: &Options| {
+            let mut zs = z0s.clone();
+            let (result, frames) = aberth_trajectories(&coeffs, &mut zs, options);
+            assert_eq!(result.reason, StopReason::Converged);
+            frames
+                .iter()
+                .flatten()
+                .map(|z| z.norm())
+                .fold(0.0, f64::max)
+        };
+        assert!(peak(&Options::default()) > 1e12);
+        let clamped = Options {
+            max_step: Some(1.0),
+            ..Options::default()
+        };
+        assert!(peak(&clamped) < 3.0);
+    }
+
+    #[test]
+    fn test_stability_margin() {
+        let options = Options::default();
+        // (z - 0.9)(z^2 + 0.25) = z^3 - 0.9 z^2 + 0.25 z - 0.225: stable, spectral radius 0.9
+        let stable = [1.0, -0.9, 0.25, -0.225];
+        let margin = stability_margin_discrete(&stable, &options).unwrap();
+        assert!((margin - 0.1).abs() < 1e-12);
+        // (z - 1.2)(z + 0.5) = z^2 - 0.7 z - 0.6: unstable
+        let margin = stability_margin_discrete(&[1.0, -0.7, -0.6], &options).unwrap();
+        assert!((margin + 0.2).abs() < 1e-12);
+
+        // (s + 0.5)(s^2 + 2 s + 5): stable, rightmost root -0.5
+        let margin = stability_margin_continuous(&[1.0, 2.5, 6.0, 2.5], &options).unwrap();
+        assert!((margin - 0.5).abs() < 1e-12);
+        // s^2 - 2 s + 5, roots 1 ± 2i: unstable
+        let margin = stability_margin_continuous(&[1.0, -2.0, 5.0], &options).unwrap();
+        assert!((margin + 1.0).abs() < 1e-12);
+
+        assert_eq!(
+            stability_margin_discrete(&[5.0], &options),
+            Err(RootError::ConstantPolynomial)
+        );
+    }
+
+    #[test]
+    fn test_aberth_stack_array() {
+        // (x - 1)(x - 2)(x - 4)(x - 5)
+        let coeffs = [1.0, -12.0, 49.0, -78.0, 40.0];
+        let mut zs = [Complex::new(0.0, 0.0); 4];
+        zs.copy_from_slice(&initial_aberth(&coeffs).unwrap());
+        let (_, found) = aberth(&coeffs, &mut zs, &Options::default());
+        assert!(found);
+        for r in [1.0, 2.0, 4.0, 5.0] {
+            assert!(zs.iter().any(|z| (z - r).norm() < 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_initial_aberth_par() {
+        // a high degree with a non-trivial center and radius, plus a centroid that is a root
+        let coeffs: Vec<f64> = (0..301).map(|k| 1.0 + ((k * 7) % 13) as f64).collect();
+        for coeffs in [&coeffs[..], &[1.0, 0.0, -5.0, 0.0, 4.0]] {
+            assert_eq!(initial_aberth_par(coeffs), initial_aberth(coeffs));
+        }
+        assert_eq!(
+            initial_aberth_par(&[5.0]),
+            Err(RootError::ConstantPolynomial)
+        );
+        assert_eq!(initial_aberth_par(&[0.0, 1.0]), Err(RootError::LeadingZero));
+    }
+
+    #[test]
+    fn test_initial_aberth_slice() {
+        let mut zs = vec![Complex::new(0.0, 0.0); 4];
+        // the buffer is reused across polynomials of the same degree, including one whose
+        // centroid is a root
+        for coeffs in [
+            [1.0, -12.0, 49.0, -78.0, 40.0],
+            [1.0, 0.0, -5.0, 0.0, 4.0],
+            [2.0, 3.0, 5.0, 7.0, 11.0],
+        ] {
+            initial_aberth_slice(&coeffs, &mut zs).unwrap();
+            assert_eq!(zs, initial_aberth(&coeffs).unwrap());
+        }
+        assert_eq!(
+            initial_aberth_slice(&[1.0, -3.0, 2.0], &mut zs),
+            Err(RootError::BufferLength {
+                name: "out",
+                expected: 2,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_residual_stats() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let result = aberth_detailed(&coeffs, &mut zs, &Options::default());
+        assert_eq!(result.reason, StopReason::Converged);
+
+        let stats = residual_stats(&coeffs, &zs, NormKind::L1);
+        assert_eq!(stats.max, result.tol);
+        assert!(stats.min <= stats.median && stats.median <= stats.max);
+        assert!(stats.min <= stats.mean && stats.mean <= stats.max);
+        let inf = residual_stats(&coeffs, &zs, NormKind::Inf);
+        let l2 = residual_stats(&coeffs, &zs, NormKind::L2);
+        assert!(inf.max <= l2.max && l2.max <= stats.max);
+
+        assert!(residual_stats(&coeffs, &[], NormKind::L1).max.is_nan());
+    }
+
+    #[test]
+    fn test_vieta_check() {
+        // (x^2 + 1)(x - 1)(x - 2)(x - 4): the roots of x^2 + 1 sum to zero
+        let coeffs = [1.0, -7.0, 15.0, -15.0, 14.0, -8.0];
+        let zs = find_roots(&coeffs, &Options::default()).unwrap();
+        let (sum_err, prod_err) = vieta_check(&coeffs, &zs);
+        assert!(sum_err < 1e-14);
+        assert!(prod_err < 1e-14);
+
+        let mut corrupted = zs.clone();
+        corrupted[0] *= 1.1;
+        let (sum_err, prod_err) = vieta_check(&coeffs, &corrupted);
+        assert!(sum_err > 1e-3);
+        assert!(prod_err > 1e-2);
+
+        assert_eq!(
+            vieta_check(&coeffs, &zs[1..]),
+            (f64::INFINITY, f64::INFINITY)
+        );
+        assert_eq!(vieta_check(&[], &[]), (f64::INFINITY, f64::INFINITY));
+    }
+
+    #[test]
+    fn test_solve_quadratic_cascade() {
+        use std::f64::consts::PI;
+
+        // a 6th order Butterworth low-pass as three biquads, and a section with real poles
+        let factors = [
+            [1.0, 2.0 * (PI / 12.0).sin(), 1.0],
+            [1.0, 2.0 * (PI / 4.0).sin(), 1.0],
+            [1.0, 2.0 * (5.0 * PI / 12.0).sin(), 1.0],
+            [1.0, -1e8, 1.0],
+        ];
+        let zs = solve_quadratic_cascade(&factors);
+        assert_eq!(zs.len(), 8);
+        for (k, pair) in zs[..6].chunks(2).enumerate() {
+            let theta = PI / 2.0 + PI * (2 * k + 1) as f64 / 12.0;
+            assert!((pair[0] - Complex::from_polar(1.0, theta)).norm() < 1e-15);
+            assert_eq!(pair[1], pair[0].conj());
+        }
+        // the stable formula keeps the small root of x^2 - 1e8 x + 1 to full relative accuracy
+        assert_eq!(zs[6].re, 1e8);
+        assert!((zs[7].re - 1e-8).abs() < 1e-23);
+
+        // matches Aberth on the expanded product of the biquads
+        let mut coeffs = vec![1.0];
+        for f in factors[..3].iter() {
+            let mut next = vec![0.0; coeffs.len() + 2];
+            for (i, a) in coeffs.iter().enumerate() {
+                for (j, b) in f.iter().enumerate() {
+                    next[i + j] += a * b;
+                }
+            }
+            coeffs = next;
+        }
+        let found = find_roots(&coeffs, &Options::default()).unwrap();
+        for z in zs[..6].iter() {
+            assert!(found.iter().any(|w| (w - z).norm() < 1e-10));
+        }
+
+        // degenerate factors: linear, double zero, no root
+        let zs = solve_quadratic_cascade(&[[0.0, 2.0, -1.0], [3.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert_eq!(
+            zs,
+            vec![
+                Complex::new(0.5, 0.0),
+                Complex::new(0.0, 0.0),
+                Complex::new(0.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_roots_factored() {
+        // 30 quadratics with roots r_k exp(±i theta_k) inside the unit disk
+        let roots: Vec<Complex<f64>> = (0..30)
+            .map(|k| Complex::from_polar(0.5 + 0.015 * k as f64, 0.1 + 0.1 * k as f64))
+            .collect();
+        let factors: Vec<Polynomial> = roots
+            .iter()
+            .map(|z| Polynomial(vec![1.0, -2.0 * z.re, z.norm_sqr()]))
+            .collect();
+        let mut coeffs = vec![1.0];
+        for f in factors.iter() {
+            let mut next = vec![0.0; coeffs.len() + 2];
+            for (i, a) in coeffs.iter().enumerate() {
+                for (j, b) in f.0.iter().enumerate() {
+                    next[i + j] += a * b;
+                }
+            }
+            coeffs = next;
+        }
+        let error = |zs: &[Complex<f64>]| {
+            roots
+                .iter()
+                .map(|r| {
+                    zs.iter()
+                        .map(|z| (z - r).norm())
+                        .fold(f64::INFINITY, f64::min)
+                })
+                .fold(0.0, f64::max)
+        };
+        let options = Options::default();
+        let zs_factored = find_roots_factored(&factors, &options).unwrap();
+        let zs_expanded = find_roots(&coeffs, &options).unwrap();
+        assert!(error(&zs_factored) < 1e-10);
+        // both runs converged, so the error of the expanded product is that of its coefficients
+        assert!(zs_expanded
+            .iter()
+            .all(|z| horner_eval_c(&coeffs, z).l1_norm() < options.tol));
+        assert!(error(&zs_expanded) > 1e3 * error(&zs_factored));
+
+        let short = Options {
+            max_iters: 2,
+            ..options
+        };
+        assert!(matches!(
+            find_roots_factored(&factors, &short),
+            Err(RootError::NotConverged { niter: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_check_roots() {
+        // (x - 1)(x + 2)(x - 3)(x^2 + x + 1)
+        let coeffs = [1.0, -1.0, -6.0, -1.0, 1.0, 6.0];
+        let zs = find_roots_checked(&coeffs, &Options::default()).unwrap();
+        assert_eq!(zs.len(), 5);
+
+        // a corrupted result dropping a real root
+        let mut corrupted = zs.clone();
+        let idx = corrupted.iter().position(|z| z.im.abs() < 1e-8).unwrap();
+        corrupted.remove(idx);
+        assert!(matches!(
+            check_roots(&coeffs, &corrupted),
+            Err(RootError::RootCountMismatch {
+                kind: "total",
+                expected: 5,
+                found: 4
+            })
+        ));
+
+        // a complex root replaced by a spurious real one keeps the total but not the real count
+        let mut corrupted = zs.clone();
+        let idx = corrupted.iter().position(|z| z.im.abs() > 1e-8).unwrap();
+        corrupted[idx] = Complex::new(10.0, 0.0);
+        assert!(matches!(
+            check_roots(&coeffs, &corrupted),
+            Err(RootError::RootCountMismatch {
+                kind: "real",
+                expected: 3,
+                found: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn test_aberth_until() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let options = Options::default();
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let (niter_full, _) = aberth(&coeffs, &mut zs, &options);
+
+        // stop as soon as every estimate lies within 0.05 of a root
+        let roots = zs.clone();
+        let in_region = |zs: &[Complex<f64>], _: f64| {
+            zs.iter()
+                .all(|z| roots.iter().any(|r| (z - r).norm() < 0.05))
+        };
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let (niter, stopped) = aberth_until(&coeffs, &mut zs, &options, in_region);
+        assert!(stopped);
+        assert!(niter < niter_full);
+        assert!(in_region(&zs, 0.0));
+    }
+
+    #[test]
+    fn test_horner_with_derivative_c_many() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let points: Vec<Complex<f64>> = (0..37)
+            .map(|k| Complex::from_polar(1.3, TWO_PI * k as f64 / 37.0))
+            .collect();
+        let pairs = horner_with_derivative_c_many(&coeffs, &points);
+
+        assert_eq!(pairs.len(), points.len());
+        for (pair, z) in pairs.iter().zip(points.iter()) {
+            assert_eq!(*pair, horner_with_derivative_c(&coeffs, z));
+        }
+    }
+
+    #[test]
+    fn test_jacobi_matches_aberth_mt() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let options = Options {
+            max_iters: 3,
+            scheme: IterationScheme::Jacobi,
+            ..Options::default()
+        };
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        aberth(&coeffs, &mut zs, &options);
+        let mut zs_mt = initial_aberth(&coeffs).unwrap();
+        aberth_mt(&coeffs, &mut zs_mt, &options);
+        assert_eq!(zs, zs_mt);
+
+        let mut zs_gs = initial_aberth(&coeffs).unwrap();
+        let options = Options {
+            scheme: IterationScheme::GaussSeidel,
+            ..options
+        };
+        aberth(&coeffs, &mut zs_gs, &options);
+        assert_ne!(zs, zs_gs);
+    }
+
+    #[test]
+    fn test_track_roots() {
+        // (x - 1)(x - 2)(x - 4) + c for c from 0 to 0.3, before the two smaller roots collide
+        let start = [1.0, -7.0, 14.0, -8.0];
+        let target = [1.0, -7.0, 14.0, -7.7];
+        let options = Options::default();
+        let trajectory = track_roots(&start, &target, 30, &options).unwrap();
+
+        assert_eq!(trajectory.len(), 31);
+        for w in trajectory.windows(2) {
+            for (z0, z1) in w[0].iter().zip(w[1].iter()) {
+                assert!((z1 - z0).norm() < 0.05);
+            }
+        }
+        // every trajectory ends on a root of the target, and the roots keep their order
+        let last = &trajectory[30];
+        for z in last.iter() {
+            assert!(horner_eval_c(&target, z).norm() < 1e-10);
+        }
+        let order = |zs: &[Complex<f64>]| {
+            let mut idx: Vec<usize> = (0..zs.len()).collect();
+            idx.sort_by(|&i, &j| zs[i].re.partial_cmp(&zs[j].re).unwrap());
+            idx
+        };
+        assert_eq!(order(&trajectory[0]), order(last));
+        assert_eq!(
+            track_roots(&start, &target[..3], 30, &options),
+            Err(RootError::BufferLength {
+                name: "target",
+                expected: 4,
+                found: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_interlace_bounds() {
+        // (x - 1)(x - 2)(x - 4)
+        let coeffs = [1.0, -7.0, 14.0, -8.0];
+        let bounds = interlace_bounds(&coeffs, &Options::default()).unwrap();
+
+        assert_eq!(bounds.len(), 3);
+        for ((lo, hi), root) in bounds.iter().zip([1.0, 2.0, 4.0]) {
+            assert!(*lo < root && root < *hi);
+        }
+    }
+
+    #[test]
+    fn test_find_roots_auto() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let options = Options::default();
+        let complex: Vec<Complex<f64>> = coeffs.iter().map(|c| Complex::new(*c, 0.0)).collect();
+
+        // real-valued input goes through the real solver: same iterates, bit for bit
+        let zs = find_roots_auto(&complex, &options).unwrap();
+        assert_eq!(zs, find_roots(&coeffs, &options).unwrap());
+        assert_ne!(zs, find_roots_complex(&complex, &options).unwrap());
+
+        // a genuinely complex input goes through the complex solver
+        let mut rotated = complex.clone();
+        rotated[8] = Complex::new(10.0, 1.0);
+        let zs = find_roots_auto(&rotated, &options).unwrap();
+        for z in zs.iter() {
+            assert!(horner_with_derivative_cc(&rotated, z).0.norm() < 1e-10);
+        }
+
+        // a loose stopping tolerance does not make a small imaginary part negligible
+        let loose = Options {
+            tol_ind: 1e-3,
+            ..options
+        };
+        rotated[8] = Complex::new(10.0, 1e-6);
+        let zs = find_roots_auto(&rotated, &loose).unwrap();
+        assert_eq!(zs, find_roots_complex(&rotated, &loose).unwrap());
+    }
+
+    #[test]
+    fn test_nearest_root() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let options = Options::default();
+        let roots = find_roots(&coeffs, &options).unwrap();
+        for near in [
+            Complex::new(0.0, 0.8),
+            Complex::new(-1.5, 1.2),
+            Complex::new(-0.3, -0.4),
+            Complex::new(0.2, -1.2),
+        ] {
+            let closest = roots
+                .iter()
+                .min_by(|a, b| (*a - near).norm().partial_cmp(&(*b - near).norm()).unwrap())
+                .unwrap();
+            let z = nearest_root(&coeffs, near, &options).unwrap();
+            assert!((z - closest).norm() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_high_degree() {
+        // (z^75 - 50^75)(z^75 - 100^75): the roots lie on the circles |z| = 50 and |z| = 100
+        let (c1, c2) = (50.0_f64.powi(75), 100.0_f64.powi(75));
+        let mut coeffs = vec![0.0; 151];
+        coeffs[0] = 1.0;
+        coeffs[75] = -(c1 + c2);
+        coeffs[150] = c1 * c2;
+        // seeds on |z| = 300, where z^150 alone is about 1e371
+        let seeds = initial_aberth_with(&coeffs, Some(300.0), 0.1).unwrap();
+        // no absolute tol suits both circles, so run a fixed number of sweeps
+        let mut options = Options {
+            tol: 0.0,
+            max_iters: 150,
+            ..Options::default()
+        };
+
+        let mut zs = seeds.clone();
+        let result = aberth_detailed(&coeffs, &mut zs, &options);
+        assert_eq!(result.reason, StopReason::Overflow);
+
+        options.high_degree = true;
+        let mut zs = seeds;
+        aberth_detailed(&coeffs, &mut zs, &options);
+        let mut inner = 0;
+        for z in zs.iter() {
+            let radius = if z.norm() < 75.0 { 50.0 } else { 100.0 };
+            inner += (radius == 50.0) as usize;
+            // the nearest exact root radius * exp(2 pi i k / 75)
+            let k = (z.arg() / TWO_PI * 75.0).round();
+            let exact = Complex::from_polar(radius, k * TWO_PI / 75.0);
+            assert!((z - exact).norm() < 1e-12 * radius);
+        }
+        assert_eq!(inner, 75);
+
+        // z^150 - 1 from the same seeds: the reported residual is scaled like the sweeps
+        let mut coeffs = vec![0.0; 151];
+        coeffs[0] = 1.0;
+        coeffs[150] = -1.0;
+        let seeds = initial_aberth_with(&coeffs, Some(300.0), 0.1).unwrap();
+        let options = Options {
+            high_degree: true,
+            ..Options::default()
+        };
+        let mut zs = seeds.clone();
+        let result = aberth_detailed(&coeffs, &mut zs, &options);
+        assert_eq!(result.reason, StopReason::Converged);
+        assert!(result.tol.is_finite() && result.tol < options.tol);
+        // stopped while the estimates are still far out, where horner_eval_c overflows
+        let early = Options {
+            max_iters: 1,
+            high_degree: true,
+            ..Options::default()
+        };
+        let mut zs = seeds;
+        let result = aberth_detailed(&coeffs, &mut zs, &early);
+        assert_eq!(result.reason, StopReason::MaxIters);
+        assert!(!horner_eval_c(&coeffs, &zs[0]).is_finite());
+        assert!(result.tol.is_finite());
+    }
+
+    #[test]
+    fn test_split_by_unit_circle() {
+        // 2 (x - 0.5)(x + 0.25)(x^2 + 4)
+        let coeffs = [2.0, -0.5, 7.75, -2.0, -1.0];
+        let (inside, outside) = split_by_unit_circle(&coeffs, &Options::default()).unwrap();
+
+        assert_eq!((inside.len(), outside.len()), (3, 3));
+        for (c, expected) in inside.iter().zip([2.0, -0.5, -0.25]) {
+            assert!((c - expected).abs() < 1e-12);
+        }
+        for (c, expected) in outside.iter().zip([1.0, 0.0, 4.0]) {
+            assert!((c - expected).abs() < 1e-12);
+        }
+        let mut inner_roots = find_roots(&inside, &Options::default()).unwrap();
+        inner_roots.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+        assert!((inner_roots[0] - Complex::new(-0.25, 0.0)).norm() < 1e-12);
+        assert!((inner_roots[1] - Complex::new(0.5, 0.0)).norm() < 1e-12);
+        for z in find_roots(&outside, &Options::default()).unwrap() {
+            assert!((z.norm() - 2.0).abs() < 1e-12 && z.re.abs() < 1e-12);
+        }
+
+        // a constant has no roots to split, with or without leading zeros
+        for constant in [&[5.0][..], &[0.0, 5.0]] {
+            assert_eq!(
+                split_by_unit_circle(constant, &Options::default()),
+                Ok((vec![], vec![]))
+            );
+        }
+        assert_eq!(
+            split_by_unit_circle(&[0.0, 0.0], &Options::default()),
+            Err(RootError::ZeroPolynomial)
+        );
+    }
+
+    #[test]
+    fn test_aberth_with_steps() {
+        // (x - 1)^2 (x + 2)(x - 3)
+        let coeffs = [1.0, -3.0, -3.0, 11.0, -6.0];
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let (result, steps) = aberth_with_steps(&coeffs, &mut zs, &Options::default());
+
+        assert_eq!(result.reason, StopReason::Converged);
+        for (z, s) in zs.iter().zip(steps.iter()) {
+            if (z - 1.0).norm() < 1e-4 {
+                // linear convergence: each step only halves the previous one
+                assert!(s[s.len() - 1] > 1e-9);
+                assert!(s[s.len() - 1] > 0.1 * s[s.len() - 2]);
+            } else {
+                // quadratic convergence: once close, every step shrinks by a smaller ratio than
+                // the previous one, up to the sweep where the root is frozen and records 0
+                let close: Vec<f64> = s
+                    .iter()
+                    .copied()
+                    .skip_while(|step| *step > 0.1)
+                    .take_while(|step| *step > 0.0)
+                    .collect();
+                let ratios: Vec<f64> = close.windows(2).map(|w| w[1] / w[0]).collect();
+                assert!(ratios.len() >= 2);
+                assert!(ratios.windows(2).all(|w| w[1] < w[0]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_auto_respread() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let options = Options {
+            auto_respread: true,
+            ..Options::default()
+        };
+        let expected = find_roots(&coeffs, &Options::default()).unwrap();
+
+        // merged estimates, which make plain Aberth diverge (see test_stop_reason)
+        for merged in [vec![1, 2], vec![3, 5, 7]] {
+            let mut zs = initial_aberth(&coeffs).unwrap();
+            for j in merged {
+                zs[j] = zs[0];
+            }
+            let result = aberth_detailed(&coeffs, &mut zs, &options);
+            assert_eq!(result.reason, StopReason::Converged);
+            // every root is found, none twice
+            for e in expected.iter() {
+                assert_eq!(zs.iter().filter(|z| (*z - e).norm() < 1e-10).count(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_respread_unconverged() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let options = Options::default();
+        let roots = find_roots(&coeffs, &options).unwrap();
+        // five good roots, three guesses merged onto one point
+        let mut merged = roots.clone();
+        merged[5..].fill(Complex::new(0.3, 0.3));
+        let converged = [true, true, true, true, true, false, false, false];
+
+        let mut zs = merged.clone();
+        assert!(!aberth(&coeffs, &mut zs, &options).1);
+
+        let mut zs = merged;
+        assert!(respread_unconverged(&coeffs, &mut zs[..7], &converged).is_err());
+        respread_unconverged(&coeffs, &mut zs, &converged).unwrap();
+        assert_eq!(zs[..5], roots[..5]);
+        assert!(aberth(&coeffs, &mut zs, &options).1);
+        for r in roots.iter() {
+            assert_eq!(zs.iter().filter(|z| (*z - r).norm() < 1e-10).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_aberth_converged_at() {
+        // (x - 1)(x - 1.001)(x + 2)(x - 3)(x^2 + 1): one clustered pair
+        let mut coeffs = vec![1.0];
+        for (r, q) in [(2.001, -1.001), (1.0, 6.0), (0.0, -1.0)] {
+            // multiply by x^2 - r x - q
+            coeffs.extend([0.0, 0.0]);
+            for i in (1..coeffs.len()).rev() {
+                coeffs[i] -= r * coeffs[i - 1];
+                if i >= 2 {
+                    coeffs[i] -= q * coeffs[i - 2];
+                }
+            }
+        }
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let result = aberth_detailed(&coeffs, &mut zs, &Options::default());
+        let converged_at = &result.converged_at;
+        assert_eq!(result.reason, StopReason::Converged);
+
+        let clustered = |z: &Complex<f64>| (z.re - 1.0005).abs() < 0.01 && z.im.abs() < 0.01;
+        let last_simple = zs
+            .iter()
+            .zip(converged_at.iter())
+            .filter(|(z, _)| !clustered(z))
+            .map(|(_, at)| at.unwrap())
+            .max()
+            .unwrap();
+        let pair: Vec<Option<usize>> = zs
+            .iter()
+            .zip(converged_at.iter())
+            .filter(|(z, _)| clustered(z))
+            .map(|(_, at)| *at)
+            .collect();
+        assert_eq!(pair.len(), 2);
+        for at in pair {
+            assert!(at.unwrap() > last_simple);
+        }
+    }
+
+    #[test]
+    fn test_aberth_real() {
+        // (x - 0.5)(x + 1)(x - 2)(x + 3)(x - 4)(x + 5)
+        let roots = [0.5, -1.0, 2.0, -3.0, 4.0, -5.0];
+        let cs: Vec<Complex<f64>> = roots.iter().map(|&r| Complex::new(r, 0.0)).collect();
+        let coeffs = crate::poly::from_roots(&cs);
+        let mut xs = initial_aberth_real(&coeffs).unwrap();
+        let (_, found) = aberth_real(&coeffs, &mut xs, &Options::default());
+        assert!(found);
+
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        assert!(aberth(&coeffs, &mut zs, &Options::default()).1);
+        for z in &zs {
+            assert!(z.im.abs() < 1e-10);
+            assert_eq!(xs.iter().filter(|&&x| (x - z.re).abs() < 1e-10).count(), 1);
+        }
+
+        // (x - 1)(x^2 + x + 1): a complex pair breaks the all-real assumption
+        let coeffs = [1.0, 0.0, 0.0, -1.0];
+        let mut xs = initial_aberth_real(&coeffs).unwrap();
+        assert!(!aberth_real(&coeffs, &mut xs, &Options::default()).1);
+    }
+
+    #[test]
+    fn test_all_residuals() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let result = aberth_detailed(&coeffs, &mut zs, &Options::default());
+        let res = all_residuals(&coeffs, &zs, NormKind::L1);
+        assert_eq!(res.len(), 8);
+        assert_eq!(res.iter().copied().fold(0.0, nan_max), result.tol);
+        assert!(result.tol < Options::default().tol);
+    }
+
+    #[test]
+    fn test_initial_from_approx() {
+        // 8 roots of magnitude 1.6 to 2.4 and a cluster of 32 roots on the circle of radius 0.4
+        let big = [
+            (1.8, 0.6),
+            (1.8, -0.6),
+            (-2.0, 1.0),
+            (-2.0, -1.0),
+            (2.2, 0.0),
+            (-2.4, 0.0),
+            (0.2, 2.2),
+            (0.2, -2.2),
+        ];
+        let mut roots: Vec<Complex<f64>> = big.iter().map(|&(a, b)| Complex::new(a, b)).collect();
+        roots.extend((0..32).map(|k| Complex::from_polar(0.4, TWO_PI * k as f64 / 32.0)));
+        let coeffs = crate::poly::from_roots(&roots);
+
+        // the residuals of degree 40 say little here, so count the sweeps until every root is found
+        let options = Options {
+            tol: 0.0,
+            max_iters: 60,
+            ..Options::default()
+        };
+        let sweeps_to_roots = |mut zs: Vec<Complex<f64>>| {
+            let (_, frames) = aberth_trajectories(&coeffs, &mut zs, &options);
+            frames.iter().position(|zs| {
+                roots
+                    .iter()
+                    .all(|r| zs.iter().filter(|z| (*z - r).norm() < 1e-6).count() == 1)
+            })
+        };
+        let cold = sweeps_to_roots(initial_aberth(&coeffs).unwrap()).unwrap();
+        let z0s = initial_from_approx(&coeffs, 8, &Options::default()).unwrap();
+        assert_eq!(z0s.len(), 40);
+        let warm = sweeps_to_roots(z0s).unwrap();
+        assert!(warm < cold);
+    }
+
+    #[test]
+    fn test_residual_scaling() {
+        let roots: Vec<Complex<f64>> = [0.01, -0.03, 0.5, 100.0, 250.0]
+            .iter()
+            .map(|&r| Complex::new(r, 0.0))
+            .collect();
+        let coeffs = crate::poly::from_roots(&roots);
+
+        // rounding alone leaves a residual far above `tol` at the large roots
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let raw = aberth_detailed(&coeffs, &mut zs, &Options::default());
+        assert_eq!(raw.reason, StopReason::MaxIters);
+
+        let options = Options {
+            residual_scaling: ResidualScaling::ByRootMagnitude,
+            ..Options::default()
+        };
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let scaled = aberth_detailed(&coeffs, &mut zs, &options);
+        assert_eq!(scaled.reason, StopReason::Converged);
+        assert!(scaled.niter < 100);
+        for r in roots.iter() {
+            assert_eq!(
+                zs.iter()
+                    .filter(|z| (*z - r).norm() < 1e-12 * r.norm())
+                    .count(),
+                1
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_solve_report_serde() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let report = find_roots_report(&coeffs, &Options::default()).unwrap();
+        let json = serde_json::to_string(&report).unwrap();
+        let back: SolveReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, report);
+    }
+
+    #[test]
+    fn test_find_roots_centered() {
+        // (x - 998)(x - 999)(x - 1000)(x - 1001)(x - 1002), every coefficient exact in f64
+        let roots: Vec<Complex<f64>> = (998..=1002).map(|r| Complex::new(r as f64, 0.0)).collect();
+        let coeffs = crate::poly::from_roots(&roots);
+        let backward_error = |zs: &[Complex<f64>]| {
+            zs.iter()
+                .map(|z| {
+                    let scale: f64 = coeffs
+                        .iter()
+                        .rev()
+                        .enumerate()
+                        .map(|(i, a)| a.abs() * z.norm().powi(i as i32))
+                        .sum();
+                    horner_eval_c(&coeffs, z).norm() / scale
+                })
+                .fold(0.0, f64::max)
+        };
+        let options = Options::default();
+        let plain = find_roots(&coeffs, &options).unwrap();
+        let centered = find_roots_centered(&coeffs, &options).unwrap();
+        assert_eq!(centered.len(), 5);
+        assert!(backward_error(&centered) < backward_error(&plain));
+        for r in roots.iter() {
+            assert!(centered.iter().any(|z| (z - r).norm() < 1e-9));
+        }
+
+        assert_eq!(find_roots_centered(&[5.0], &options), Ok(vec![]));
+        assert_eq!(
+            find_roots_centered(&[0.0], &options),
+            Err(RootError::ZeroPolynomial)
+        );
+    }
+
+    #[test]
+    fn test_inverse_iteration_polish() {
+        let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        for z in find_roots(&coeffs, &Options::default()).unwrap() {
+            let rough = z + Complex::new(1e-3, -1e-3);
+            let polished = inverse_iteration_polish(&coeffs, rough, 5);
+            let before = horner_eval_c(&coeffs, &rough).norm();
+            assert!(horner_eval_c(&coeffs, &polished).norm() < 1e-8 * before);
+        }
+
+        let z = Complex::new(2.0, 0.0);
+        assert_eq!(inverse_iteration_polish(&[1.0, -3.0, 2.0], z, 3), z);
+        assert_eq!(inverse_iteration_polish(&[5.0], z, 3), z);
+        assert_eq!(inverse_iteration_polish(&[1.0, f64::NAN], z, 3), z);
+    }
+
+    #[test]
+    fn test_eval_along_ray() {
+        let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let from = Complex::new(-1.3, 0.7);
+        let to = Complex::new(0.4, -2.1);
+        let values = eval_along_ray(&coeffs, from, to, 7);
+        assert_eq!(values.len(), 7);
+        assert_eq!(values[0], (0.0, horner_eval_c(&coeffs, &from)));
+        assert_eq!(values[6], (1.0, horner_eval_c(&coeffs, &to)));
+        assert!(values.windows(2).all(|w| w[0].0 < w[1].0));
+
+        assert_eq!(
+            eval_along_ray(&coeffs, from, to, 1),
+            vec![(0.0, horner_eval_c(&coeffs, &from))]
+        );
+        assert!(eval_along_ray(&coeffs, from, to, 0).is_empty());
+    }
+
+    #[test]
+    fn test_solve_by_deflation_direction() {
+        use crate::rootfinding::DeflationDirection;
+
+        // 1.7 is the root left after the other three are deflated, so the last linear quotient
+        // should be x - 1.7 and its difference shows the error accumulated by the deflations
+        let roots = [0.3, -0.8, 1.7, 4000.5].map(|r| Complex::new(r, 0.0));
+        let coeffs = crate::poly::from_roots(&roots);
+        let error = |deflation| {
+            // rounding alone leaves a residual near 1e-2 at 4000.5, so it is scaled by |z|^4
+            let options = Options {
+                deflation,
+                residual_scaling: ResidualScaling::ByRootMagnitude,
+                ..Options::default()
+            };
+            let (_, trace) = solve_by_deflation_traced(&coeffs, &options).unwrap();
+            let linear = &trace[2];
+            (linear[0] - 1.0).abs() + (linear[1] + 1.7).abs()
+        };
+        let forward = error(DeflationDirection::Forward);
+        let backward = error(DeflationDirection::Backward);
+        let auto = error(DeflationDirection::Auto);
+
+        // forward deflation is stable when the roots divided out are the smaller ones
+        assert!(forward < 1e-12);
+        assert!(backward > 100.0 * forward);
+        // choosing the direction per root is at least as good as either fixed direction
+        assert!(auto <= forward);
+    }
+
+    #[test]
+    fn test_derivative_root_tower() {
+        // (x + 3)(x + 1)(x - 2)(x - 4)
+        let coeffs = [1.0, -2.0, -13.0, 14.0, 24.0];
+        let tower = derivative_root_tower(&coeffs, &Options::default()).unwrap();
+        assert_eq!(tower.len(), 4);
+        for (k, level) in tower.iter().enumerate() {
+            assert_eq!(level.len(), 4 - k);
+        }
+        for (r, expected) in tower[0].iter().zip([-3.0, -1.0, 2.0, 4.0]) {
+            assert!((r - expected).abs() < 1e-12);
+        }
+        // every root of the next derivative lies strictly between two consecutive roots
+        for w in tower.windows(2) {
+            for (i, r) in w[1].iter().enumerate() {
+                assert!(w[0][i] < *r && *r < w[0][i + 1]);
+            }
+        }
+        // the linear level is the mean of the roots
+        assert!((tower[3][0] - 0.5).abs() < 1e-12);
+
+        assert_eq!(
+            derivative_root_tower(&[5.0], &Options::default()),
+            Err(RootError::ConstantPolynomial)
+        );
+    }
+
+    #[test]
+    fn test_solve_by_deflation_traced() {
+        // rounding keeps the residual of the product near 7 above 1e-10
+        let options = Options {
+            tol: 1e-8,
+            ..Options::default()
+        };
+        // the sample has complex pairs only, the product of (x - k) real roots only
+        let roots: Vec<Complex<f64>> = (1..=7).map(|k| Complex::new(k as f64, 0.0)).collect();
+        let product = crate::poly::from_roots(&roots);
+        for coeffs in [
+            vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0],
+            product,
+        ] {
+            let degree = coeffs.len() - 1;
+            let (zs, trace) = solve_by_deflation_traced(&coeffs, &options).unwrap();
+            assert_eq!(zs, solve_by_deflation(&coeffs, &options).unwrap());
+            assert_eq!(trace.len(), degree);
+            assert!(trace.windows(2).all(|w| w[1].len() <= w[0].len()));
+            assert_eq!(trace[degree - 1], vec![coeffs[0]]);
+        }
+        assert_eq!(
+            solve_by_deflation_traced(&[5.0], &options),
+            Err(RootError::ConstantPolynomial)
+        );
+    }
+
+    #[test]
+    fn test_aberth_detailed_timing() {
+        let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        assert_eq!(
+            aberth_detailed(&coeffs, &mut zs, &Options::default()).elapsed,
+            None
+        );
+
+        let options = Options {
+            timing: true,
+            ..Options::default()
+        };
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let result = aberth_detailed(&coeffs, &mut zs, &options);
+        assert_eq!(result.reason, StopReason::Converged);
+        let elapsed = result.elapsed.unwrap();
+        assert!(elapsed > std::time::Duration::ZERO);
+        assert!(elapsed < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_aberth_detailed_time_limit() {
+        let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let options = Options {
+            time_limit: Some(std::time::Duration::ZERO),
+            ..Options::default()
+        };
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let result = aberth_detailed(&coeffs, &mut zs, &options);
+        assert_eq!(result.reason, StopReason::Timeout);
+        assert_eq!(result.niter, 0);
+
+        let options = Options {
+            time_limit: Some(std::time::Duration::from_secs(60)),
+            ..Options::default()
+        };
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        assert_eq!(
+            aberth_detailed(&coeffs, &mut zs, &options).reason,
+            StopReason::Converged
+        );
+    }
+
+    #[test]
+    fn test_aberth_detailed_cancel() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let flag = Arc::new(AtomicBool::new(false));
+        let options = Options {
+            cancel: Some(Arc::clone(&flag)),
+            ..Options::default()
+        };
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        assert_eq!(
+            aberth_detailed(&coeffs, &mut zs, &options).reason,
+            StopReason::Converged
+        );
+
+        flag.store(true, Ordering::Relaxed);
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        let result = aberth_detailed(&coeffs, &mut zs, &options);
+        assert_eq!(result.reason, StopReason::Cancelled);
+        assert_eq!(result.niter, 0);
+        // the threaded sweeps stop too
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        assert_eq!(aberth_mt(&coeffs, &mut zs, &options), (0, false));
+    }
+
+    #[test]
+    fn test_interaction_neighbors() {
+        let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let mut full = initial_aberth(&coeffs).unwrap();
+        let result = aberth_detailed(&coeffs, &mut full, &Options::default());
+        assert_eq!(result.reason, StopReason::Converged);
+
+        for scheme in [IterationScheme::GaussSeidel, IterationScheme::Jacobi] {
+            let options = Options {
+                interaction_neighbors: Some(3),
+                scheme,
+                ..Options::default()
+            };
+            let mut zs = initial_aberth(&coeffs).unwrap();
+            let result = aberth_detailed(&coeffs, &mut zs, &options);
+            assert_eq!(result.reason, StopReason::Converged);
+            assert!(crate::testutil::roots_approx_eq(&zs, &full, 1e-6));
+        }
+
+        // more neighbors than estimates falls back to the full sum
+        let options = Options {
+            interaction_neighbors: Some(20),
+            ..Options::default()
+        };
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        aberth(&coeffs, &mut zs, &options);
+        assert_eq!(zs, full);
+    }
+
+    #[test]
+    fn test_find_roots_i64() {
+        // (x - 10^8)^2 - 1: 10^16 - 1 rounds to 10^16 in f64, a double root at 10^8
+        let coeffs = [1, -200_000_000, 9_999_999_999_999_999];
+        let mut zs = find_roots_i64(&coeffs, &Options::default()).unwrap();
+        assert_eq!(zs.len(), 2);
+        zs.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+        assert!((zs[0].re - (1e8 - 1.0)).abs() < 1e-3 && zs[0].im.abs() < 1e-3);
+        assert!((zs[1].re - (1e8 + 1.0)).abs() < 1e-3 && zs[1].im.abs() < 1e-3);
+
+        assert_eq!(
+            find_roots_i64(&[0, 1, 2], &Options::default()),
+            Err(RootError::LeadingZero)
+        );
+        let short = Options {
+            max_iters: 1,
+            ..Options::default()
+        };
+        assert!(matches!(
+            find_roots_i64(&[1, -7, 14, -8], &short),
+            Err(RootError::NotConverged { niter: 1, .. })
+        ));
     }
-    (options.max_iters, false)
 }
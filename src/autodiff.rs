@@ -0,0 +1,224 @@
+use super::horner::HornerScalar;
+use std::ops::{Add, Mul};
+
+/// The `Dual` struct is a dual number `val + der e` with `e^2 = 0`. Evaluating a polynomial at
+/// `Dual::variable(x)` carries the exact first derivative along with the value.
+///
+/// Properties:
+///
+/// * `val`: The value part.
+/// * `der`: The derivative part.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dual {
+    pub val: f64,
+    pub der: f64,
+}
+
+impl Dual {
+    /// The `variable` function returns the dual number `x + e`, the independent variable at `x`.
+    #[inline]
+    pub const fn variable(x: f64) -> Self {
+        Dual { val: x, der: 1.0 }
+    }
+
+    /// The `constant` function returns the dual number `c + 0 e`.
+    #[inline]
+    pub const fn constant(c: f64) -> Self {
+        Dual { val: c, der: 0.0 }
+    }
+}
+
+impl From<f64> for Dual {
+    #[inline]
+    fn from(c: f64) -> Self {
+        Dual::constant(c)
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+
+    #[inline]
+    fn add(self, other: Dual) -> Dual {
+        Dual {
+            val: self.val + other.val,
+            der: self.der + other.der,
+        }
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+
+    #[inline]
+    fn mul(self, other: Dual) -> Dual {
+        Dual {
+            val: self.val * other.val,
+            der: self.val * other.der + self.der * other.val,
+        }
+    }
+}
+
+impl HornerScalar for Dual {
+    #[inline]
+    fn mul(&self, other: &Self) -> Self {
+        *self * *other
+    }
+
+    #[inline]
+    fn add(&self, other: &Self) -> Self {
+        *self + *other
+    }
+}
+
+/// The `HyperDual` struct is a hyper-dual number `val + d1 e1 + d2 e2 + d12 e1 e2` with
+/// `e1^2 = e2^2 = 0`. Evaluating a polynomial at `HyperDual::variable(x)` carries the exact first
+/// derivative in `d1` (and `d2`) and the exact second derivative in `d12`.
+///
+/// Properties:
+///
+/// * `val`: The value part.
+/// * `d1`: The `e1` part.
+/// * `d2`: The `e2` part.
+/// * `d12`: The `e1 e2` part.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HyperDual {
+    pub val: f64,
+    pub d1: f64,
+    pub d2: f64,
+    pub d12: f64,
+}
+
+impl HyperDual {
+    /// The `variable` function returns `x + e1 + e2`, the independent variable at `x`.
+    #[inline]
+    pub const fn variable(x: f64) -> Self {
+        HyperDual {
+            val: x,
+            d1: 1.0,
+            d2: 1.0,
+            d12: 0.0,
+        }
+    }
+
+    /// The `constant` function returns the hyper-dual number with value `c` and no derivative parts.
+    #[inline]
+    pub const fn constant(c: f64) -> Self {
+        HyperDual {
+            val: c,
+            d1: 0.0,
+            d2: 0.0,
+            d12: 0.0,
+        }
+    }
+}
+
+impl From<f64> for HyperDual {
+    #[inline]
+    fn from(c: f64) -> Self {
+        HyperDual::constant(c)
+    }
+}
+
+impl Add for HyperDual {
+    type Output = HyperDual;
+
+    #[inline]
+    fn add(self, other: HyperDual) -> HyperDual {
+        HyperDual {
+            val: self.val + other.val,
+            d1: self.d1 + other.d1,
+            d2: self.d2 + other.d2,
+            d12: self.d12 + other.d12,
+        }
+    }
+}
+
+impl Mul for HyperDual {
+    type Output = HyperDual;
+
+    #[inline]
+    fn mul(self, other: HyperDual) -> HyperDual {
+        HyperDual {
+            val: self.val * other.val,
+            d1: self.val * other.d1 + self.d1 * other.val,
+            d2: self.val * other.d2 + self.d2 * other.val,
+            d12: self.val * other.d12
+                + self.d1 * other.d2
+                + self.d2 * other.d1
+                + self.d12 * other.val,
+        }
+    }
+}
+
+impl HornerScalar for HyperDual {
+    #[inline]
+    fn mul(&self, other: &Self) -> Self {
+        *self * *other
+    }
+
+    #[inline]
+    fn add(&self, other: &Self) -> Self {
+        *self + *other
+    }
+}
+
+/// Exact derivatives by automatic differentiation
+///
+/// The `eval_derivatives` function evaluates `P(x)`, `P'(x)` and `P''(x)` with the generic
+/// `horner_eval` on hyper-dual numbers, without forming the derivative coefficients.
+///
+/// Arguments:
+///
+/// * `coeffs`: A non-empty slice of coefficients ordered from highest degree to lowest degree.
+/// * `x`: The point at which the polynomial is evaluated.
+///
+/// Returns:
+///
+/// The function `eval_derivatives` returns `(P(x), P'(x), P''(x))`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::autodiff::{eval_derivatives, Dual};
+/// use bairstow::horner::horner_eval;
+///
+/// // P(x) = x^3 - 2x^2 + 3x - 4
+/// let coeffs = [1.0, -2.0, 3.0, -4.0];
+/// let duals: Vec<Dual> = coeffs.iter().map(|c| Dual::constant(*c)).collect();
+/// let p = horner_eval(&duals, &Dual::variable(2.0));
+///
+/// assert_eq!((p.val, p.der), (2.0, 7.0));
+/// assert_eq!(eval_derivatives(&coeffs, 2.0), (2.0, 7.0, 8.0));
+/// ```
+pub fn eval_derivatives(coeffs: &[f64], x: f64) -> (f64, f64, f64) {
+    let coeffs: Vec<HyperDual> = coeffs.iter().map(|c| HyperDual::constant(*c)).collect();
+    let p = super::horner::horner_eval(&coeffs, &HyperDual::variable(x));
+    (p.val, p.d1, p.d12)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aberth::horner_with_derivative_c;
+    use crate::horner::horner_eval;
+    use num::Complex;
+
+    #[test]
+    fn test_dual_matches_fused_horner() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let duals: Vec<Dual> = coeffs.iter().map(|c| Dual::constant(*c)).collect();
+        for x in [-1.3, 0.0, 0.7, 2.0] {
+            let p = horner_eval(&duals, &Dual::variable(x));
+            let (pp, pp1) = horner_with_derivative_c(&coeffs, &Complex::new(x, 0.0));
+            assert_eq!(p.val, pp.re);
+            assert!((p.der - pp1.re).abs() <= 1e-12 * pp1.re.abs());
+
+            // P'' from the derivative coefficients
+            let pb: Vec<f64> = (0..8).map(|i| coeffs[i] * (8 - i) as f64).collect();
+            let (_, pp2) = horner_with_derivative_c(&pb, &Complex::new(x, 0.0));
+            let (_, _, d2) = eval_derivatives(&coeffs, x);
+            assert!((d2 - pp2.re).abs() <= 1e-12 * pp2.re.abs().max(1.0));
+        }
+    }
+}
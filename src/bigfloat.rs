@@ -0,0 +1,125 @@
+use crate::aberth::find_roots;
+use crate::error::RootError;
+use crate::rootfinding::Options;
+use num::Complex;
+use rug::Complex as BigComplex;
+
+const MAX_REFINE_STEPS: usize = 8;
+
+/// The `horner_big` function evaluates the polynomial and its derivative at `z` in the precision
+/// of `z`. The `f64` coefficients are converted exactly.
+fn horner_big(coeffs: &[f64], z: &BigComplex) -> (BigComplex, BigComplex) {
+    let mut p = BigComplex::new(z.prec());
+    let mut dp = BigComplex::new(z.prec());
+    for c in coeffs {
+        dp *= z;
+        dp += &p;
+        p *= z;
+        p += *c;
+    }
+    (p, dp)
+}
+
+/// The `refine_root` function converts `z0` up to `bits` of precision and runs Newton's method on
+/// it until the step is below `2^-bits` relative to the root, for at most `MAX_REFINE_STEPS` steps.
+fn refine_root(coeffs: &[f64], z0: &Complex<f64>, bits: u32) -> BigComplex {
+    let rel_step = 2.0_f64.powi(-(bits as i32));
+    let mut z = BigComplex::with_val(bits, (z0.re, z0.im));
+    for _ in 0..MAX_REFINE_STEPS {
+        let (p, dp) = horner_big(coeffs, &z);
+        if dp.is_zero() {
+            break;
+        }
+        let dz = BigComplex::with_val(bits, &p / &dp);
+        z -= &dz;
+        let step = dz.real().to_f64().hypot(dz.imag().to_f64());
+        let norm = z.real().to_f64().hypot(z.imag().to_f64());
+        if step <= rel_step * norm {
+            break;
+        }
+    }
+    z
+}
+
+/// Find the roots in `f64` and refine them in arbitrary precision
+///
+/// The `find_roots_refined` function solves the polynomial with `find_roots` in `f64`, converts every
+/// root up to `refine_bits` of precision and polishes it with a few Newton steps evaluated in that
+/// precision, then rounds the refined roots back to `f64`. Nearly all of the work is done at `f64`
+/// speed, and the result is the `f64` value nearest to a root of the polynomial given by the `f64`
+/// coefficients, which ill-conditioned roots rarely reach when the evaluation is itself in `f64`.
+/// Newton's method converges only linearly at a multiple root, so clustered roots gain little.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria of the `f64` solve.
+/// * `refine_bits`: The working precision of the refinement, at least `53`.
+///
+/// Returns:
+///
+/// The function `find_roots_refined` returns one root per degree, or the error of `find_roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::bigfloat::find_roots_refined;
+/// use bairstow::rootfinding::Options;
+///
+/// // (x - 1)(x - 2)(x - 4)
+/// let zs = find_roots_refined(&[1.0, -7.0, 14.0, -8.0], &Options::default(), 128).unwrap();
+///
+/// assert!(zs.iter().all(|z| z.im.abs() < 1e-30));
+/// assert!(zs.iter().any(|z| z.re == 4.0));
+/// ```
+pub fn find_roots_refined(
+    coeffs: &[f64],
+    options: &Options,
+    refine_bits: u32,
+) -> Result<Vec<Complex<f64>>, RootError> {
+    let bits = refine_bits.max(53);
+    let zs = find_roots(coeffs, options)?;
+    Ok(zs
+        .iter()
+        .map(|z0| {
+            let z = refine_root(coeffs, z0, bits);
+            Complex::new(z.real().to_f64(), z.imag().to_f64())
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The backward error `|P(z)| / sum |a_i| |z|^i`, evaluated in 256 bits.
+    fn backward_error(coeffs: &[f64], z: &Complex<f64>) -> f64 {
+        let big = BigComplex::with_val(256, (z.re, z.im));
+        let (p, _) = horner_big(coeffs, &big);
+        let abs_coeffs: Vec<f64> = coeffs.iter().map(|c| c.abs()).collect();
+        let (scale, _) = horner_big(&abs_coeffs, &BigComplex::with_val(256, (z.norm(), 0.0)));
+        let p_norm = p.real().to_f64().hypot(p.imag().to_f64());
+        p_norm / scale.real().to_f64()
+    }
+
+    #[test]
+    fn test_find_roots_refined() {
+        // Wilkinson's polynomial: roots 1, 2, ..., 20
+        let mut coeffs = vec![1.0];
+        for r in 1..=20 {
+            coeffs.push(0.0);
+            for i in (1..coeffs.len()).rev() {
+                coeffs[i] -= r as f64 * coeffs[i - 1];
+            }
+        }
+        let options = Options::default();
+        let worst = |zs: &[Complex<f64>]| {
+            zs.iter()
+                .map(|z| backward_error(&coeffs, z))
+                .fold(0.0, f64::max)
+        };
+        let raw = worst(&find_roots(&coeffs, &options).unwrap());
+        let refined = worst(&find_roots_refined(&coeffs, &options, 256).unwrap());
+        assert!(refined < raw / 50.0);
+    }
+}
@@ -0,0 +1,385 @@
+use num::Complex;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// The `Interval` struct is a closed real interval `[lo, hi]`.
+/// The arithmetic rounds outward: every result is widened by one ulp on each side. A correctly
+/// rounded operation is off by at most half an ulp, so the result always encloses the exact
+/// range of the operation. That holds without switching the rounding mode of the FPU.
+///
+/// Properties:
+///
+/// * `lo`: The lower end.
+/// * `hi`: The upper end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    pub lo: f64,
+    pub hi: f64,
+}
+
+impl Interval {
+    /// The `new` function returns `[lo, hi]`.
+    #[inline]
+    pub fn new(lo: f64, hi: f64) -> Self {
+        Interval { lo, hi }
+    }
+
+    /// The `point` function returns the degenerate interval `[x, x]`, which is exact.
+    #[inline]
+    pub fn point(x: f64) -> Self {
+        Interval { lo: x, hi: x }
+    }
+
+    /// The `outward` function widens `[lo, hi]` by one ulp on each side.
+    #[inline]
+    fn outward(lo: f64, hi: f64) -> Self {
+        Interval {
+            lo: lo.next_down(),
+            hi: hi.next_up(),
+        }
+    }
+
+    /// The `width` function returns `hi - lo`.
+    #[inline]
+    pub fn width(&self) -> f64 {
+        self.hi - self.lo
+    }
+
+    /// The `contains` function returns whether `x` lies in the interval.
+    #[inline]
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// The `interior_of` function returns whether the interval lies strictly inside `other`.
+    #[inline]
+    pub fn interior_of(&self, other: &Interval) -> bool {
+        other.lo < self.lo && self.hi < other.hi
+    }
+
+    /// The `intersects` function returns whether the intervals share a point.
+    #[inline]
+    pub fn intersects(&self, other: &Interval) -> bool {
+        self.lo <= other.hi && other.lo <= self.hi
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Interval::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Interval::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let ps = [
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        ];
+        let lo = ps.iter().copied().fold(f64::INFINITY, f64::min);
+        let hi = ps.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        Interval::outward(lo, hi)
+    }
+}
+
+/// The `ComplexInterval` struct is a rectangular box `re + i im` in the complex plane.
+///
+/// Properties:
+///
+/// * `re`: The range of the real part.
+/// * `im`: The range of the imaginary part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexInterval {
+    pub re: Interval,
+    pub im: Interval,
+}
+
+impl ComplexInterval {
+    /// The `new` function returns the box `re + i im`.
+    #[inline]
+    pub fn new(re: Interval, im: Interval) -> Self {
+        ComplexInterval { re, im }
+    }
+
+    /// The `point` function returns the degenerate box holding `z` only.
+    #[inline]
+    pub fn point(z: Complex<f64>) -> Self {
+        ComplexInterval {
+            re: Interval::point(z.re),
+            im: Interval::point(z.im),
+        }
+    }
+
+    /// The `around` function returns the square box of half-width `radius` centered at `z`.
+    #[inline]
+    pub fn around(z: Complex<f64>, radius: f64) -> Self {
+        ComplexInterval {
+            re: Interval::outward(z.re - radius, z.re + radius),
+            im: Interval::outward(z.im - radius, z.im + radius),
+        }
+    }
+
+    /// The `mid` function returns the center of the box.
+    #[inline]
+    pub fn mid(&self) -> Complex<f64> {
+        Complex::new(
+            0.5 * (self.re.lo + self.re.hi),
+            0.5 * (self.im.lo + self.im.hi),
+        )
+    }
+
+    /// The `width` function returns the larger side of the box.
+    #[inline]
+    pub fn width(&self) -> f64 {
+        self.re.width().max(self.im.width())
+    }
+
+    /// The `contains` function returns whether `z` lies in the box.
+    #[inline]
+    pub fn contains(&self, z: &Complex<f64>) -> bool {
+        self.re.contains(z.re) && self.im.contains(z.im)
+    }
+
+    /// The `interior_of` function returns whether the box lies strictly inside `other`.
+    #[inline]
+    pub fn interior_of(&self, other: &ComplexInterval) -> bool {
+        self.re.interior_of(&other.re) && self.im.interior_of(&other.im)
+    }
+
+    /// The `intersects` function returns whether the boxes share a point.
+    #[inline]
+    pub fn intersects(&self, other: &ComplexInterval) -> bool {
+        self.re.intersects(&other.re) && self.im.intersects(&other.im)
+    }
+}
+
+impl Add for ComplexInterval {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        ComplexInterval {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+impl Sub for ComplexInterval {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        ComplexInterval {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+}
+
+impl Mul for ComplexInterval {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        ComplexInterval {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+/// Interval Horner evaluation
+///
+/// The `horner_interval` function evaluates the polynomial over a box with Horner's scheme in
+/// interval arithmetic. The result encloses `P(z)` for every `z` in the box. It is usually wider
+/// than the exact range, by the dependency effect of interval arithmetic. The coefficients are
+/// taken as exact.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zval`: The box to evaluate over.
+///
+/// Returns:
+///
+/// The function `horner_interval` returns a box enclosing the values of the polynomial.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::certify::{horner_interval, ComplexInterval};
+/// use num::Complex;
+///
+/// // x^2 + 1 vanishes at i, and the enclosure at the point i must contain 0
+/// let pz = horner_interval(&[1.0, 0.0, 1.0], &ComplexInterval::point(Complex::new(0.0, 1.0)));
+///
+/// assert!(pz.contains(&Complex::new(0.0, 0.0)));
+/// ```
+pub fn horner_interval(coeffs: &[f64], zval: &ComplexInterval) -> ComplexInterval {
+    let zero = ComplexInterval::point(Complex::new(0.0, 0.0));
+    coeffs.iter().fold(zero, |acc, c| {
+        acc * *zval + ComplexInterval::point(Complex::new(*c, 0.0))
+    })
+}
+
+/// The `horner_interval_derivative` function encloses `P'` over a box with the derivative
+/// recurrence of `aberth::horner_with_derivative_c`, so that no rounded derivative coefficients
+/// enter the enclosure.
+fn horner_interval_derivative(coeffs: &[f64], zval: &ComplexInterval) -> ComplexInterval {
+    let zero = ComplexInterval::point(Complex::new(0.0, 0.0));
+    let (mut pp, mut pp1) = (zero, zero);
+    for c in coeffs {
+        pp1 = pp1 * *zval + pp;
+        pp = pp * *zval + ComplexInterval::point(Complex::new(*c, 0.0));
+    }
+    pp1
+}
+
+/// The number of times `certify_roots` quadruples the box before giving up on a root.
+const MAX_INFLATIONS: usize = 24;
+
+/// The `krawczyk` function returns the Krawczyk operator
+/// `K(X) = z - Y P(z) + (1 - Y P'(X)) (X - z)` of the box `X` around `z`, with `Y = 1 / P'(z)`.
+fn krawczyk(
+    coeffs: &[f64],
+    z: Complex<f64>,
+    y: Complex<f64>,
+    xs: &ComplexInterval,
+) -> ComplexInterval {
+    let zi = ComplexInterval::point(z);
+    let yi = ComplexInterval::point(y);
+    let one = ComplexInterval::point(Complex::new(1.0, 0.0));
+    let pz = horner_interval(coeffs, &zi);
+    let dx = horner_interval_derivative(coeffs, xs);
+    zi - yi * pz + (one - yi * dx) * (*xs - zi)
+}
+
+/// Certified root enclosures
+///
+/// The `certify_roots` function turns every root estimate into a box that provably contains a
+/// root, by the Krawczyk test of interval Newton: if `K(X)` lies strictly inside the box `X`, then
+/// `X` contains exactly one root, and so does `K(X)`. The first box is the square around the
+/// estimate with twice the length of the Newton step as half-width. It is quadrupled up to
+/// `MAX_INFLATIONS` times until the test passes. The test cannot pass at a multiple root, where
+/// `P'` vanishes. Two certified boxes that overlap may hold the same root, so both are rejected;
+/// this happens when two estimates converged to one root.
+/// The coefficients are taken as exact, and the returned boxes hold roots of exactly that
+/// polynomial.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `roots`: The root estimates, e.g. from `aberth::find_roots`.
+///
+/// Returns:
+///
+/// The function `certify_roots` returns one entry per estimate: `Some(K(X))` when the Krawczyk test
+/// passed, or `None` when the estimate could not be certified.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::find_roots;
+/// use bairstow::certify::certify_roots;
+/// use bairstow::rootfinding::Options;
+/// use num::Complex;
+///
+/// // (x - 1)(x - 2)^2: the double root cannot be certified
+/// let coeffs = [1.0, -5.0, 8.0, -4.0];
+/// let boxes = certify_roots(&coeffs, &[Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
+///
+/// assert!(boxes[0].unwrap().contains(&Complex::new(1.0, 0.0)));
+/// assert!(boxes[1].is_none());
+/// ```
+pub fn certify_roots(coeffs: &[f64], roots: &[Complex<f64>]) -> Vec<Option<ComplexInterval>> {
+    let mut boxes: Vec<Option<ComplexInterval>> = roots
+        .iter()
+        .map(|z| {
+            let (pz, dz) = crate::aberth::horner_with_derivative_c(coeffs, z);
+            if dz.norm() == 0.0 || !dz.norm().is_finite() {
+                return None;
+            }
+            let y = dz.inv();
+            let mut radius = (2.0 * (pz * y).norm()).max(f64::EPSILON * z.norm().max(1.0));
+            for _ in 0..MAX_INFLATIONS {
+                let xs = ComplexInterval::around(*z, radius);
+                let kx = krawczyk(coeffs, *z, y, &xs);
+                if kx.interior_of(&xs) {
+                    return Some(kx);
+                }
+                radius *= 4.0;
+            }
+            None
+        })
+        .collect();
+    let certified = boxes.clone();
+    for (i, bx) in boxes.iter_mut().enumerate() {
+        let overlaps = |b: &ComplexInterval| {
+            certified
+                .iter()
+                .enumerate()
+                .any(|(j, other)| j != i && other.is_some_and(|o| o.intersects(b)))
+        };
+        if bx.is_some_and(|b| overlaps(&b)) {
+            *bx = None;
+        }
+    }
+    boxes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aberth::find_roots;
+    use crate::rootfinding::Options;
+
+    #[test]
+    fn test_certify_roots() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let zs = find_roots(&coeffs, &Options::default()).unwrap();
+        let boxes = certify_roots(&coeffs, &zs);
+        assert_eq!(boxes.len(), 8);
+        for (z, bx) in zs.iter().zip(boxes.iter()) {
+            let bx = bx.expect("well-separated roots certify");
+            assert!(bx.width() < 1e-12);
+            assert!((bx.mid() - z).norm() < 1e-12);
+        }
+
+        // two estimates of one root, and one far from every root
+        let mut zs = zs;
+        zs[1] = zs[0] + Complex::new(1e-14, 0.0);
+        zs[2] = Complex::new(100.0, 0.0);
+        let boxes = certify_roots(&coeffs, &zs);
+        assert!(boxes[0].is_none() && boxes[1].is_none() && boxes[2].is_none());
+        assert!(boxes[3..].iter().all(|bx| bx.is_some()));
+    }
+}
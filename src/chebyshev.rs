@@ -0,0 +1,309 @@
+use crate::rootfinding::Options;
+use num::Complex;
+
+/// Roots of a Chebyshev series
+///
+/// The `chebyshev_roots` function finds the roots of `p(x) = c_n T_n(x) + ... + c_1 T_1(x) + c_0`
+/// as the eigenvalues of its colleague matrix, the Chebyshev analogue of the companion matrix. The
+/// matrix follows from `x T_0 = T_1` and `x T_k = (T_(k-1) + T_(k+1)) / 2`, with `T_n` replaced by
+/// `-(c_0 T_0 + ... + c_(n-1) T_(n-1)) / c_n`: it is tridiagonal plus a full last row. Its transpose
+/// is upper Hessenberg, so the eigenvalues come straight from the shifted QR iteration, without
+/// converting to the monomial basis. That conversion is ill-conditioned: the monomial coefficients
+/// of `T_n` grow like `2^n` and cancel on `[-1, 1]`, where the roots of a Chebyshev series
+/// usually lie.
+///
+/// Arguments:
+///
+/// * `cheb_coeffs`: The Chebyshev coefficients, ordered from highest degree to lowest degree like
+///   the monomial coefficients elsewhere in the crate. Leading zeros are dropped.
+/// * `options`: `max_iters` caps the QR sweeps spent on each eigenvalue.
+///
+/// Returns:
+///
+/// The function `chebyshev_roots` returns one root per degree, none for a constant or empty
+/// series, and fewer than the degree if the QR iteration runs out of sweeps.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::chebyshev::chebyshev_roots;
+/// use bairstow::rootfinding::Options;
+///
+/// // T_2(x) = 2x^2 - 1
+/// let mut zs = chebyshev_roots(&[1.0, 0.0, 0.0], &Options::default());
+/// zs.sort_by(|a, b| a.re.total_cmp(&b.re));
+///
+/// assert!((zs[0].re + 0.5_f64.sqrt()).abs() < 1e-15);
+/// assert!((zs[1].re - 0.5_f64.sqrt()).abs() < 1e-15);
+/// ```
+pub fn chebyshev_roots(cheb_coeffs: &[f64], options: &Options) -> Vec<Complex<f64>> {
+    let start = cheb_coeffs
+        .iter()
+        .position(|c| *c != 0.0)
+        .unwrap_or(cheb_coeffs.len());
+    let coeffs = &cheb_coeffs[start..];
+    match coeffs {
+        [] | [_] => vec![],
+        [a, b] => vec![Complex::new(-b / a, 0.0)],
+        _ => hessenberg_eigenvalues(colleague_matrix(coeffs), options.max_iters),
+    }
+}
+
+/// The `colleague_matrix` function builds the transposed colleague matrix of a Chebyshev series of
+/// degree `n >= 2`: upper Hessenberg, with `1/2` on both off-diagonals (`1` where `T_0` is
+/// involved) and `-c_j / (2 c_n)` added to the last column.
+fn colleague_matrix(coeffs: &[f64]) -> Vec<Vec<f64>> {
+    let n = coeffs.len() - 1;
+    let mut a = vec![vec![0.0; n]; n];
+    a[1][0] = 1.0;
+    for k in 1..n - 1 {
+        a[k - 1][k] = 0.5;
+        a[k + 1][k] = 0.5;
+    }
+    a[n - 2][n - 1] = 0.5;
+    // `coeffs[n - j]` is `c_j`
+    for (j, row) in a.iter_mut().enumerate() {
+        row[n - 1] -= coeffs[n - j] / (2.0 * coeffs[0]);
+    }
+    a
+}
+
+/// The `hessenberg_eigenvalues` function computes the eigenvalues of an upper Hessenberg matrix
+/// with the Francis double-shift QR iteration, deflating a `1 x 1` or `2 x 2` block whenever a
+/// subdiagonal entry becomes negligible. Exceptional shifts after 10 and 20 sweeps break cycles.
+/// The search stops early, with the eigenvalues found so far, when one eigenvalue takes more than
+/// `max_iters` sweeps.
+fn hessenberg_eigenvalues(mut a: Vec<Vec<f64>>, max_iters: usize) -> Vec<Complex<f64>> {
+    let n = a.len();
+    let mut eigs = Vec::with_capacity(n);
+    let mut anorm = 0.0;
+    for (i, row) in a.iter().enumerate() {
+        anorm += row[i.saturating_sub(1)..]
+            .iter()
+            .map(|v| v.abs())
+            .sum::<f64>();
+    }
+    let mut t = 0.0;
+    let mut nn = n;
+    while nn > 0 {
+        let mut its = 0;
+        loop {
+            let e = nn - 1;
+            // look for a single small subdiagonal element
+            let mut l = e;
+            while l > 0 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == 0.0 {
+                    s = anorm;
+                }
+                if a[l][l - 1].abs() <= f64::EPSILON * s {
+                    a[l][l - 1] = 0.0;
+                    break;
+                }
+                l -= 1;
+            }
+            let mut x = a[e][e];
+            if l == e {
+                eigs.push(Complex::new(x + t, 0.0));
+                nn -= 1;
+                break;
+            }
+            let mut y = a[e - 1][e - 1];
+            let mut w = a[e][e - 1] * a[e - 1][e];
+            if l == e - 1 {
+                let p = 0.5 * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                x += t;
+                if q >= 0.0 {
+                    let z = p + z.copysign(p);
+                    eigs.push(Complex::new(x + z, 0.0));
+                    eigs.push(Complex::new(if z != 0.0 { x - w / z } else { x + z }, 0.0));
+                } else {
+                    eigs.push(Complex::new(x + p, z));
+                    eigs.push(Complex::new(x + p, -z));
+                }
+                nn -= 2;
+                break;
+            }
+            if its == max_iters {
+                return eigs;
+            }
+            if its == 10 || its == 20 {
+                t += x;
+                for (i, row) in a.iter_mut().enumerate().take(nn) {
+                    row[i] -= x;
+                }
+                let s = a[e][e - 1].abs() + a[e - 1][e - 2].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            its += 1;
+            francis_step(&mut a, l, e, x, y, w);
+        }
+    }
+    eigs
+}
+
+/// The `francis_step` function applies one implicit double-shift QR sweep to the active block
+/// `a[l..=e][l..=e]`, with the shifts given by the trace `x + y` and `x y - w` of the trailing
+/// `2 x 2` block.
+fn francis_step(a: &mut [Vec<f64>], l: usize, e: usize, mut x: f64, mut y: f64, w: f64) {
+    let (mut p, mut q, mut r, mut z);
+    // look for two consecutive small subdiagonal elements
+    let mut m = e - 2;
+    loop {
+        z = a[m][m];
+        let rr = x - z;
+        let ss = y - z;
+        p = (rr * ss - w) / a[m + 1][m] + a[m][m + 1];
+        q = a[m + 1][m + 1] - z - rr - ss;
+        r = a[m + 2][m + 1];
+        let s = p.abs() + q.abs() + r.abs();
+        p /= s;
+        q /= s;
+        r /= s;
+        if m == l {
+            break;
+        }
+        let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+        let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+        if u <= f64::EPSILON * v {
+            break;
+        }
+        m -= 1;
+    }
+    for i in m..e - 1 {
+        a[i + 2][i] = 0.0;
+        if i != m {
+            a[i + 2][i - 1] = 0.0;
+        }
+    }
+    for k in m..e {
+        if k != m {
+            p = a[k][k - 1];
+            q = a[k + 1][k - 1];
+            r = if k + 1 != e { a[k + 2][k - 1] } else { 0.0 };
+            x = p.abs() + q.abs() + r.abs();
+            if x != 0.0 {
+                p /= x;
+                q /= x;
+                r /= x;
+            }
+        }
+        let s = (p * p + q * q + r * r).sqrt().copysign(p);
+        if s == 0.0 {
+            continue;
+        }
+        if k == m {
+            if l != m {
+                a[k][k - 1] = -a[k][k - 1];
+            }
+        } else {
+            a[k][k - 1] = -s * x;
+        }
+        p += s;
+        x = p / s;
+        y = q / s;
+        z = r / s;
+        q /= p;
+        r /= p;
+        // rows k, k + 1 and, inside the block, k + 2
+        let (upper, lower) = a.split_at_mut(k + 1);
+        let (row1, lower) = lower.split_first_mut().unwrap();
+        let mut row2 = lower.first_mut().filter(|_| k + 1 != e);
+        let rows = upper[k].iter_mut().zip(row1.iter_mut());
+        for (j, (a0, a1)) in rows.enumerate().take(e + 1).skip(k) {
+            let mut pj = *a0 + q * *a1;
+            if let Some(row2) = row2.as_deref_mut() {
+                pj += r * row2[j];
+                row2[j] -= pj * z;
+            }
+            *a1 -= pj * y;
+            *a0 -= pj * x;
+        }
+        // columns k, k + 1 and, inside the block, k + 2
+        for row in a[l..=e.min(k + 3)].iter_mut() {
+            let mut pi = x * row[k] + y * row[k + 1];
+            if k + 1 != e {
+                pi += z * row[k + 2];
+                row[k + 2] -= pi * r;
+            }
+            row[k + 1] -= pi * q;
+            row[k] -= pi;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aberth::find_roots;
+    use std::f64::consts::PI;
+
+    /// The monomial coefficients of `T_n`, from `T_(k+1) = 2x T_k - T_(k-1)`.
+    fn chebyshev_t(n: usize) -> Vec<f64> {
+        let mut t0 = vec![1.0];
+        let mut t1 = vec![1.0, 0.0];
+        for _ in 1..n {
+            let mut t2: Vec<f64> = t1.iter().map(|c| 2.0 * c).chain([0.0]).collect();
+            let offset = t2.len() - t0.len();
+            for (i, c) in t0.iter().enumerate() {
+                t2[offset + i] -= c;
+            }
+            t0 = std::mem::replace(&mut t1, t2);
+        }
+        t1
+    }
+
+    /// Evaluates a Chebyshev series with Clenshaw's recurrence.
+    fn clenshaw(coeffs: &[f64], z: Complex<f64>) -> Complex<f64> {
+        let (mut b1, mut b2) = (Complex::new(0.0, 0.0), Complex::new(0.0, 0.0));
+        let (last, rest) = coeffs.split_last().unwrap();
+        for c in rest {
+            (b1, b2) = (2.0 * z * b1 - b2 + c, b1);
+        }
+        z * b1 - b2 + last
+    }
+
+    #[test]
+    fn test_chebyshev_roots() {
+        let options = Options::default();
+        // T_20, whose roots cos((2k + 1) pi / 40) crowd towards +-1
+        let n = 20;
+        let mut cheb = vec![0.0; n + 1];
+        cheb[0] = 1.0;
+        let error = |zs: &[Complex<f64>]| {
+            (0..n)
+                .map(|k| ((2 * k + 1) as f64 * PI / (2 * n) as f64).cos())
+                .map(|r| {
+                    zs.iter()
+                        .map(|z| (z - r).norm())
+                        .fold(f64::INFINITY, f64::min)
+                })
+                .fold(0.0, f64::max)
+        };
+        let direct = chebyshev_roots(&cheb, &options);
+        let monomial = chebyshev_t(n);
+        let converted = find_roots(&monomial, &Options::auto_tol(&monomial)).unwrap();
+        assert_eq!(direct.len(), n);
+        assert!(error(&direct) < 1e-14);
+        assert!(error(&direct) * 100.0 < error(&converted));
+
+        // complex and real roots, and a leading zero
+        let cheb = [0.0, 0.3, -1.2, 0.5, 2.0, -0.7, 0.1];
+        let zs = chebyshev_roots(&cheb, &options);
+        assert_eq!(zs.len(), 5);
+        assert_eq!(zs.iter().filter(|z| z.im != 0.0).count(), 2);
+        for z in zs {
+            assert!(clenshaw(&cheb[1..], z).norm() < 1e-13);
+        }
+
+        assert!(chebyshev_roots(&[0.0, 2.0], &options).is_empty());
+        assert_eq!(
+            chebyshev_roots(&[2.0, 1.0], &options),
+            vec![Complex::new(-0.5, 0.0)]
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use super::aberth::nan_max;
+use super::horner::HornerScalar;
+use super::Options;
+use num::{Complex, Num, One, Zero};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// The `DoubleDouble` struct is an unevaluated sum `hi + lo` of two `f64` with
+/// `|lo| <= ulp(hi) / 2`, which carries about 106 bits (32 decimal digits) of precision with the
+/// exponent range of `f64`.
+/// The arithmetic uses the error-free transformations of Dekker and Knuth and needs no external
+/// crate; it sits between plain `f64` and the `rug` backend of the `bigfloat` feature.
+///
+/// Properties:
+///
+/// * `hi`: The leading part, the `f64` nearest to the value.
+/// * `lo`: The rounding error of `hi`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct DoubleDouble {
+    pub hi: f64,
+    pub lo: f64,
+}
+
+/// The `two_sum` function returns `a + b` and its exact rounding error.
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// The `quick_two_sum` function is `two_sum` for `|a| >= |b|`.
+#[inline]
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// The `two_prod` function returns `a * b` and its exact rounding error.
+#[inline]
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+impl DoubleDouble {
+    /// The `new` function returns the normalized sum `hi + lo`.
+    #[inline]
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+        DoubleDouble { hi, lo }
+    }
+
+    /// The `to_f64` function rounds to the nearest `f64`.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+
+    /// The `abs` function returns the magnitude.
+    #[inline]
+    pub fn abs(self) -> Self {
+        if self.hi < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// The `trunc` function rounds towards zero.
+    #[inline]
+    fn trunc(self) -> Self {
+        if self.hi.trunc() == self.hi {
+            DoubleDouble::new(self.hi, self.lo.trunc())
+        } else {
+            DoubleDouble::new(self.hi.trunc(), 0.0)
+        }
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    #[inline]
+    fn from(x: f64) -> Self {
+        DoubleDouble { hi: x, lo: 0.0 }
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = DoubleDouble;
+
+    #[inline]
+    fn neg(self) -> DoubleDouble {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = DoubleDouble;
+
+    #[inline]
+    fn add(self, other: DoubleDouble) -> DoubleDouble {
+        let (s, e) = two_sum(self.hi, other.hi);
+        let (t, f) = two_sum(self.lo, other.lo);
+        let (s, e) = quick_two_sum(s, e + t);
+        let (hi, lo) = quick_two_sum(s, e + f);
+        DoubleDouble { hi, lo }
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = DoubleDouble;
+
+    #[inline]
+    fn sub(self, other: DoubleDouble) -> DoubleDouble {
+        self + -other
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = DoubleDouble;
+
+    #[inline]
+    fn mul(self, other: DoubleDouble) -> DoubleDouble {
+        let (p, e) = two_prod(self.hi, other.hi);
+        let e = e + (self.hi * other.lo + self.lo * other.hi);
+        let (hi, lo) = quick_two_sum(p, e);
+        DoubleDouble { hi, lo }
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = DoubleDouble;
+
+    /// Long division: three `f64` quotient digits, each correcting the remainder of the last.
+    #[inline]
+    fn div(self, other: DoubleDouble) -> DoubleDouble {
+        let q1 = self.hi / other.hi;
+        let r = self - other * q1.into();
+        let q2 = r.hi / other.hi;
+        let r = r - other * q2.into();
+        let q3 = r.hi / other.hi;
+        let (hi, lo) = quick_two_sum(q1, q2);
+        DoubleDouble { hi, lo } + q3.into()
+    }
+}
+
+impl Rem for DoubleDouble {
+    type Output = DoubleDouble;
+
+    #[inline]
+    fn rem(self, other: DoubleDouble) -> DoubleDouble {
+        self - other * (self / other).trunc()
+    }
+}
+
+impl Zero for DoubleDouble {
+    #[inline]
+    fn zero() -> Self {
+        DoubleDouble { hi: 0.0, lo: 0.0 }
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.hi == 0.0
+    }
+}
+
+impl One for DoubleDouble {
+    #[inline]
+    fn one() -> Self {
+        DoubleDouble { hi: 1.0, lo: 0.0 }
+    }
+}
+
+impl Num for DoubleDouble {
+    type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+
+    /// Parses through `f64`, so a literal gets only `f64` precision.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        <f64 as Num>::from_str_radix(s, radix).map(DoubleDouble::from)
+    }
+}
+
+impl HornerScalar for DoubleDouble {
+    #[inline]
+    fn mul(&self, other: &Self) -> Self {
+        *self * *other
+    }
+
+    #[inline]
+    fn add(&self, other: &Self) -> Self {
+        *self + *other
+    }
+}
+
+/// Horner evaluation in double-double (complex)
+///
+/// The `horner_eval_dd` function evaluates the polynomial at a complex point in double-double
+/// arithmetic. The `f64` coefficients are converted exactly, so the rounding error is about
+/// `2^-106` relative to the sum of the magnitudes of the terms instead of `2^-53`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zval`: The point at which the polynomial is evaluated.
+///
+/// Returns:
+///
+/// The function `horner_eval_dd` returns `P(z)`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::doubledouble::{horner_eval_dd, DoubleDouble};
+/// use num::Complex;
+///
+/// // (x - 1)^2 at 1 + 1e-10: the f64 evaluation returns 0, double-double keeps the 1e-20
+/// let coeffs = [1.0, -2.0, 1.0];
+/// let re = DoubleDouble::from(1.0) + DoubleDouble::from(1e-10);
+/// let z = Complex::new(re, DoubleDouble::from(0.0));
+/// let px = horner_eval_dd(&coeffs, &z);
+///
+/// assert!((px.re.to_f64() - 1e-20).abs() < 1e-30);
+/// ```
+pub fn horner_eval_dd(coeffs: &[f64], zval: &Complex<DoubleDouble>) -> Complex<DoubleDouble> {
+    let mut res = Complex::<DoubleDouble>::zero();
+    for coeff in coeffs {
+        res = res * zval + Complex::new((*coeff).into(), DoubleDouble::zero());
+    }
+    res
+}
+
+/// The `horner_with_derivative_dd` function evaluates `P(z)` and `P'(z)` in one Horner sweep.
+fn horner_with_derivative_dd(
+    coeffs: &[f64],
+    zval: &Complex<DoubleDouble>,
+) -> (Complex<DoubleDouble>, Complex<DoubleDouble>) {
+    let mut p = Complex::<DoubleDouble>::zero();
+    let mut dp = Complex::<DoubleDouble>::zero();
+    for coeff in coeffs {
+        dp = dp * zval + p;
+        p = p * zval + Complex::new((*coeff).into(), DoubleDouble::zero());
+    }
+    (p, dp)
+}
+
+/// Aberth's method in double-double
+///
+/// The `aberth_dd` function runs the Gauss-Seidel sweeps of `aberth::aberth` with every evaluation
+/// and update in double-double arithmetic. An ill-conditioned root, whose `f64` residual is
+/// dominated by rounding error long before the root is accurate, keeps converging for another 53
+/// bits. Each sweep costs roughly ten times an `f64` sweep. Seed it with `aberth::initial_aberth`
+/// or with the `f64` roots, converted component-wise with `DoubleDouble::from`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `zs`: The initial guesses, refined in place.
+/// * `options`: The stopping criteria: `max_iters`, and `tol` on the largest residual `|P(z)|`
+///   (l1 norm), which can be set far below what `f64` evaluation reaches.
+///
+/// Returns:
+///
+/// The function `aberth_dd` returns the number of sweeps and whether the residuals met `tol`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::initial_aberth;
+/// use bairstow::doubledouble::{aberth_dd, DoubleDouble};
+/// use bairstow::rootfinding::Options;
+/// use num::Complex;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut zs: Vec<Complex<DoubleDouble>> = initial_aberth(&coeffs)
+///     .unwrap()
+///     .iter()
+///     .map(|z| Complex::new(z.re.into(), z.im.into()))
+///     .collect();
+/// let options = Options::default().tol(1e-28);
+/// let (_niter, found) = aberth_dd(&coeffs, &mut zs, &options);
+///
+/// assert!(found);
+/// ```
+pub fn aberth_dd(
+    coeffs: &[f64],
+    zs: &mut [Complex<DoubleDouble>],
+    options: &Options,
+) -> (usize, bool) {
+    for niter in 0..options.max_iters {
+        let mut tol = 0.0_f64;
+        for i in 0..zs.len() {
+            let zi = zs[i];
+            let (pp, pp1) = horner_with_derivative_dd(coeffs, &zi);
+            let tol_i = pp.re.hi.abs() + pp.im.hi.abs();
+            tol = nan_max(tol, tol_i);
+            if pp.is_zero() {
+                continue;
+            }
+            let mut denom = pp1;
+            for (_, zj) in zs.iter().enumerate().filter(|t| t.0 != i) {
+                denom = denom - pp / (zi - zj);
+            }
+            zs[i] = zi - pp / denom;
+        }
+        if tol < options.tol {
+            return (niter, true);
+        }
+    }
+    (options.max_iters, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aberth::{aberth, initial_aberth};
+
+    #[test]
+    fn test_double_double_arithmetic() {
+        let third = DoubleDouble::from(1.0) / DoubleDouble::from(3.0);
+        let one = third * DoubleDouble::from(3.0);
+        assert!((one - DoubleDouble::one()).abs().to_f64() < 1e-31);
+        // 1 + 2^-70 is lost in f64 but kept in lo
+        let tiny = 2.0_f64.powi(-70);
+        let sum = DoubleDouble::from(1.0) + DoubleDouble::from(tiny);
+        assert_eq!((sum.hi, sum.lo), (1.0, tiny));
+        assert_eq!((sum - DoubleDouble::one()).to_f64(), tiny);
+        assert_eq!(
+            DoubleDouble::from(7.5) % DoubleDouble::from(2.0),
+            DoubleDouble::from(1.5)
+        );
+    }
+
+    #[test]
+    fn test_aberth_dd_wilkinson() {
+        // (x^2 - 1)(x^2 - 2)...(x^2 - 12): exact integer coefficients, irrational roots +-sqrt(k)
+        // crowding together like the roots of Wilkinson's polynomial
+        let mut coeffs = vec![1.0];
+        for k in 1..=12 {
+            coeffs.extend([0.0, 0.0]);
+            for i in (2..coeffs.len()).rev() {
+                coeffs[i] -= k as f64 * coeffs[i - 2];
+            }
+        }
+        let error = |zs: &[Complex<f64>]| {
+            zs.iter()
+                .map(|z| {
+                    let k = (z.re * z.re).round().max(1.0);
+                    (z - k.sqrt().copysign(z.re)).norm()
+                })
+                .fold(0.0, f64::max)
+        };
+        let options = Options {
+            tol: 0.0,
+            max_iters: 200,
+            ..Options::default()
+        };
+
+        let mut zs = initial_aberth(&coeffs).unwrap();
+        aberth(&coeffs, &mut zs, &options);
+        let error_f64 = error(&zs);
+
+        let mut zs_dd: Vec<Complex<DoubleDouble>> = initial_aberth(&coeffs)
+            .unwrap()
+            .iter()
+            .map(|z| Complex::new(z.re.into(), z.im.into()))
+            .collect();
+        aberth_dd(&coeffs, &mut zs_dd, &options);
+        let zs: Vec<Complex<f64>> = zs_dd
+            .iter()
+            .map(|z| Complex::new(z.re.to_f64(), z.im.to_f64()))
+            .collect();
+        let error_dd = error(&zs);
+
+        assert!(error_f64 > 1e-11);
+        assert!(error_dd < 1e-15);
+        for k in 1..=12 {
+            let root = (k as f64).sqrt();
+            assert!(zs.iter().any(|z| (z - root).norm() < 1e-15));
+            assert!(zs.iter().any(|z| (z + root).norm() < 1e-15));
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt;
+
+/// The `RootError` enum lists the ways a root finder can reject its input.
+///
+/// Variants:
+///
+/// * `BufferLength`: A caller-provided buffer does not have the length the polynomial requires.
+/// * `ConstantPolynomial`: The polynomial has degree 0, so it has no roots to find.
+/// * `DivisionByZero`: A polynomial division was asked to divide by the zero polynomial.
+/// * `Empty`: The coefficient slice is empty.
+/// * `LeadingZero`: The leading coefficient is zero, so the degree is not `coeffs.len() - 1`.
+/// * `NonFinite`: A coefficient is NaN or infinite.
+/// * `ZeroPolynomial`: Every coefficient is zero, so every number is a root.
+/// * `RootCountMismatch`: An independent count of the `kind` roots (`"real"` or `"total"`) disagrees
+///   with the roots a solver returned.
+/// * `NotConverged`: The iteration stopped after `niter` sweeps with the largest residual `tol`
+///   still above the requested tolerance.
+/// * `UnsupportedOption`: The `method` would silently ignore the field `option` of `Options`, which
+///   is set away from its default.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RootError {
+    BufferLength {
+        name: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    ConstantPolynomial,
+    DivisionByZero,
+    Empty,
+    LeadingZero,
+    NonFinite,
+    ZeroPolynomial,
+    RootCountMismatch {
+        kind: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    NotConverged {
+        niter: usize,
+        tol: f64,
+    },
+    UnsupportedOption {
+        method: &'static str,
+        option: &'static str,
+    },
+}
+
+impl fmt::Display for RootError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootError::BufferLength {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "buffer `{}` has length {}, expected {}",
+                name, found, expected
+            ),
+            RootError::ConstantPolynomial => write!(f, "a constant polynomial has no roots"),
+            RootError::DivisionByZero => write!(f, "division by the zero polynomial"),
+            RootError::Empty => write!(f, "no coefficients given"),
+            RootError::LeadingZero => write!(f, "the leading coefficient is zero"),
+            RootError::NonFinite => write!(f, "a coefficient is not finite"),
+            RootError::ZeroPolynomial => {
+                write!(f, "the zero polynomial has infinitely many roots")
+            }
+            RootError::RootCountMismatch {
+                kind,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {} {} roots, the solver returned {}",
+                expected, kind, found
+            ),
+            RootError::NotConverged { niter, tol } => write!(
+                f,
+                "no convergence after {} iterations, the residual is {}",
+                niter, tol
+            ),
+            RootError::UnsupportedOption { method, option } => {
+                write!(f, "{} does not support `options.{}`", method, option)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RootError {}
+
+/// The `ParseError` struct reports a token of a coefficient list that is not a number.
+///
+/// Properties:
+///
+/// * `token`: The offending token.
+/// * `index`: The position of the token among the non-empty tokens, i.e. the coefficient index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub token: String,
+    pub index: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "coefficient {} `{}` is not a number",
+            self.index, self.token
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The `EvalError` enum lists the ways a polynomial evaluation can fail.
+///
+/// Variants:
+///
+/// * `Overflow`: An intermediate value of Horner's rule became infinite or NaN, typically because
+///   `|z|^degree` exceeds the range of `f64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvalError {
+    Overflow,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Overflow => write!(f, "the polynomial evaluation overflowed"),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// The `MultiplicityError` enum lists the ways `roots::multiplicities` can fail.
+///
+/// Variants:
+///
+/// * `Root`: The coefficients were rejected, see `RootError`.
+/// * `DegreeMismatch`: The multiplicities read off the clusters add up to `total` instead of the
+///   `degree` of the polynomial. The clustering tolerance does not fit the accuracy of the roots,
+///   or a root was missed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MultiplicityError {
+    Root(RootError),
+    DegreeMismatch { degree: usize, total: usize },
+}
+
+impl From<RootError> for MultiplicityError {
+    fn from(err: RootError) -> Self {
+        MultiplicityError::Root(err)
+    }
+}
+
+impl fmt::Display for MultiplicityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MultiplicityError::Root(err) => write!(f, "{}", err),
+            MultiplicityError::DegreeMismatch { degree, total } => write!(
+                f,
+                "the multiplicities add up to {}, the degree is {}",
+                total, degree
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MultiplicityError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MultiplicityError::Root(err) => Some(err),
+            MultiplicityError::DegreeMismatch { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use num::Complex;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+
+/// The `HornerScalar` trait is the minimal arithmetic needed by the generic `horner_eval`: a
+/// multiplication and an addition. Implementing it lets custom number types, such as dual numbers
+/// for automatic differentiation or fixed-point types, be evaluated with Horner's rule.
+pub trait HornerScalar: Clone {
+    fn mul(&self, other: &Self) -> Self;
+    fn add(&self, other: &Self) -> Self;
+}
+
+impl HornerScalar for f64 {
+    #[inline]
+    fn mul(&self, other: &Self) -> Self {
+        self * other
+    }
+
+    #[inline]
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+}
+
+impl HornerScalar for Complex<f64> {
+    #[inline]
+    fn mul(&self, other: &Self) -> Self {
+        self * other
+    }
+
+    #[inline]
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+}
+
+/// Generic Horner's rule
+///
+/// The `horner_eval` function evaluates a polynomial with Horner's rule using only the operations
+/// of `HornerScalar`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree. An empty slice
+///   is the zero polynomial and evaluates to `T::default()`.
+/// * `z`: The point at which the polynomial is evaluated.
+///
+/// Returns:
+///
+/// The function `horner_eval` returns `P(z)`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::horner::horner_eval;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+///
+/// assert_eq!(horner_eval(&coeffs, &2.0), 18250.0);
+/// ```
+pub fn horner_eval<T: HornerScalar + Default>(coeffs: &[T], z: &T) -> T {
+    let Some((first, rest)) = coeffs.split_first() else {
+        return T::default(); // empty: the zero polynomial
+    };
+    let mut res = first.clone();
+    for coeff in rest.iter() {
+        res = res.mul(z).add(coeff);
+    }
+    res
+}
+
+/// Horner's rule at a matrix
+///
+/// The `horner_eval_matrix` function evaluates a polynomial at a square matrix `A` with the matrix
+/// Horner recurrence `res = res A + a_k I`, one matrix product per coefficient. As `A` commutes
+/// with its powers, `P(A)` has eigenvalues `P(lambda)` for the eigenvalues `lambda` of `A`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `a`: The square matrix at which the polynomial is evaluated.
+///
+/// Returns:
+///
+/// The function `horner_eval_matrix` returns `P(A)`, the zero matrix for an empty `coeffs` and an
+/// empty matrix when `a` is not square.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::horner::horner_eval_matrix;
+/// use nalgebra::DMatrix;
+///
+/// // x^2 - 1 at a rotation by a quarter turn, whose square is -I
+/// let a = DMatrix::from_row_slice(2, 2, &[0.0, -1.0, 1.0, 0.0]);
+///
+/// assert_eq!(horner_eval_matrix(&[1.0, 0.0, -1.0], &a), DMatrix::identity(2, 2) * -2.0);
+/// ```
+#[cfg(feature = "nalgebra")]
+pub fn horner_eval_matrix(coeffs: &[f64], a: &nalgebra::DMatrix<f64>) -> nalgebra::DMatrix<f64> {
+    use nalgebra::DMatrix;
+
+    if !a.is_square() {
+        return DMatrix::zeros(0, 0);
+    }
+    let n = a.nrows();
+    let mut res = DMatrix::zeros(n, n);
+    for coeff in coeffs.iter() {
+        res = &res * a;
+        for i in 0..n {
+            res[(i, i)] += coeff;
+        }
+    }
+    res
+}
+
+/// The `EvalCache` struct memoizes real polynomial evaluations, for callers that evaluate the same
+/// polynomial at the same few points again and again.
+///
+/// An entry is keyed by a hash of the coefficient bit patterns, the number of coefficients and the
+/// bit pattern of `x`, so the key follows the coefficient *values*, not the slice they live in.
+/// Changing a coefficient in place therefore can never return a stale value: the changed polynomial
+/// has a new key, and the entries of the old one are unreachable until the least recently used
+/// entries are evicted, or `invalidate` drops them at once. Two polynomials share a key only on a
+/// 64-bit hash collision. `0.0` and `-0.0` are different keys. The entries are also ordered by
+/// their last use, so a hit or an eviction costs `O(log capacity)`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::horner::EvalCache;
+///
+/// let mut cache = EvalCache::new(16);
+/// let coeffs = [1.0, -3.0, 2.0];
+///
+/// assert_eq!(cache.eval_f(&coeffs, 3.0), 2.0);
+/// assert_eq!(cache.eval_f(&coeffs, 3.0), 2.0);
+/// assert_eq!(cache.hits(), 1);
+/// ```
+#[derive(Debug)]
+pub struct EvalCache {
+    capacity: usize,
+    clock: u64,
+    hits: usize,
+    entries: HashMap<EvalKey, (f64, u64)>,
+    by_use: BTreeMap<u64, EvalKey>,
+}
+
+/// The `EvalKey` type is the coefficient hash, the number of coefficients and the bits of `x`.
+type EvalKey = (u64, usize, u64);
+
+impl EvalCache {
+    /// The `new` function creates an empty cache holding at most `capacity` evaluations. A
+    /// capacity of `0` disables the caching.
+    pub fn new(capacity: usize) -> Self {
+        EvalCache {
+            capacity,
+            clock: 0,
+            hits: 0,
+            entries: HashMap::with_capacity(capacity),
+            by_use: BTreeMap::new(),
+        }
+    }
+
+    /// The `eval_f` function returns `P(x)`, from the cache if this polynomial was evaluated at
+    /// this `x` before and otherwise with `horner_eval`. When the cache is full, the least recently
+    /// used entry is evicted to make room.
+    ///
+    /// Arguments:
+    ///
+    /// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+    /// * `x`: The point at which the polynomial is evaluated.
+    ///
+    /// Returns:
+    ///
+    /// The function `eval_f` returns `P(x)`, bit-identical to `horner_eval(coeffs, &x)`.
+    pub fn eval_f(&mut self, coeffs: &[f64], x: f64) -> f64 {
+        let (hash, len) = coeffs_key(coeffs);
+        let key = (hash, len, x.to_bits());
+        self.clock += 1;
+        if let Some((value, used)) = self.entries.get_mut(&key) {
+            self.by_use.remove(used);
+            *used = self.clock;
+            self.by_use.insert(self.clock, key);
+            self.hits += 1;
+            return *value;
+        }
+        let value = horner_eval(coeffs, &x);
+        if self.capacity > 0 {
+            if self.entries.len() >= self.capacity {
+                if let Some((_, oldest)) = self.by_use.pop_first() {
+                    self.entries.remove(&oldest);
+                }
+            }
+            self.entries.insert(key, (value, self.clock));
+            self.by_use.insert(self.clock, key);
+        }
+        value
+    }
+
+    /// The `invalidate` function drops every cached evaluation of the polynomial `coeffs`, e.g.
+    /// with the old coefficients right before they are changed.
+    pub fn invalidate(&mut self, coeffs: &[f64]) {
+        let (hash, len) = coeffs_key(coeffs);
+        self.entries.retain(|k, _| (k.0, k.1) != (hash, len));
+        self.by_use.retain(|_, k| (k.0, k.1) != (hash, len));
+    }
+
+    /// The `clear` function drops every cached evaluation and resets the hit counter.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.by_use.clear();
+        self.hits = 0;
+    }
+
+    /// The `hits` function returns how many calls to `eval_f` were answered from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// The `len` function returns the number of cached evaluations.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The `is_empty` function returns whether nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// The `coeffs_key` function hashes the bit patterns of the coefficients and returns the hash with
+/// the number of coefficients.
+fn coeffs_key(coeffs: &[f64]) -> (u64, usize) {
+    let mut hasher = DefaultHasher::new();
+    for c in coeffs {
+        c.to_bits().hash(&mut hasher);
+    }
+    (hasher.finish(), coeffs.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::autodiff::Dual;
+
+    #[test]
+    fn test_horner_eval_dual() {
+        // P(x) = x^3 - 2x^2 + 3x - 4, P'(x) = 3x^2 - 4x + 3
+        let coeffs: Vec<Dual> = [1.0, -2.0, 3.0, -4.0]
+            .iter()
+            .map(|c| Dual::constant(*c))
+            .collect();
+        let x = 1.5;
+        let res = horner_eval(&coeffs, &Dual::variable(x));
+
+        assert_eq!(
+            res,
+            Dual {
+                val: x * x * x - 2.0 * x * x + 3.0 * x - 4.0,
+                der: 3.0 * x * x - 4.0 * x + 3.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_eval_cache() {
+        let mut cache = EvalCache::new(3);
+        let mut coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let first = cache.eval_f(&coeffs, 0.3);
+        let hit = cache.eval_f(&coeffs, 0.3);
+        assert_eq!(hit.to_bits(), first.to_bits());
+        assert_eq!(hit.to_bits(), horner_eval(&coeffs, &0.3).to_bits());
+        assert_eq!(cache.hits(), 1);
+
+        // a changed coefficient is a different key, so the old value is never returned
+        coeffs[4] = 151.0;
+        let changed = cache.eval_f(&coeffs, 0.3);
+        assert_eq!(cache.hits(), 1);
+        assert!((changed - first - 0.3_f64.powi(4)).abs() < 1e-12);
+        assert_eq!(cache.len(), 2);
+
+        // `invalidate` drops the entries of the old coefficients only
+        coeffs[4] = 150.0;
+        cache.invalidate(&coeffs);
+        assert_eq!(cache.len(), 1);
+        cache.eval_f(&coeffs, 0.3);
+        assert_eq!(cache.hits(), 1);
+
+        // the least recently used entry makes room: the changed polynomial at 0.3
+        cache.eval_f(&coeffs, 0.5);
+        cache.eval_f(&coeffs, 0.3);
+        cache.eval_f(&coeffs, 0.7);
+        assert_eq!(cache.len(), 3);
+        cache.eval_f(&coeffs, 0.3);
+        cache.eval_f(&coeffs, 0.7);
+        assert_eq!(cache.hits(), 4);
+        let mut changed_coeffs = coeffs.clone();
+        changed_coeffs[4] = 151.0;
+        assert_eq!(cache.eval_f(&changed_coeffs, 0.3), changed);
+        assert_eq!(cache.hits(), 4);
+
+        // bringing it back evicted 0.5, the least recently used since
+        cache.eval_f(&coeffs, 0.3);
+        cache.eval_f(&coeffs, 0.7);
+        assert_eq!(cache.hits(), 6);
+        cache.eval_f(&coeffs, 0.5);
+        assert_eq!(cache.hits(), 6);
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_horner_eval_matrix() {
+        use nalgebra::DMatrix;
+
+        let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let eigenvalues = [0.5, -1.25, 2.0];
+        let a = DMatrix::from_diagonal(&nalgebra::DVector::from_row_slice(&eigenvalues));
+        let pa = horner_eval_matrix(&coeffs, &a);
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j {
+                    horner_eval(&coeffs, &eigenvalues[i])
+                } else {
+                    0.0
+                };
+                assert_eq!(pa[(i, j)], expected);
+            }
+        }
+
+        assert_eq!(horner_eval_matrix(&[], &a), DMatrix::zeros(3, 3));
+        assert_eq!(
+            horner_eval_matrix(&[4.0], &a),
+            DMatrix::identity(3, 3) * 4.0
+        );
+        assert_eq!(horner_eval_matrix(&coeffs, &DMatrix::zeros(2, 3)).len(), 0);
+    }
+}
@@ -1,16 +1,33 @@
 #![allow(non_snake_case)]
 
 pub mod aberth;
+pub mod autodiff;
+#[cfg(feature = "bigfloat")]
+pub mod bigfloat;
+pub mod certify;
+pub mod chebyshev;
+pub mod doubledouble;
+pub mod error;
+pub mod horner;
 pub mod matrix2;
+pub mod poly;
 pub mod rootfinding;
+pub mod roots;
+pub mod solver;
+#[cfg(any(test, feature = "test-util"))]
+pub mod testutil;
 pub mod vector2;
 // pub mod robin;
 
-pub use crate::aberth::{aberth, aberth_mt, initial_aberth};
+pub use crate::aberth::{
+    aberth, aberth_detailed, aberth_into, aberth_mt, initial_aberth, AberthResult, StopReason,
+};
+pub use crate::error::{EvalError, MultiplicityError, ParseError, RootError};
 pub use crate::matrix2::Matrix2;
 pub use crate::rootfinding::{
-    horner_eval, initial_autocorr, initial_guess, pbairstow_autocorr, pbairstow_autocorr_mt,
-    pbairstow_even, pbairstow_even_mt, Options,
+    bairstow, horner_eval, initial_autocorr, initial_guess, pbairstow_autocorr,
+    pbairstow_autocorr_mt, pbairstow_even, pbairstow_even_mt, DeflationDirection, IterationScheme,
+    Options, ResidualScaling,
 };
 pub use crate::vector2::Vector2;
 
@@ -53,23 +70,24 @@ mod tests {
             max_iters: 2000,
             tol: 1e-14,
             tol_ind: 1e-15,
+            ..Options::default()
         };
 
         let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
 
-        let mut vrs = initial_guess(&coeffs);
+        let mut vrs = initial_guess(&coeffs).unwrap();
         let (niter, _found) = pbairstow_even(&coeffs, &mut vrs, &options);
         println!("{niter}");
 
-        let mut vrs = initial_guess(&coeffs);
+        let mut vrs = initial_guess(&coeffs).unwrap();
         let (niter, _found) = pbairstow_even_mt(&coeffs, &mut vrs, &options);
         println!("{niter}");
 
-        let mut vrs = initial_autocorr(&coeffs);
+        let mut vrs = initial_autocorr(&coeffs).unwrap();
         let (niter, _found) = pbairstow_autocorr(&coeffs, &mut vrs, &options);
         println!("{niter}");
 
-        let mut vrs = initial_autocorr(&coeffs);
+        let mut vrs = initial_autocorr(&coeffs).unwrap();
         let (niter, _found) = pbairstow_autocorr_mt(&coeffs, &mut vrs, &options);
         println!("{niter}");
 
@@ -77,13 +95,14 @@ mod tests {
             max_iters: 2000,
             tol: 1e-12,
             tol_ind: 1e-15,
+            ..Options::default()
         };
 
-        let mut zs = initial_aberth(&coeffs);
+        let mut zs = initial_aberth(&coeffs).unwrap();
         let (niter, _found) = aberth(&coeffs, &mut zs, &options);
         println!("{niter}");
 
-        let mut zs = initial_aberth(&coeffs);
+        let mut zs = initial_aberth(&coeffs).unwrap();
         let (niter, _found) = aberth_mt(&coeffs, &mut zs, &options);
         println!("{niter}");
     }
@@ -0,0 +1,1575 @@
+use crate::aberth::horner_with_derivative_c;
+use crate::error::{ParseError, RootError};
+use crate::rootfinding::{DeflationDirection, Options};
+use crate::vector2::Vector2;
+use num::{BigInt, Complex, One, Zero};
+
+/// The `Polynomial` struct wraps real coefficients ordered from highest degree to lowest degree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial(pub Vec<f64>);
+
+impl Polynomial {
+    /// The `eval_and_deriv` function evaluates the polynomial and its derivative at `z` in a single
+    /// Horner pass, see `horner_with_derivative_c`.
+    ///
+    /// Arguments:
+    ///
+    /// * `z`: The complex point at which the polynomial is evaluated.
+    ///
+    /// Returns:
+    ///
+    /// The function `eval_and_deriv` returns the pair `(P(z), P'(z))`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use bairstow::poly::Polynomial;
+    /// use num::Complex;
+    ///
+    /// let p = Polynomial(vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0]);
+    /// let z = Complex::new(-0.2, 1.0);
+    /// let (pz, dpz) = p.eval_and_deriv(&z);
+    /// let z1 = z - pz / dpz; // one Newton step
+    ///
+    /// assert!(p.eval_and_deriv(&z1).0.norm() < pz.norm());
+    /// ```
+    #[inline]
+    pub fn eval_and_deriv(&self, z: &Complex<f64>) -> (Complex<f64>, Complex<f64>) {
+        horner_with_derivative_c(&self.0, z)
+    }
+}
+
+impl TryFrom<&str> for Polynomial {
+    type Error = ParseError;
+
+    /// Parses a coefficient list with `parse_coeffs`.
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        parse_coeffs(s).map(Polynomial)
+    }
+}
+
+/// Parse a coefficient list
+///
+/// The `parse_coeffs` function reads coefficients separated by any mix of whitespace and commas,
+/// e.g. `"10 34, 75,94"`. Empty tokens, as between two consecutive commas, are skipped. Each token
+/// is parsed as an `f64`, so `NaN` and `inf` are accepted here and rejected later by `degree`.
+///
+/// Arguments:
+///
+/// * `s`: The coefficients, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `parse_coeffs` returns the coefficients, or a `ParseError` naming the first token
+/// that is not a number.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::{parse_coeffs, Polynomial};
+///
+/// assert_eq!(parse_coeffs("1, -3,\t2\n"), Ok(vec![1.0, -3.0, 2.0]));
+/// assert_eq!(parse_coeffs("1 x 2").unwrap_err().token, "x");
+/// assert_eq!(Polynomial::try_from("1,,0 -1"), Ok(Polynomial(vec![1.0, 0.0, -1.0])));
+/// ```
+pub fn parse_coeffs(s: &str) -> Result<Vec<f64>, ParseError> {
+    s.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .enumerate()
+        .map(|(index, token)| {
+            token.parse().map_err(|_| ParseError {
+                token: token.to_owned(),
+                index,
+            })
+        })
+        .collect()
+}
+
+/// Polynomial long division
+///
+/// The `poly_divmod` function divides `num` by `den` and returns the quotient and the remainder, so
+/// that `num = quotient * den + remainder`. Leading zeros of `den` are ignored.
+///
+/// Arguments:
+///
+/// * `num`: The dividend, ordered from highest degree to lowest degree.
+/// * `den`: The divisor, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `poly_divmod` returns `(quotient, remainder)`. The remainder has `deg(den)`
+/// coefficients (or `num.len()` if `num` is shorter), including any leading zeros. Dividing by the
+/// zero polynomial gives `RootError::DivisionByZero`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::poly_divmod;
+///
+/// // (x^3 - 2x^2 - 4) / (x - 3) = x^2 + x + 3, remainder 5
+/// let (q, r) = poly_divmod(&[1.0, -2.0, 0.0, -4.0], &[1.0, -3.0]).unwrap();
+///
+/// assert_eq!(q, vec![1.0, 1.0, 3.0]);
+/// assert_eq!(r, vec![5.0]);
+/// ```
+pub fn poly_divmod(num: &[f64], den: &[f64]) -> Result<(Vec<f64>, Vec<f64>), RootError> {
+    let den = trim_leading(den, 0.0);
+    if den.is_empty() {
+        return Err(RootError::DivisionByZero);
+    }
+    if num.len() < den.len() {
+        return Ok((vec![], num.to_owned()));
+    }
+    let mut rem = num.to_owned();
+    let nq = num.len() - den.len() + 1;
+    let mut quot = vec![0.0; nq];
+    for i in 0..nq {
+        let q = rem[i] / den[0];
+        quot[i] = q;
+        for (j, d) in den.iter().enumerate() {
+            rem[i + j] -= q * d;
+        }
+    }
+    Ok((quot, rem.split_off(nq)))
+}
+
+/// The `trim_leading` function drops the leading coefficients whose magnitude is at most `thresh`.
+fn trim_leading(coeffs: &[f64], thresh: f64) -> &[f64] {
+    let start = coeffs
+        .iter()
+        .position(|c| c.abs() > thresh)
+        .unwrap_or(coeffs.len());
+    &coeffs[start..]
+}
+
+/// The `max_abs` function returns the largest coefficient magnitude.
+fn max_abs(coeffs: &[f64]) -> f64 {
+    coeffs.iter().fold(0.0, |acc: f64, c| acc.max(c.abs()))
+}
+
+/// Greatest common divisor of two polynomials
+///
+/// The `poly_gcd` function runs the Euclidean algorithm with `poly_divmod`. In floating point a
+/// remainder is hardly ever exactly zero, so every remainder coefficient at most `tol` times the
+/// largest coefficient of the current dividend is treated as zero. The result depends strongly on
+/// `tol`: too small and rounding noise hides a common factor (the GCD collapses to `1`), too large and
+/// nearby but distinct roots are merged into a spurious common factor.
+///
+/// Arguments:
+///
+/// * `a`: The first polynomial, ordered from highest degree to lowest degree.
+/// * `b`: The second polynomial, ordered from highest degree to lowest degree.
+/// * `tol`: The relative threshold under which remainder coefficients count as zero.
+///
+/// Returns:
+///
+/// The function `poly_gcd` returns the monic GCD, or an empty vector if both inputs are zero.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::poly_gcd;
+/// use approx_eq::assert_approx_eq;
+///
+/// // (x - 1)(x - 2) and (x - 1)(x - 3)
+/// let g = poly_gcd(&[1.0, -3.0, 2.0], &[1.0, -4.0, 3.0], 1e-12);
+///
+/// assert_eq!(g.len(), 2);
+/// assert_approx_eq!(g[0], 1.0);
+/// assert_approx_eq!(g[1], -1.0);
+/// ```
+pub fn poly_gcd(a: &[f64], b: &[f64], tol: f64) -> Vec<f64> {
+    let mut r0 = trim_leading(a, tol * max_abs(a)).to_owned();
+    let mut r1 = trim_leading(b, tol * max_abs(b)).to_owned();
+    if r0.len() < r1.len() {
+        std::mem::swap(&mut r0, &mut r1);
+    }
+    // stops once the remainder `r1` is the zero polynomial
+    while let Ok((_, rem)) = poly_divmod(&r0, &r1) {
+        let rem = trim_leading(&rem, tol * max_abs(&r0)).to_owned();
+        r0 = r1;
+        r1 = rem;
+    }
+    if let Some(&lead) = r0.first() {
+        r0.iter_mut().for_each(|c| *c /= lead);
+    }
+    r0
+}
+
+/// Square-free part of a polynomial
+///
+/// The `squarefree` function divides `coeffs` by `gcd(P, P')`, which removes every repeated factor:
+/// each root of `P` appears exactly once in the result. The GCD is computed with `poly_gcd` and
+/// `options.zero_threshold` as its relative tolerance, so raising the threshold also merges roots
+/// that are close but not equal. The remainders of the Euclidean algorithm carry rounding errors
+/// well above `f64::EPSILON`, so the default threshold rarely finds the GCD; `1e-12` is a
+/// practical start.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+/// * `options`: Provides `zero_threshold`.
+///
+/// Returns:
+///
+/// The function `squarefree` returns the square-free part, with the leading coefficient of `coeffs`,
+/// or `coeffs` unchanged when its derivative vanishes.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::squarefree;
+/// use bairstow::rootfinding::Options;
+/// use approx_eq::assert_approx_eq;
+///
+/// // (x - 1)^3 (x + 2)^2 becomes (x - 1)(x + 2)
+/// let options = Options::default().zero_threshold(1e-12);
+/// let sf = squarefree(&[1.0, 1.0, -5.0, -1.0, 8.0, -4.0], &options);
+///
+/// assert_eq!(sf.len(), 3);
+/// assert_approx_eq!(sf[1], 1.0);
+/// assert_approx_eq!(sf[2], -2.0);
+/// ```
+pub fn squarefree(coeffs: &[f64], options: &Options) -> Vec<f64> {
+    let g = poly_gcd(coeffs, &derivative(coeffs), options.zero_threshold);
+    match poly_divmod(coeffs, &g) {
+        Ok((quotient, _)) => quotient,
+        Err(_) => coeffs.to_vec(),
+    }
+}
+
+/// Graeffe's root-squaring step
+///
+/// The `graeffe_step` function returns the polynomial whose roots are the squares of the roots of
+/// `coeffs`, computed from `Q(x^2) = (-1)^n P(x) P(-x)`. Every step squares the root magnitudes, so
+/// roots of nearly equal magnitude drift apart. After `k` steps a root `w` of the transformed
+/// polynomial maps back to the `2^k` candidates `w^(1/2^k) * exp(2*pi*i*m/2^k)`, all of magnitude
+/// `|w|^(1/2^k)`; only the magnitude is recovered without testing the candidates against `P`.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `graeffe_step` returns the coefficients of the root-squared polynomial, which has
+/// the same degree and the leading coefficient `coeffs[0]^2`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::graeffe_step;
+///
+/// // (x - 1)(x + 2)(x - 3) becomes (y - 1)(y - 4)(y - 9)
+/// let q = graeffe_step(&[1.0, -2.0, -5.0, 6.0]);
+///
+/// assert_eq!(q, vec![1.0, -14.0, 49.0, -36.0]);
+/// ```
+pub fn graeffe_step(coeffs: &[f64]) -> Vec<f64> {
+    if coeffs.is_empty() {
+        return vec![];
+    }
+    let degree = coeffs.len() - 1;
+    // coefficient of x^k in P(-x) flips sign for odd k
+    let neg: Vec<f64> = coeffs
+        .iter()
+        .enumerate()
+        .map(|(i, c)| if (degree - i) % 2 == 1 { -c } else { *c })
+        .collect();
+    let mut prod = vec![0.0; 2 * degree + 1];
+    for (i, a) in coeffs.iter().enumerate() {
+        for (j, b) in neg.iter().enumerate() {
+            prod[i + j] += a * b;
+        }
+    }
+    let sign = if degree % 2 == 1 { -1.0 } else { 1.0 };
+    prod.iter().step_by(2).map(|c| sign * c).collect()
+}
+
+/// Drop negligible coefficients
+///
+/// The `chop` function sets every coefficient with `|a_i| < rel_tol * max_j |a_j|` to zero and then
+/// removes the leading zeros. This changes the polynomial and may move its roots, in particular when
+/// a leading coefficient is dropped, so it is meant for cleaning up rounding noise left by earlier
+/// arithmetic, not for simplifying a genuine polynomial.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+/// * `rel_tol`: The threshold relative to the largest coefficient magnitude.
+///
+/// Returns:
+///
+/// The function `chop` returns the cleaned coefficients, or an empty vector if all of them were
+/// dropped.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::chop;
+///
+/// assert_eq!(chop(&[1e-18, 1.0, 2e-18, -3.0, 2.0], 1e-12), vec![1.0, 0.0, -3.0, 2.0]);
+/// ```
+pub fn chop(coeffs: &[f64], rel_tol: f64) -> Vec<f64> {
+    let thresh = rel_tol * max_abs(coeffs);
+    let chopped: Vec<f64> = coeffs
+        .iter()
+        .map(|c| if c.abs() < thresh { 0.0 } else { *c })
+        .collect();
+    trim_leading(&chopped, 0.0).to_vec()
+}
+
+/// Cauchy's bound on the root magnitudes
+///
+/// The `cauchy_bound` function returns `1 + max_i |a_i / a_0|` for `i >= 1`; every root `z` of the
+/// polynomial satisfies `|z| <= cauchy_bound(coeffs)`.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree, with `coeffs[0] != 0`.
+///
+/// Returns:
+///
+/// The function `cauchy_bound` returns the radius of a disk around the origin containing all roots,
+/// `1` for an empty slice and infinity for a zero leading coefficient.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::cauchy_bound;
+///
+/// // (x - 1)(x - 2)
+/// assert_eq!(cauchy_bound(&[1.0, -3.0, 2.0]), 4.0);
+/// ```
+pub fn cauchy_bound(coeffs: &[f64]) -> f64 {
+    match coeffs.split_first() {
+        Some((lead, rest)) => 1.0 + max_abs(rest) / lead.abs(),
+        None => 1.0,
+    }
+}
+
+/// Companion-matrix bound on the root magnitudes
+///
+/// The `companion_norm_bound` function returns the 1-norm (largest column sum) of the companion
+/// matrix of the monic polynomial `x^n + b_1 x^(n-1) + ... + b_n`, with `b_i = a_i / a_0`. The
+/// columns are `(b_i, 1)` for `i < n` and `b_n` alone, so the norm is
+/// `max(|b_n|, 1 + max_{i<n} |b_i|)`, computed in `O(n)` without building the matrix. Every
+/// eigenvalue, hence every root, has a magnitude at most this norm. It never exceeds `cauchy_bound`.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree, with `coeffs[0] != 0`.
+///
+/// Returns:
+///
+/// The function `companion_norm_bound` returns the radius of a disk around the origin containing
+/// all roots, `0` for a constant or empty slice and infinity for a zero leading coefficient.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::{cauchy_bound, companion_norm_bound};
+///
+/// // (x - 1)(x - 2): the columns are (-3, 1) and (2)
+/// assert_eq!(companion_norm_bound(&[1.0, -3.0, 2.0]), 4.0);
+/// // (x - 1)(x + 2)(x - 3): tighter than `cauchy_bound`, `1 + max |b_i| = 7`
+/// assert_eq!(companion_norm_bound(&[1.0, -2.0, -5.0, 6.0]), 6.0);
+/// assert!(companion_norm_bound(&[1.0, -2.0, -5.0, 6.0]) < cauchy_bound(&[1.0, -2.0, -5.0, 6.0]));
+/// ```
+pub fn companion_norm_bound(coeffs: &[f64]) -> f64 {
+    let Some((lead, rest)) = coeffs.split_first() else {
+        return 0.0;
+    };
+    let Some((last, middle)) = rest.split_last() else {
+        return 0.0;
+    };
+    let last_column = last.abs() / lead.abs();
+    if middle.is_empty() {
+        return last_column;
+    }
+    last_column.max(1.0 + max_abs(middle) / lead.abs())
+}
+
+/// The `degree` function validates a coefficient slice and returns the degree of the polynomial.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `degree` returns `coeffs.len() - 1`, or in this order of precedence
+/// `RootError::Empty` for an empty slice, `RootError::NonFinite` for a NaN or infinite coefficient,
+/// `RootError::ConstantPolynomial` for a single coefficient, which has no roots, and
+/// `RootError::LeadingZero` for a zero leading coefficient.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::degree;
+/// use bairstow::RootError;
+///
+/// assert_eq!(degree(&[1.0, -3.0, 2.0]), Ok(2));
+/// assert_eq!(degree(&[5.0]), Err(RootError::ConstantPolynomial));
+/// assert_eq!(degree(&[]), Err(RootError::Empty));
+/// assert_eq!(degree(&[0.0, 1.0]), Err(RootError::LeadingZero));
+/// assert_eq!(degree(&[1.0, f64::NAN]), Err(RootError::NonFinite));
+/// ```
+pub fn degree(coeffs: &[f64]) -> Result<usize, RootError> {
+    if coeffs.is_empty() {
+        return Err(RootError::Empty);
+    }
+    if coeffs.iter().any(|c| !c.is_finite()) {
+        return Err(RootError::NonFinite);
+    }
+    if coeffs.len() == 1 {
+        return Err(RootError::ConstantPolynomial);
+    }
+    if coeffs[0] == 0.0 {
+        return Err(RootError::LeadingZero);
+    }
+    Ok(coeffs.len() - 1)
+}
+
+/// The `PolyClass` enum tells a polynomial with roots from the degenerate cases.
+///
+/// Variants:
+///
+/// * `Zero`: Every coefficient is zero (or there are none); every number is a root.
+/// * `Constant`: A non-zero constant; there is no root.
+/// * `Degree`: The degree `n >= 1` after skipping leading zeros; there are `n` roots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolyClass {
+    Zero,
+    Constant,
+    Degree(usize),
+}
+
+/// Classify a polynomial by degree
+///
+/// The `classify_poly` function skips the leading zero coefficients and tells the zero polynomial
+/// and the non-zero constants from the polynomials that have roots. A NaN coefficient counts as
+/// non-zero; `degree` rejects it.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `classify_poly` returns the `PolyClass`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::{classify_poly, PolyClass};
+///
+/// assert_eq!(classify_poly(&[0.0]), PolyClass::Zero);
+/// assert_eq!(classify_poly(&[]), PolyClass::Zero);
+/// assert_eq!(classify_poly(&[5.0]), PolyClass::Constant);
+/// assert_eq!(classify_poly(&[0.0, 0.0, 5.0]), PolyClass::Constant);
+/// assert_eq!(classify_poly(&[0.0, 1.0, -3.0, 2.0]), PolyClass::Degree(2));
+/// ```
+pub fn classify_poly(coeffs: &[f64]) -> PolyClass {
+    let start = coeffs
+        .iter()
+        .position(|c| *c != 0.0)
+        .unwrap_or(coeffs.len());
+    match coeffs.len() - start {
+        0 => PolyClass::Zero,
+        1 => PolyClass::Constant,
+        n => PolyClass::Degree(n - 1),
+    }
+}
+
+/// Number of distinct real roots
+///
+/// The `count_real_roots` function builds the Sturm sequence `p_0 = P`, `p_1 = P'`,
+/// `p_(k+1) = -rem(p_(k-1), p_k)` and returns the difference between the number of sign changes of
+/// the sequence at `-inf` and at `+inf`, which is the number of distinct real roots. A remainder
+/// whose coefficients are all below `1e-10` times those of the dividend is treated as zero, so
+/// nearly multiple roots are counted once.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `count_real_roots` returns the number of distinct real roots, `0` for a constant or
+/// empty polynomial, and `0` if rounding leaves more sign changes at `+inf` than at `-inf`.
+/// Leading zeros are ignored.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::count_real_roots;
+///
+/// // (x^2 + 1)(x - 1)(x + 2)
+/// assert_eq!(count_real_roots(&[1.0, 1.0, -1.0, 1.0, -2.0]), 2);
+/// ```
+pub fn count_real_roots(coeffs: &[f64]) -> usize {
+    let coeffs = trim_leading(coeffs, 0.0);
+    if coeffs.len() < 2 {
+        return 0;
+    }
+    let mut seq = vec![coeffs.to_vec(), derivative(coeffs)];
+    while seq[seq.len() - 1].len() > 1 {
+        let (prev, last) = (&seq[seq.len() - 2], &seq[seq.len() - 1]);
+        let Ok((_, rem)) = poly_divmod(prev, last) else {
+            break;
+        };
+        let rem = trim_leading(&rem, 1e-10 * max_abs(prev));
+        if rem.is_empty() {
+            break;
+        }
+        seq.push(rem.iter().map(|c| -c).collect());
+    }
+    // the sign at +inf is that of the leading coefficient, flipped at -inf for odd degrees
+    let sign_changes = |at_neg_inf: bool| {
+        let signs: Vec<bool> = seq
+            .iter()
+            .map(|p| (p[0] > 0.0) ^ (at_neg_inf && p.len() % 2 == 0))
+            .collect();
+        signs.windows(2).filter(|w| w[0] != w[1]).count()
+    };
+    // rounding in the remainders can leave more sign changes at +inf than at -inf
+    sign_changes(true).saturating_sub(sign_changes(false))
+}
+
+/// The `derivative` function returns the coefficients of `P'`.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `derivative` returns `coeffs.len() - 1` coefficients, or none for an empty slice.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::derivative;
+///
+/// assert_eq!(derivative(&[1.0, -2.0, 3.0, -4.0]), vec![3.0, -4.0, 3.0]);
+/// ```
+pub fn derivative(coeffs: &[f64]) -> Vec<f64> {
+    let degree = coeffs.len().saturating_sub(1);
+    coeffs[..degree]
+        .iter()
+        .enumerate()
+        .map(|(i, c)| c * (degree - i) as f64)
+        .collect()
+}
+
+/// Resultant of two polynomials
+///
+/// The `resultant` function computes `Res(a, b)` as the determinant of the Sylvester matrix, by
+/// Gaussian elimination with partial pivoting. The resultant vanishes exactly when `a` and `b` share
+/// a root. Leading zero coefficients are stripped first, so the degrees used are the true degrees.
+/// The resultant with the zero polynomial is `0`; if `a` is a nonzero constant, `Res(a, b) = a^deg(b)`,
+/// and symmetrically for `b`. These conventions make every finite input valid, so only non-finite
+/// coefficients are rejected.
+///
+/// Arguments:
+///
+/// * `a`: The first polynomial, ordered from highest degree to lowest degree.
+/// * `b`: The second polynomial, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `resultant` returns `Res(a, b)`, or `RootError::NonFinite` when a coefficient is
+/// NaN or infinite.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::resultant;
+///
+/// // Res(x - 2, x^2 - 1) = (2^2 - 1) = 3
+/// assert!((resultant(&[1.0, -2.0], &[1.0, 0.0, -1.0]).unwrap() - 3.0).abs() < 1e-12);
+/// // x^2 - 3x + 2 and x^2 - 1 share the root 1
+/// assert!(resultant(&[1.0, -3.0, 2.0], &[1.0, 0.0, -1.0]).unwrap().abs() < 1e-12);
+/// ```
+pub fn resultant(a: &[f64], b: &[f64]) -> Result<f64, RootError> {
+    if a.iter().chain(b).any(|c| !c.is_finite()) {
+        return Err(RootError::NonFinite);
+    }
+    let a = trim_leading(a, 0.0);
+    let b = trim_leading(b, 0.0);
+    if a.is_empty() || b.is_empty() {
+        return Ok(0.0);
+    }
+    let (m, n) = (a.len() - 1, b.len() - 1);
+    let size = m + n;
+    if size == 0 {
+        return Ok(1.0);
+    }
+    let mut mat = vec![vec![0.0; size]; size];
+    for i in 0..n {
+        mat[i][i..i + m + 1].copy_from_slice(a);
+    }
+    for i in 0..m {
+        mat[n + i][i..i + n + 1].copy_from_slice(b);
+    }
+    let mut det = 1.0;
+    for col in 0..size {
+        let pivot = (col..size)
+            .max_by(|&r, &s| mat[r][col].abs().total_cmp(&mat[s][col].abs()))
+            .unwrap();
+        if mat[pivot][col] == 0.0 {
+            return Ok(0.0);
+        }
+        if pivot != col {
+            mat.swap(pivot, col);
+            det = -det;
+        }
+        det *= mat[col][col];
+        let (upper, lower) = mat.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower.iter_mut() {
+            let factor = row[col] / pivot_row[col];
+            for (x, p) in row[col..].iter_mut().zip(pivot_row[col..].iter()) {
+                *x -= factor * p;
+            }
+        }
+    }
+    Ok(det)
+}
+
+/// Exact resultant of two integer polynomials
+///
+/// The `resultant_exact` function computes `Res(a, b)` as the determinant of the Sylvester matrix
+/// like `resultant`, but over the integers with the fraction-free elimination of Bareiss: step `k`
+/// replaces every entry below and right of the pivot by `(m_kk m_ij - m_ik m_kj) / m_(k-1)(k-1)`,
+/// and the division is exact, so the entries stay integers of moderate size and the result has no
+/// rounding error. A zero pivot is swapped with a lower row. The conventions for leading zeros,
+/// constants and the zero polynomial are those of `resultant`.
+///
+/// Arguments:
+///
+/// * `a`: The first polynomial, ordered from highest degree to lowest degree.
+/// * `b`: The second polynomial, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `resultant_exact` returns `Res(a, b)`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::resultant_exact;
+/// use num::BigInt;
+///
+/// let ints = |cs: &[i64]| cs.iter().map(|&c| BigInt::from(c)).collect::<Vec<_>>();
+///
+/// // Res(x^2 - 3x + 2, x - 5) = (1 - 5)(2 - 5)
+/// assert_eq!(resultant_exact(&ints(&[1, -3, 2]), &ints(&[1, -5])), BigInt::from(12));
+/// ```
+pub fn resultant_exact(a: &[BigInt], b: &[BigInt]) -> BigInt {
+    let a = &a[a.iter().position(|c| !c.is_zero()).unwrap_or(a.len())..];
+    let b = &b[b.iter().position(|c| !c.is_zero()).unwrap_or(b.len())..];
+    if a.is_empty() || b.is_empty() {
+        return BigInt::zero();
+    }
+    let (m, n) = (a.len() - 1, b.len() - 1);
+    let size = m + n;
+    if size == 0 {
+        return BigInt::one();
+    }
+    let mut mat = vec![vec![BigInt::zero(); size]; size];
+    for i in 0..n {
+        mat[i][i..i + m + 1].clone_from_slice(a);
+    }
+    for i in 0..m {
+        mat[n + i][i..i + n + 1].clone_from_slice(b);
+    }
+    let mut negate = false;
+    let mut prev = BigInt::one();
+    for k in 0..size {
+        if mat[k][k].is_zero() {
+            match (k + 1..size).find(|&r| !mat[r][k].is_zero()) {
+                Some(r) => {
+                    mat.swap(k, r);
+                    negate = !negate;
+                }
+                None => return BigInt::zero(),
+            }
+        }
+        let (upper, lower) = mat.split_at_mut(k + 1);
+        let pivot_row = &upper[k];
+        for row in lower.iter_mut() {
+            let factor = std::mem::take(&mut row[k]);
+            for (x, p) in row[k + 1..].iter_mut().zip(pivot_row[k + 1..].iter()) {
+                *x = (&pivot_row[k] * &*x - &factor * p) / &prev;
+            }
+        }
+        prev = pivot_row[k].clone();
+    }
+    if negate {
+        -prev
+    } else {
+        prev
+    }
+}
+
+/// Discriminant of a polynomial
+///
+/// The `discriminant` function computes `(-1)^(n(n-1)/2) / a_n * Res(P, P')` with `resultant` and
+/// `derivative`, where `n` is the degree and `a_n` the leading coefficient. It is zero exactly when
+/// `P` has a repeated root, and a value that is tiny relative to the size of the coefficients raised
+/// to the power `2n - 2` indicates nearly repeated roots, a hint to run a squarefree decomposition
+/// first.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `discriminant` returns the discriminant of `P`, or the error of `degree` for
+/// invalid coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::discriminant;
+///
+/// // b^2 - 4ac for x^2 - 3x + 2
+/// assert!((discriminant(&[1.0, -3.0, 2.0]).unwrap() - 1.0).abs() < 1e-12);
+/// ```
+pub fn discriminant(coeffs: &[f64]) -> Result<f64, RootError> {
+    let n = degree(coeffs)?;
+    let sign = if (n * (n - 1) / 2) % 2 == 1 {
+        -1.0
+    } else {
+        1.0
+    };
+    Ok(sign / coeffs[0] * resultant(coeffs, &derivative(coeffs))?)
+}
+
+/// Synthetic division by a linear factor (Ruffini's rule)
+///
+/// The `horner_divmod_linear` function divides `P` by `x - a` in one Horner sweep: the partial
+/// sums `q_k = q_{k-1} a + a_k` are the coefficients of the quotient, and the last one is the
+/// remainder `P(a)`, so `P(x) = Q(x) (x - a) + P(a)`.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+/// * `a`: The point to divide at.
+///
+/// Returns:
+///
+/// The function `horner_divmod_linear` returns the `coeffs.len() - 1` coefficients of the quotient
+/// and the remainder `P(a)`; an empty polynomial has remainder `0`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::horner_divmod_linear;
+///
+/// // x^2 + 1 = (x + 2)(x - 2) + 5
+/// assert_eq!(horner_divmod_linear(&[1.0, 0.0, 1.0], 2.0), (vec![1.0, 2.0], 5.0));
+/// ```
+pub fn horner_divmod_linear(coeffs: &[f64], a: f64) -> (Vec<f64>, f64) {
+    let mut quot = Vec::with_capacity(coeffs.len().saturating_sub(1));
+    let mut acc = 0.0;
+    for (k, c) in coeffs.iter().enumerate() {
+        if k > 0 {
+            quot.push(acc);
+        }
+        acc = acc * a + c;
+    }
+    (quot, acc)
+}
+
+/// Deflation by a real root
+///
+/// The `deflate_linear` function divides `P` by `x - r` with synthetic division and drops the
+/// remainder `P(r)`, which is small when `r` is a good root estimate. Deflating the roots of
+/// smallest magnitude first keeps the rounding errors of the quotient small.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+/// * `r`: The root to divide out.
+///
+/// Returns:
+///
+/// The function `deflate_linear` returns the `coeffs.len() - 1` coefficients of the quotient.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::deflate_linear;
+///
+/// // (x - 1)(x - 2) / (x - 2)
+/// assert_eq!(deflate_linear(&[1.0, -3.0, 2.0], 2.0), vec![1.0, -1.0]);
+/// ```
+pub fn deflate_linear(coeffs: &[f64], r: f64) -> Vec<f64> {
+    horner_divmod_linear(coeffs, r).0
+}
+
+/// Deflation by a real root from either end
+///
+/// The `deflate_linear_with` function divides `P` by `x - r` like `deflate_linear`, but can run
+/// the division from the constant coefficient: `Backward` solves `a_n = -r q_{n-1}` and
+/// `a_k = q_k - r q_{k-1}` for the quotient from the bottom up. Forward deflation leaves the
+/// remainder `P(r)` at the constant term and multiplies the rounding errors by `r` at every step,
+/// backward deflation leaves the remainder `a_0 - q_0` at the leading term and divides them by `r`.
+/// So forward deflation is stable for small roots and backward deflation for large ones; `Auto`
+/// picks by `|r|` relative to 1. `Backward` by `r = 0` runs forward, as it would divide by zero.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+/// * `r`: The root to divide out.
+/// * `direction`: The end to divide from.
+///
+/// Returns:
+///
+/// The function `deflate_linear_with` returns the `coeffs.len() - 1` coefficients of the quotient
+/// and the remainder that the quotient leaves unexplained: `P(r)` for forward deflation and the
+/// mismatch `a_0 - q_0` of the leading coefficient for backward deflation.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::{deflate_linear, deflate_linear_with};
+/// use bairstow::DeflationDirection;
+///
+/// // (x - 1)(x - 2) / (x - 2)
+/// let coeffs = [1.0, -3.0, 2.0];
+///
+/// let (quot, remainder) = deflate_linear_with(&coeffs, 2.0, DeflationDirection::Backward);
+///
+/// assert_eq!((quot, remainder), (vec![1.0, -1.0], 0.0));
+/// assert_eq!(
+///     deflate_linear_with(&coeffs, 2.0, DeflationDirection::Forward).0,
+///     deflate_linear(&coeffs, 2.0)
+/// );
+/// ```
+pub fn deflate_linear_with(
+    coeffs: &[f64],
+    r: f64,
+    direction: DeflationDirection,
+) -> (Vec<f64>, f64) {
+    let backward = match direction {
+        DeflationDirection::Forward => false,
+        DeflationDirection::Backward => r != 0.0,
+        DeflationDirection::Auto => r.abs() > 1.0,
+    };
+    let n = coeffs.len().saturating_sub(1);
+    if n == 0 {
+        return (vec![], coeffs.first().copied().unwrap_or(0.0));
+    }
+    if !backward {
+        return horner_divmod_linear(coeffs, r);
+    }
+    let mut quot = vec![0.0; n];
+    quot[n - 1] = -coeffs[n] / r;
+    for k in (1..n).rev() {
+        quot[k - 1] = (quot[k] - coeffs[k]) / r;
+    }
+    let remainder = coeffs[0] - quot[0];
+    (quot, remainder)
+}
+
+/// Deflation by a quadratic factor
+///
+/// The `deflate_quadratic` function divides `P` by `x^2 - r x - q`, with `vr = (r, q)` as in
+/// Bairstow's method, and drops the linear remainder. A complex conjugate pair `a ± bi` is the
+/// factor `r = 2a`, `q = -(a^2 + b^2)`, so the quotient keeps real coefficients.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+/// * `vr`: The quadratic factor `(r, q)` to divide out.
+///
+/// Returns:
+///
+/// The function `deflate_quadratic` returns the `coeffs.len() - 2` coefficients of the quotient.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::deflate_quadratic;
+/// use bairstow::vector2::Vector2;
+///
+/// // (x^2 + 1)(x - 3) / (x^2 + 1)
+/// let quot = deflate_quadratic(&[1.0, -3.0, 1.0, -3.0], &Vector2::new(0.0, -1.0));
+///
+/// assert_eq!(quot, vec![1.0, -3.0]);
+/// ```
+pub fn deflate_quadratic(coeffs: &[f64], vr: &Vector2<f64>) -> Vec<f64> {
+    let n = coeffs.len().saturating_sub(2);
+    let mut quot: Vec<f64> = Vec::with_capacity(n);
+    for (i, c) in coeffs.iter().take(n).enumerate() {
+        let mut b = *c;
+        if i >= 1 {
+            b += vr.x_ * quot[i - 1];
+        }
+        if i >= 2 {
+            b += vr.y_ * quot[i - 2];
+        }
+        quot.push(b);
+    }
+    quot
+}
+
+/// Polynomial from its roots
+///
+/// The `from_roots` function expands the monic polynomial `(x - z_1)(x - z_2)...(x - z_n)`. The
+/// product is formed in complex arithmetic and its real part is returned, so complex roots should
+/// come in conjugate pairs, which is how `find_roots` returns them for real coefficients.
+///
+/// Arguments:
+///
+/// * `roots`: The roots of the polynomial.
+///
+/// Returns:
+///
+/// The function `from_roots` returns the `roots.len() + 1` coefficients, ordered from highest
+/// degree to lowest degree, with a leading `1.0`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::from_roots;
+/// use num::Complex;
+///
+/// // (x - 3)(x^2 + 1)
+/// let roots = [Complex::new(0.0, 1.0), Complex::new(3.0, 0.0), Complex::new(0.0, -1.0)];
+///
+/// assert_eq!(from_roots(&roots), vec![1.0, -3.0, 1.0, -3.0]);
+/// ```
+pub fn from_roots(roots: &[Complex<f64>]) -> Vec<f64> {
+    let mut res = vec![Complex::<f64>::one()];
+    for z in roots {
+        // multiply by (x - z): every coefficient picks up -z times its predecessor
+        res.push(Complex::zero());
+        for i in (1..res.len()).rev() {
+            let prev = res[i - 1];
+            res[i] -= prev * z;
+        }
+    }
+    res.iter().map(|c| c.re).collect()
+}
+
+/// Monic normalization
+///
+/// The `to_monic` function divides the polynomial by its leading coefficient, so that the result
+/// starts with `1.0`, and returns that coefficient as the scale: `coeffs = scale * monic`. Leading
+/// zeros are dropped first, so the scale is the first non-zero coefficient.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `to_monic` returns the monic coefficients and the scale, `RootError::Empty` for an
+/// empty slice, or `RootError::DivisionByZero` for the zero polynomial, which has no leading
+/// coefficient.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::to_monic;
+///
+/// assert_eq!(to_monic(&[0.0, 2.0, -6.0, 4.0]), Ok((vec![1.0, -3.0, 2.0], 2.0)));
+/// assert!(to_monic(&[0.0, 0.0]).is_err());
+/// ```
+pub fn to_monic(coeffs: &[f64]) -> Result<(Vec<f64>, f64), RootError> {
+    let mut monic = coeffs.to_vec();
+    let scale = to_monic_in_place(&mut monic)?;
+    Ok((monic, scale))
+}
+
+/// The `to_monic_in_place` function is `to_monic` without the copy: it drops the leading zeros of
+/// `coeffs`, divides the rest by the leading coefficient and returns it. On error `coeffs` is left
+/// unchanged.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::to_monic_in_place;
+///
+/// let mut coeffs = vec![-2.0, 1.0];
+///
+/// assert_eq!(to_monic_in_place(&mut coeffs), Ok(-2.0));
+/// assert_eq!(coeffs, vec![1.0, -0.5]);
+/// ```
+pub fn to_monic_in_place(coeffs: &mut Vec<f64>) -> Result<f64, RootError> {
+    if coeffs.is_empty() {
+        return Err(RootError::Empty);
+    }
+    let Some(start) = coeffs.iter().position(|c| *c != 0.0) else {
+        return Err(RootError::DivisionByZero);
+    };
+    coeffs.drain(..start);
+    let scale = coeffs[0];
+    for c in coeffs.iter_mut() {
+        *c /= scale;
+    }
+    Ok(scale)
+}
+
+/// Taylor shift
+///
+/// The `shift_poly` function returns the coefficients of `P(x + s)` by repeated synthetic
+/// division, in `O(n^2)` operations.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+/// * `s`: The shift.
+///
+/// Returns:
+///
+/// The function `shift_poly` returns the shifted polynomial, of the same degree.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::shift_poly;
+///
+/// // (x + 1)^2
+/// assert_eq!(shift_poly(&[1.0, 0.0, 0.0], 1.0), vec![1.0, 2.0, 1.0]);
+/// ```
+pub fn shift_poly(coeffs: &[f64], s: f64) -> Vec<f64> {
+    let mut res = coeffs.to_vec();
+    let degree = res.len().saturating_sub(1);
+    for i in 0..degree {
+        for j in 1..=degree - i {
+            res[j] += s * res[j - 1];
+        }
+    }
+    res
+}
+
+/// Sign variations on an interval (Vincent-Collins-Akritas)
+///
+/// The `sign_variations_in` function maps the interval `(a, b)` onto `(0, inf)` with
+/// `x = (a + b y) / (1 + y)`, i.e. it forms `(1 + y)^n P((a + b y) / (1 + y))` by a shift to `a`, a
+/// scaling by `b - a`, a reversal and a shift by one, and counts the sign changes of the resulting
+/// coefficients. By Descartes' rule of signs this bounds the number of roots in `(a, b)` and has
+/// the same parity: `0` proves there is no root and `1` proves there is exactly one, which is what
+/// real-root isolation by bisection relies on.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+/// * `a`: The lower end of the interval.
+/// * `b`: The upper end of the interval, `b > a`.
+///
+/// Returns:
+///
+/// The function `sign_variations_in` returns the number of sign variations.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::sign_variations_in;
+///
+/// // (x - 1)(x - 2)
+/// assert_eq!(sign_variations_in(&[1.0, -3.0, 2.0], 0.0, 1.5), 1);
+/// assert_eq!(sign_variations_in(&[1.0, -3.0, 2.0], 2.5, 4.0), 0);
+/// ```
+pub fn sign_variations_in(coeffs: &[f64], a: f64, b: f64) -> usize {
+    let mut q = shift_poly(coeffs, a);
+    let mut scale = 1.0;
+    for c in q.iter_mut().rev() {
+        *c *= scale;
+        scale *= b - a;
+    }
+    q.reverse();
+    let q = shift_poly(&q, 1.0);
+    let signs: Vec<bool> = q.iter().filter(|c| **c != 0.0).map(|c| *c > 0.0).collect();
+    signs.windows(2).filter(|w| w[0] != w[1]).count()
+}
+
+/// Budan-Fourier root count
+///
+/// The `budan_fourier_count` function counts the sign variations `V(x)` of the derivative sequence
+/// `P(x), P'(x), P''(x), ...` at both ends of the interval and returns `V(a) - V(b)`. By the
+/// Budan-Fourier theorem this is an upper bound on the number of real roots in `(a, b]`, counted
+/// with multiplicity, and exceeds it by an even number; complex roots near the interval inflate
+/// the bound. Unlike a Sturm sequence it needs no polynomial divisions: the derivative values at
+/// `x`, up to the positive factors `k!`, are the coefficients of `shift_poly(coeffs, x)`. Zeros
+/// in the sequence are skipped.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+/// * `a`: The lower end of the interval.
+/// * `b`: The upper end of the interval, `b > a`.
+///
+/// Returns:
+///
+/// The function `budan_fourier_count` returns the bound `V(a) - V(b)`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::budan_fourier_count;
+///
+/// // x^2 + 1 has no real roots, but the bound can only prove it by parity
+/// assert_eq!(budan_fourier_count(&[1.0, 0.0, 1.0], -1.0, 1.0), 2);
+/// assert_eq!(budan_fourier_count(&[1.0, 0.0, 1.0], 0.0, 1.0), 0);
+/// ```
+pub fn budan_fourier_count(coeffs: &[f64], a: f64, b: f64) -> usize {
+    let variations = |x: f64| {
+        let signs: Vec<bool> = shift_poly(coeffs, x)
+            .iter()
+            .filter(|c| **c != 0.0)
+            .map(|c| *c > 0.0)
+            .collect();
+        signs.windows(2).filter(|w| w[0] != w[1]).count()
+    };
+    variations(a).saturating_sub(variations(b))
+}
+
+/// The `EndBehavior` enum describes the limits of `P(x)` as `x` goes to `-inf` and `+inf`, which
+/// follow from the leading term `a_0 x^degree` alone.
+///
+/// Variants:
+///
+/// * `RisesBoth`: `+inf` at both ends: even degree, positive leading coefficient.
+/// * `FallsBoth`: `-inf` at both ends: even degree, negative leading coefficient.
+/// * `Rises`: `-inf` at `-inf` and `+inf` at `+inf`: odd degree, positive leading coefficient.
+/// * `Falls`: `+inf` at `-inf` and `-inf` at `+inf`: odd degree, negative leading coefficient.
+/// * `Constant`: The polynomial is the given constant, which is its limit at both ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EndBehavior {
+    RisesBoth,
+    FallsBoth,
+    Rises,
+    Falls,
+    Constant(f64),
+}
+
+impl EndBehavior {
+    /// The `limits` function returns the limits `(lim P(x) at -inf, lim P(x) at +inf)`, infinite
+    /// unless the polynomial is constant.
+    pub fn limits(&self) -> (f64, f64) {
+        match *self {
+            EndBehavior::RisesBoth => (f64::INFINITY, f64::INFINITY),
+            EndBehavior::FallsBoth => (f64::NEG_INFINITY, f64::NEG_INFINITY),
+            EndBehavior::Rises => (f64::NEG_INFINITY, f64::INFINITY),
+            EndBehavior::Falls => (f64::INFINITY, f64::NEG_INFINITY),
+            EndBehavior::Constant(c) => (c, c),
+        }
+    }
+}
+
+/// End behavior of a polynomial
+///
+/// The `end_behavior` function classifies the limits of the polynomial at `-inf` and `+inf` from
+/// the sign of the leading coefficient and the parity of the degree. Leading zeros are skipped, so
+/// the true degree decides.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+///
+/// Returns:
+///
+/// The function `end_behavior` returns the `EndBehavior`, `Constant(0.0)` for the zero polynomial.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::{end_behavior, EndBehavior};
+///
+/// assert_eq!(end_behavior(&[-2.0, 0.0, 1.0]), EndBehavior::FallsBoth);
+/// assert_eq!(end_behavior(&[0.0, 1.0, -1.0]), EndBehavior::Rises);
+/// assert_eq!(end_behavior(&[0.0, 3.0]), EndBehavior::Constant(3.0));
+/// assert_eq!(EndBehavior::Falls.limits(), (f64::INFINITY, f64::NEG_INFINITY));
+/// ```
+pub fn end_behavior(coeffs: &[f64]) -> EndBehavior {
+    match trim_leading(coeffs, 0.0) {
+        [] => EndBehavior::Constant(0.0),
+        [c] => EndBehavior::Constant(*c),
+        [lead, rest @ ..] => match (rest.len() % 2 == 1, *lead > 0.0) {
+            (false, true) => EndBehavior::RisesBoth,
+            (false, false) => EndBehavior::FallsBoth,
+            (true, true) => EndBehavior::Rises,
+            (true, false) => EndBehavior::Falls,
+        },
+    }
+}
+
+/// The `RootMap` enum selects the map `f` applied to every root by `map_roots`.
+///
+/// Variants:
+///
+/// * `Negate`: `f(r) = -r`.
+/// * `Reciprocal`: `f(r) = 1 / r`.
+/// * `Scale`: `f(r) = s r`.
+/// * `Shift`: `f(r) = r + s`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RootMap {
+    Negate,
+    Reciprocal,
+    Scale(f64),
+    Shift(f64),
+}
+
+/// Polynomial with mapped roots
+///
+/// The `map_roots` function returns a polynomial whose roots are `f(r_i)` for the roots `r_i` of
+/// the given one, by a closed-form transform of the coefficients instead of solving and
+/// rebuilding:
+///
+/// * `Negate`: `(-1)^n P(-x)`, the coefficients with alternating signs.
+/// * `Reciprocal`: `x^n P(1 / x)`, the coefficients reversed. A root at zero has no reciprocal and
+///   shows up as a leading zero.
+/// * `Scale(s)`: `s^n P(x / s)`, coefficient `a_k` of `x^(n - k)` times `s^k`.
+/// * `Shift(s)`: `P(x - s)`, see `shift_poly`.
+///
+/// Except for `Reciprocal`, the leading coefficient is kept. The sign changes and the reversal are
+/// exact; scaling by a power of two is exact as well.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial, ordered from highest degree to lowest degree.
+/// * `map`: The map applied to the roots.
+///
+/// Returns:
+///
+/// The function `map_roots` returns the mapped polynomial, with as many coefficients as `coeffs`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::poly::{map_roots, RootMap};
+///
+/// // (x - 1)(x - 2) = x^2 - 3x + 2
+/// let coeffs = [1.0, -3.0, 2.0];
+///
+/// assert_eq!(map_roots(&coeffs, RootMap::Negate), vec![1.0, 3.0, 2.0]);
+/// assert_eq!(map_roots(&coeffs, RootMap::Reciprocal), vec![2.0, -3.0, 1.0]);
+/// assert_eq!(map_roots(&coeffs, RootMap::Scale(2.0)), vec![1.0, -6.0, 8.0]);
+/// assert_eq!(map_roots(&coeffs, RootMap::Shift(1.0)), vec![1.0, -5.0, 6.0]);
+/// ```
+pub fn map_roots(coeffs: &[f64], map: RootMap) -> Vec<f64> {
+    let scaled = |s: f64| {
+        let mut factor = 1.0;
+        coeffs
+            .iter()
+            .map(|c| {
+                let res = c * factor;
+                factor *= s;
+                res
+            })
+            .collect()
+    };
+    match map {
+        RootMap::Negate => scaled(-1.0),
+        RootMap::Reciprocal => coeffs.iter().rev().copied().collect(),
+        RootMap::Scale(s) => scaled(s),
+        RootMap::Shift(s) => shift_poly(coeffs, -s),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budan_fourier_count() {
+        // (x - 1)(x - 2)(x - 3)
+        let coeffs = [1.0, -6.0, 11.0, -6.0];
+        assert_eq!(budan_fourier_count(&coeffs, 0.0, 4.0), 3);
+        assert_eq!(budan_fourier_count(&coeffs, 0.0, 1.5), 1);
+        assert_eq!(budan_fourier_count(&coeffs, 1.5, 2.5), 1);
+        assert_eq!(budan_fourier_count(&coeffs, 3.5, 10.0), 0);
+        // the interval is half-open: the root at 3 counts in (2.5, 3] but not in (3, 4)
+        assert_eq!(budan_fourier_count(&coeffs, 2.5, 3.0), 1);
+        assert_eq!(budan_fourier_count(&coeffs, 3.0, 4.0), 0);
+    }
+
+    #[test]
+    fn test_to_monic() {
+        let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let (monic, scale) = to_monic(&coeffs).unwrap();
+        assert_eq!(scale, 10.0);
+        for (m, c) in monic.iter().zip(coeffs.iter()) {
+            assert_eq!(*m, c / 10.0);
+        }
+        assert_eq!(to_monic(&[]), Err(RootError::Empty));
+        assert_eq!(to_monic(&[0.0; 3]), Err(RootError::DivisionByZero));
+
+        let mut zero = vec![0.0; 3];
+        assert!(to_monic_in_place(&mut zero).is_err());
+        assert_eq!(zero, vec![0.0; 3]);
+    }
+
+    #[test]
+    fn test_end_behavior() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        assert_eq!(end_behavior(&coeffs), EndBehavior::RisesBoth);
+        assert_eq!(
+            end_behavior(&coeffs).limits(),
+            (f64::INFINITY, f64::INFINITY)
+        );
+        let negated: Vec<f64> = coeffs.iter().map(|c| -c).collect();
+        assert_eq!(end_behavior(&negated), EndBehavior::FallsBoth);
+        assert_eq!(end_behavior(&[-1.0, 0.0, 0.0, 8.0]), EndBehavior::Falls);
+        assert_eq!(end_behavior(&[]), EndBehavior::Constant(0.0));
+    }
+
+    #[test]
+    fn test_resultant_exact() {
+        let ints = |cs: &[i64]| cs.iter().map(|&c| BigInt::from(c)).collect::<Vec<_>>();
+
+        // by the roots 1 and -5/2 of 2x^2 + 3x - 5:
+        // Res(2x^2 + 3x - 5, x^2 - 4) = 2^2 b(1) b(-5/2) = 4 (1 - 4)(25/4 - 4) = -27
+        assert_eq!(
+            resultant_exact(&ints(&[2, 3, -5]), &ints(&[1, 0, -4])),
+            BigInt::from(-27)
+        );
+        // a zero pivot needs a row swap: Res(x^2 + 1, x^3) = 1
+        assert_eq!(
+            resultant_exact(&ints(&[1, 0, 1]), &ints(&[1, 0, 0, 0])),
+            BigInt::from(1)
+        );
+        // a common root gives exactly zero
+        assert!(resultant_exact(&ints(&[1, -3, 2]), &ints(&[1, 0, -1])).is_zero());
+
+        // Res(x - p, x^2 - q) = p^2 - q is far beyond the 53 bits of an f64
+        let p = 1_000_000_000_001_i64;
+        let q = BigInt::from(10).pow(24);
+        let exact = resultant_exact(&ints(&[1, -p]), &[BigInt::one(), BigInt::zero(), -&q]);
+        assert_eq!(exact, BigInt::from(2_000_000_000_001_i64));
+        assert_ne!(
+            resultant(&[1.0, -p as f64], &[1.0, 0.0, -1e24]).unwrap(),
+            2_000_000_000_001.0
+        );
+
+        // the conventions of `resultant`
+        assert!(resultant_exact(&ints(&[0, 0]), &ints(&[1, 2])).is_zero());
+        assert_eq!(
+            resultant_exact(&ints(&[3]), &ints(&[0, 1, 2, 3])),
+            BigInt::from(9)
+        );
+    }
+
+    #[test]
+    fn test_resultant_common_factor() {
+        // (x - 1.5)(x^2 + x + 1) and (x - 1.5)(x + 4) share the root 1.5
+        let a = [1.0, -0.5, -0.5, -1.5];
+        let b = [1.0, 2.5, -6.0];
+        assert!(resultant(&a, &b).unwrap().abs() < 1e-12);
+
+        // moving the root of b away makes the resultant nonzero:
+        // Res(a, (x - 1.6)(x + 4)) = a(1.6) a(-4)
+        let b = [1.0, 2.4, -6.4];
+        let a_at = |x: f64| ((a[0] * x + a[1]) * x + a[2]) * x + a[3];
+        let expected = a_at(1.6) * a_at(-4.0);
+        assert!((resultant(&a, &b).unwrap() - expected).abs() < 1e-10 * expected.abs());
+
+        // leading zeros do not change the degrees
+        assert_eq!(resultant(&[0.0, 1.0, -2.0], &[0.0, 0.0, 3.0]), Ok(3.0));
+        assert_eq!(resultant(&[1.0, -2.0], &[0.0, 0.0]), Ok(0.0));
+        assert_eq!(
+            resultant(&[1.0, f64::NAN], &[1.0, 0.0]),
+            Err(RootError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn test_parse_coeffs() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let parsed = parse_coeffs("10 34 75 94 150 94 75 34 10").unwrap();
+        assert_eq!(parsed, coeffs);
+        let z = Complex::new(0.3, -1.1);
+        assert_eq!(
+            horner_with_derivative_c(&parsed, &z),
+            horner_with_derivative_c(&coeffs, &z)
+        );
+        assert_eq!(
+            parse_coeffs(" 10,34,\t75 ,, 94\n150 94 75 34 10 ").unwrap(),
+            coeffs
+        );
+        assert_eq!(parse_coeffs("").unwrap(), Vec::<f64>::new());
+
+        let err = parse_coeffs("1, 2, 3e, 4").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                token: "3e".to_owned(),
+                index: 2
+            }
+        );
+        assert_eq!(err.to_string(), "coefficient 2 `3e` is not a number");
+    }
+
+    #[test]
+    fn test_companion_norm_bound() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let zs = crate::aberth::find_roots(&coeffs, &Options::default()).unwrap();
+        let max_root = zs.iter().map(|z| z.norm()).fold(0.0, f64::max);
+        let bound = companion_norm_bound(&coeffs);
+        let naive = 1.0
+            + coeffs[1..]
+                .iter()
+                .map(|c| (c / coeffs[0]).abs())
+                .sum::<f64>();
+        assert!(max_root <= bound);
+        assert!(bound < naive);
+
+        // the centroid of (x - 1)(x + 1) is a root, so the seeding circle falls back to the bound
+        let z0s = crate::aberth::initial_aberth(&[1.0, 0.0, -1.0]).unwrap();
+        assert!(z0s.iter().all(|z| (z.norm() - 1.0).abs() < 1e-15));
+    }
+
+    #[test]
+    fn test_discriminant() {
+        // (x - 1)^2 (x + 3)
+        assert!(discriminant(&[1.0, 1.0, -5.0, 3.0]).unwrap().abs() < 1e-10);
+
+        let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        assert!(discriminant(&coeffs).unwrap().abs() > 1.0);
+    }
+
+    #[test]
+    fn test_sign_variations_isolation() {
+        // (x - 1)(x - 2)(x^2 + 1)
+        let coeffs = [1.0, -3.0, 3.0, -3.0, 2.0];
+        let mut pending = vec![(-5.1, 5.3)];
+        let mut isolated = vec![];
+        while let Some((a, b)) = pending.pop() {
+            match sign_variations_in(&coeffs, a, b) {
+                0 => {}
+                1 => isolated.push((a, b)),
+                _ => {
+                    let mid = 0.5 * (a + b);
+                    pending.push((a, mid));
+                    pending.push((mid, b));
+                }
+            }
+        }
+        isolated.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap());
+
+        assert_eq!(isolated.len(), 2);
+        assert!(isolated[0].1 <= isolated[1].0);
+        assert!(isolated[0].0 < 1.0 && 1.0 < isolated[0].1);
+        assert!(isolated[1].0 < 2.0 && 2.0 < isolated[1].1);
+    }
+
+    #[test]
+    fn test_map_roots() {
+        let coeffs = [2.0, -3.0, -11.0, 5.0, 7.0, -4.0];
+        let alternating: Vec<f64> = coeffs
+            .iter()
+            .enumerate()
+            .map(|(k, c)| if k % 2 == 1 { -c } else { *c })
+            .collect();
+        assert_eq!(map_roots(&coeffs, RootMap::Negate), alternating);
+
+        // roots 1, -2, 4
+        let roots = [1.0, -2.0, 4.0];
+        let from = |f: fn(f64) -> f64| {
+            let zs: Vec<Complex<f64>> = roots.iter().map(|&r| Complex::new(f(r), 0.0)).collect();
+            from_roots(&zs)
+        };
+        let coeffs = from(|r| r);
+        assert_eq!(map_roots(&coeffs, RootMap::Negate), from(|r| -r));
+        assert_eq!(map_roots(&coeffs, RootMap::Scale(0.5)), from(|r| 0.5 * r));
+        assert_eq!(map_roots(&coeffs, RootMap::Shift(-1.5)), from(|r| r - 1.5));
+        // x^n P(1/x) has the leading coefficient P(0) = 8
+        let reciprocal: Vec<f64> = from(|r| 1.0 / r).iter().map(|c| 8.0 * c).collect();
+        for (a, b) in map_roots(&coeffs, RootMap::Reciprocal)
+            .iter()
+            .zip(reciprocal.iter())
+        {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_classify_poly() {
+        use crate::aberth::find_roots;
+
+        let options = Options::default();
+        let cases: [(&[f64], PolyClass, Result<usize, RootError>); 7] = [
+            (&[], PolyClass::Zero, Err(RootError::Empty)),
+            (&[0.0], PolyClass::Zero, Err(RootError::ZeroPolynomial)),
+            (&[0.0, 0.0], PolyClass::Zero, Err(RootError::ZeroPolynomial)),
+            (&[5.0], PolyClass::Constant, Ok(0)),
+            (&[0.0, 0.0, 5.0], PolyClass::Constant, Ok(0)),
+            (&[0.0, 1.0, -3.0, 2.0], PolyClass::Degree(2), Ok(2)),
+            (&[1.0, -3.0, 2.0], PolyClass::Degree(2), Ok(2)),
+        ];
+        for (coeffs, class, found) in cases {
+            assert_eq!(classify_poly(coeffs), class);
+            assert_eq!(find_roots(coeffs, &options).map(|zs| zs.len()), found);
+        }
+    }
+
+    #[test]
+    fn test_horner_divmod_linear() {
+        let coeffs = [2.0, -3.0, 0.5, 7.0, -1.25];
+        for a in [0.0, 1.0, -2.5, 3.75] {
+            let (quot, remainder) = horner_divmod_linear(&coeffs, a);
+            assert_eq!(quot.len(), coeffs.len() - 1);
+            assert_eq!(remainder, crate::aberth::horner_eval_f(&coeffs, a));
+            // Q(x) (x - a) + r
+            let mut product = quot.clone();
+            product.push(remainder);
+            for k in (1..product.len()).rev() {
+                product[k] -= a * quot[k - 1];
+            }
+            for (p, c) in product.iter().zip(coeffs.iter()) {
+                assert!((p - c).abs() < 1e-12 * (1.0 + c.abs()));
+            }
+        }
+        assert_eq!(horner_divmod_linear(&[], 2.0), (vec![], 0.0));
+        assert_eq!(horner_divmod_linear(&[3.0], 2.0), (vec![], 3.0));
+    }
+
+    #[test]
+    fn test_deflate_linear_with() {
+        // a large root next to small ones: forward deflation multiplies the rounding errors by
+        // the root at every step, backward deflation divides them by it
+        let big = 12345.678;
+        let small = [0.1, 0.7, 1.3, 2.9, -0.45].map(|r| Complex::new(r, 0.0));
+        let coeffs = from_roots(&[&[Complex::new(big, 0.0)], &small[..]].concat());
+        let quotient = from_roots(&small);
+        let error = |quot: &[f64]| {
+            quot.iter()
+                .zip(quotient.iter())
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0, f64::max)
+        };
+        let (forward, forward_rem) = deflate_linear_with(&coeffs, big, DeflationDirection::Forward);
+        let (backward, backward_rem) =
+            deflate_linear_with(&coeffs, big, DeflationDirection::Backward);
+        assert_eq!(forward, deflate_linear(&coeffs, big));
+        assert!(backward_rem.abs() < 1e-10 * forward_rem.abs());
+        assert!(error(&backward) < 1e-12);
+        assert!(error(&forward) > 1.0);
+        assert_eq!(
+            deflate_linear_with(&coeffs, big, DeflationDirection::Auto),
+            (backward, backward_rem)
+        );
+        assert_eq!(
+            deflate_linear_with(&coeffs, 0.1, DeflationDirection::Auto).0,
+            deflate_linear(&coeffs, 0.1)
+        );
+
+        // x (x - 1): backward by zero falls back to forward
+        assert_eq!(
+            deflate_linear_with(&[1.0, -1.0, 0.0], 0.0, DeflationDirection::Backward),
+            (vec![1.0, -1.0], 0.0)
+        );
+        assert_eq!(
+            deflate_linear_with(&[3.0], 1.0, DeflationDirection::Auto),
+            (vec![], 3.0)
+        );
+    }
+}
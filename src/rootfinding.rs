@@ -1,9 +1,11 @@
 use super::{Matrix2, Vector2};
+use crate::error::RootError;
 
 type Vec2 = Vector2<f64>;
 type Mat2 = Matrix2<f64>;
 
 const PI: f64 = std::f64::consts::PI;
+const MAX_HALVINGS: usize = 40;
 
 /// The below code defines a struct named Options with three fields: max_iters, tol, and tol_ind.
 /// 
@@ -17,11 +19,139 @@ const PI: f64 = std::f64::consts::PI;
 /// * `tol_ind`: The `tol_ind` property in the `Options` struct represents the tolerance for individual
 /// values. It is a floating-point number (`f64`) that determines the acceptable difference between the
 /// expected value and the actual value for each element in a calculation or comparison.
+/// * `line_search`: Whether `bairstow` damps its Newton step with a backtracking line search. Set it
+///   to `false` for the undamped classic step.
+/// * `freeze_converged`: Whether a root whose residual drops below `1e-15` stops being updated. When
+///   `false`, every root keeps iterating until the global `tol` is met.
+/// * `graeffe_steps`: How many Graeffe root-squaring steps `find_roots` applies to precondition a
+///   polynomial on which plain Aberth fails to converge. `0` disables the fallback.
+/// * `scheme`: Whether the sequential `aberth` updates the estimates in place or from a snapshot,
+///   see `IterationScheme`. `aberth_mt` always updates from a snapshot and ignores it.
+/// * `max_step`: The largest `|dz|` an Aberth update may take; a longer step keeps its direction
+///   and is shortened to this length. `None` leaves the steps unclamped.
+/// * `zero_threshold`: The relative size under which a quantity counts as numerically zero in
+///   `poly::squarefree` (the GCD remainders), `find_roots` (the trailing coefficients factored out
+///   as zero roots) and `roots::cluster_roots`. It is scale-aware: a coefficient is zero when it is
+///   at most `zero_threshold` times the largest coefficient magnitude of its polynomial, and two
+///   roots coincide when they are at most `zero_threshold * max(1, |z|)` apart. The default
+///   `f64::EPSILON` only absorbs rounding noise; raise it to merge nearby roots.
+/// * `adaptive_tol`: Whether the sequential Aberth sweeps (`aberth`, `aberth_detailed`, `aberth_into`
+///   and `aberth_until`) concentrate on the roots that lag behind. The effective tolerance starts
+///   loose at the largest residual of the first sweep and is multiplied by `1e-4` after every
+///   further sweep, clamped at `tol`. A root whose residual is already below the effective
+///   tolerance is evaluated but not moved. The stopping test still compares the largest residual
+///   of *all* roots with `tol`, so a skipped root never ends the iteration early.
+/// * `high_degree`: Whether the sequential Aberth sweeps evaluate the polynomial with
+///   `aberth::horner_eval_c_scaled`, which carries a separate binary exponent and cannot overflow.
+///   Use it above degree ~100, where `|z|^n` overflows `f64` at estimates still far from the roots.
+///   The residual compared with `tol` saturates at `f64::MAX`. It costs a few extra operations
+///   per Horner step. `aberth_mt` ignores it.
+/// * `auto_respread`: Whether the sequential Aberth sweeps repair merged estimates. Before every
+///   sweep, an estimate that has come within `zero_threshold * max(1, |z|)` of an earlier one,
+///   where the interaction term `1 / (z_i - z_j)` blows up, is moved back onto the starting circle
+///   of `aberth::initial_aberth` and iterated afresh. The estimates of a multiple root approach
+///   each other only to about `eps^(1/m)`, far above the default threshold; a threshold raised
+///   above that keeps them from converging. `aberth_mt` ignores it.
+/// * `residual_scaling`: How the Aberth sweeps of `aberth`, `aberth_detailed`, `aberth_into`,
+///   `aberth_until` and `aberth_mt` weigh the residual of a root before comparing it with `tol` and
+///   with the `1e-15` freeze threshold, see `ResidualScaling`.
+/// * `deflation`: The end from which `aberth::solve_by_deflation` divides out a real root, see
+///   `DeflationDirection` and `poly::deflate_linear_with`.
+/// * `timing`: Whether `aberth::aberth_detailed` and the solvers built on it measure the wall-clock
+///   time of the iteration into `AberthResult::elapsed`. When it is off, the clock is never read.
+/// * `interaction_neighbors`: The number `k` of nearest other estimates that enter the interaction
+///   sum `sum 1 / (z_i - z_j)` of the sequential Aberth sweeps (`aberth`, `aberth_detailed`,
+///   `aberth_into` and `aberth_until`); `None` sums over all of them. Far estimates contribute
+///   little, and the fixed points are still the roots, so the truncated sum converges to the same
+///   roots. An estimate only drops its far neighbors once its Newton step is small against the
+///   distance to its `k`-th neighbor; until then the full sum is used, since truncating early
+///   does not converge from the starting circle. The sum then costs `O(k)` instead of `O(n)`, but
+///   evaluating the polynomial stays `O(n)` per estimate, so the gain is limited to the late
+///   sweeps. The grid the neighbors are chosen from is rebuilt every sweep and allocates.
+///   `aberth_mt` ignores it and always sums over all estimates.
+/// * `time_limit`: The wall-clock budget of the Aberth sweeps. The elapsed time is checked after
+///   every sweep, and once it exceeds the limit the iteration stops with `StopReason::Timeout`.
+///   `None` never reads the clock.
+/// * `cancel`: A flag another thread may set to stop the Aberth sweeps. It is checked after every
+///   sweep, and once it is set the iteration stops with `StopReason::Cancelled`.
+///
+/// More fields may be added, so outside this crate `Options` is built from `Options::default()`
+/// with one setter per field.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::Options;
+///
+/// let options = Options::default().max_iters(100).tol(1e-10);
+///
+/// assert_eq!((options.max_iters, options.tol), (100, 1e-10));
+/// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct Options {
     pub max_iters: usize,
     pub tol: f64,
     pub tol_ind: f64,
+    pub line_search: bool,
+    pub freeze_converged: bool,
+    pub graeffe_steps: usize,
+    pub scheme: IterationScheme,
+    pub max_step: Option<f64>,
+    pub zero_threshold: f64,
+    pub adaptive_tol: bool,
+    pub high_degree: bool,
+    pub auto_respread: bool,
+    pub residual_scaling: ResidualScaling,
+    pub deflation: DeflationDirection,
+    pub timing: bool,
+    pub interaction_neighbors: Option<usize>,
+    pub time_limit: Option<std::time::Duration>,
+    pub cancel: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+}
+
+/// The `IterationScheme` enum selects how a sweep of Aberth's method uses the estimates.
+///
+/// Variants:
+///
+/// * `GaussSeidel`: Every update sees the estimates already updated in the same sweep.
+/// * `Jacobi`: Every update sees the estimates from the start of the sweep, like `aberth_mt`. The
+///   snapshot is one buffer per solve, which `aberth_into` lets the caller supply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IterationScheme {
+    GaussSeidel,
+    Jacobi,
+}
+
+/// The `ResidualScaling` enum selects how the Aberth sweeps weigh the residual `|P(z_i)|` of a root.
+///
+/// Variants:
+///
+/// * `Raw`: The l1 norm of `P(z_i)` as it is.
+/// * `ByRootMagnitude`: The l1 norm of `P(z_i)` divided by `max(1, |z_i|^degree)`. Rounding alone
+///   leaves a residual of about `eps * |z|^degree` at a large root, which a fixed `tol` may never
+///   accept; the scaled residual asks for comparable relative accuracy at every scale instead.
+///   `AberthResult::tol` still reports the unscaled residual.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResidualScaling {
+    Raw,
+    ByRootMagnitude,
+}
+
+/// The `DeflationDirection` enum selects the end of `P` from which a real root is divided out.
+///
+/// Variants:
+///
+/// * `Forward`: From the leading coefficient, as `poly::deflate_linear`. Every step multiplies by
+///   the root, so the rounding errors stay small for `|r| <= 1` and grow like `|r|^k` above.
+/// * `Backward`: From the constant coefficient. Every step divides by the root, which suits
+///   `|r| >= 1`.
+/// * `Auto`: `Forward` for `|r| <= 1` and `Backward` otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeflationDirection {
+    Forward,
+    Backward,
+    Auto,
 }
 
 /// The below code is implementing the `Default` trait for the `Options` struct in Rust. The `Default`
@@ -34,10 +164,164 @@ impl Default for Options {
             max_iters: 2000,
             tol: 1e-12,
             tol_ind: 1e-15,
+            line_search: true,
+            freeze_converged: true,
+            graeffe_steps: 0,
+            scheme: IterationScheme::GaussSeidel,
+            max_step: None,
+            zero_threshold: f64::EPSILON,
+            adaptive_tol: false,
+            high_degree: false,
+            auto_respread: false,
+            residual_scaling: ResidualScaling::Raw,
+            deflation: DeflationDirection::Forward,
+            timing: false,
+            interaction_neighbors: None,
+            time_limit: None,
+            cancel: None,
         }
     }
 }
 
+impl Options {
+    /// The `auto_tol` function builds default options whose `tol` is scaled to the polynomial:
+    /// `tol = degree * f64::EPSILON * ||coeffs||_1`, the size of the rounding error Horner's rule makes
+    /// when evaluating the polynomial near the unit circle.
+    ///
+    /// Arguments:
+    ///
+    /// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+    ///
+    /// Returns:
+    ///
+    /// The function `auto_tol` returns `Options::default()` with the scaled `tol`.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use bairstow::rootfinding::Options;
+    ///
+    /// let options = Options::auto_tol(&[1.0, -3.0, 2.0]);
+    ///
+    /// assert_eq!(options.tol, 2.0 * f64::EPSILON * 6.0);
+    /// ```
+    pub fn auto_tol(coeffs: &[f64]) -> Self {
+        let degree = coeffs.len().saturating_sub(1);
+        let norm: f64 = coeffs.iter().map(|c| c.abs()).sum();
+        Options {
+            tol: degree as f64 * f64::EPSILON * norm,
+            ..Options::default()
+        }
+    }
+    /// The `max_iters` function sets the iteration limit.
+    pub fn max_iters(mut self, max_iters: usize) -> Self {
+        self.max_iters = max_iters;
+        self
+    }
+
+    /// The `tol` function sets the convergence tolerance.
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// The `tol_ind` function sets the tolerance of the individual values.
+    pub fn tol_ind(mut self, tol_ind: f64) -> Self {
+        self.tol_ind = tol_ind;
+        self
+    }
+
+    /// The `line_search` function sets whether `bairstow` damps its Newton step.
+    pub fn line_search(mut self, line_search: bool) -> Self {
+        self.line_search = line_search;
+        self
+    }
+
+    /// The `freeze_converged` function sets whether converged roots stop being updated.
+    pub fn freeze_converged(mut self, freeze_converged: bool) -> Self {
+        self.freeze_converged = freeze_converged;
+        self
+    }
+
+    /// The `graeffe_steps` function sets the number of Graeffe steps of the `find_roots` fallback.
+    pub fn graeffe_steps(mut self, graeffe_steps: usize) -> Self {
+        self.graeffe_steps = graeffe_steps;
+        self
+    }
+
+    /// The `scheme` function sets how the sequential Aberth sweeps use the estimates.
+    pub fn scheme(mut self, scheme: IterationScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// The `max_step` function sets the longest Aberth step.
+    pub fn max_step(mut self, max_step: Option<f64>) -> Self {
+        self.max_step = max_step;
+        self
+    }
+
+    /// The `zero_threshold` function sets the relative size under which a quantity counts as zero.
+    pub fn zero_threshold(mut self, zero_threshold: f64) -> Self {
+        self.zero_threshold = zero_threshold;
+        self
+    }
+
+    /// The `adaptive_tol` function sets whether the Aberth sweeps use the adaptive tolerance.
+    pub fn adaptive_tol(mut self, adaptive_tol: bool) -> Self {
+        self.adaptive_tol = adaptive_tol;
+        self
+    }
+
+    /// The `high_degree` function sets whether the Aberth sweeps evaluate with a separate exponent.
+    pub fn high_degree(mut self, high_degree: bool) -> Self {
+        self.high_degree = high_degree;
+        self
+    }
+
+    /// The `auto_respread` function sets whether the Aberth sweeps repair merged estimates.
+    pub fn auto_respread(mut self, auto_respread: bool) -> Self {
+        self.auto_respread = auto_respread;
+        self
+    }
+
+    /// The `residual_scaling` function sets how the Aberth sweeps weigh the residual of a root.
+    pub fn residual_scaling(mut self, residual_scaling: ResidualScaling) -> Self {
+        self.residual_scaling = residual_scaling;
+        self
+    }
+
+    /// The `deflation` function sets the end from which a real root is deflated.
+    pub fn deflation(mut self, deflation: DeflationDirection) -> Self {
+        self.deflation = deflation;
+        self
+    }
+
+    /// The `timing` function sets whether the iteration time is measured.
+    pub fn timing(mut self, timing: bool) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// The `interaction_neighbors` function sets the number of neighbors in the interaction sum.
+    pub fn interaction_neighbors(mut self, interaction_neighbors: Option<usize>) -> Self {
+        self.interaction_neighbors = interaction_neighbors;
+        self
+    }
+
+    /// The `time_limit` function sets the wall-clock budget of the Aberth sweeps.
+    pub fn time_limit(mut self, time_limit: Option<std::time::Duration>) -> Self {
+        self.time_limit = time_limit;
+        self
+    }
+
+    /// The `cancel` function sets the flag that cancels the Aberth sweeps.
+    pub fn cancel(mut self, cancel: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>) -> Self {
+        self.cancel = cancel;
+        self
+    }
+}
+
 /// The function `make_adjoint` calculates the adjoint matrix between two vectors.
 /// 
 /// Arguments:
@@ -293,6 +577,9 @@ pub fn horner_eval(coeffs: &mut [f64], degree: usize, zval: f64) -> f64 {
 /// ```
 pub fn horner(coeffs: &mut [f64], degree: usize, vr: &Vec2) -> Vec2 {
     let Vec2 { x_: r, y_: q } = vr;
+    if degree == 0 {
+        return Vector2::<f64>::new(0.0, coeffs[0]); // a constant is its own remainder
+    }
     for idx in 0..(degree - 1) {
         coeffs[idx + 1] += coeffs[idx] * r;
         coeffs[idx + 2] += coeffs[idx] * q;
@@ -300,6 +587,86 @@ pub fn horner(coeffs: &mut [f64], degree: usize, vr: &Vec2) -> Vec2 {
     Vector2::<f64>::new(coeffs[degree - 1], coeffs[degree])
 }
 
+/// Bairstow remainder of a quadratic division
+///
+/// The `bairstow_remainder` function divides the polynomial by `x^2 + b*x + c` with the synthetic
+/// division of `horner` and returns the remainder `R*x + S`. The quadratic is an exact factor iff
+/// both are zero. In the `(r, q)` form of `bairstow`, the quadratic is `r = -b`, `q = -c`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `b`: The linear coefficient of the monic quadratic.
+/// * `c`: The constant coefficient of the monic quadratic.
+///
+/// Returns:
+///
+/// The function `bairstow_remainder` returns `(R, S)`; `(0.0, 0.0)` for empty coefficients.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::bairstow_remainder;
+///
+/// // x^3 + 2x^2 - x - 2 = (x + 2)(x^2 + 1) - 2x - 4 = (x - 1)(x^2 + 3x + 2)
+/// let coeffs = [1.0, 2.0, -1.0, -2.0];
+///
+/// assert_eq!(bairstow_remainder(&coeffs, 0.0, 1.0), (-2.0, -4.0));
+/// assert_eq!(bairstow_remainder(&coeffs, 3.0, 2.0), (0.0, 0.0));
+/// ```
+pub fn bairstow_remainder(coeffs: &[f64], b: f64, c: f64) -> (f64, f64) {
+    if coeffs.is_empty() {
+        return (0.0, 0.0);
+    }
+    let mut pb = coeffs.to_owned();
+    let vA = horner(&mut pb, coeffs.len() - 1, &Vector2::<f64>::new(-b, -c));
+    (vA.x_, vA.y_)
+}
+
+/// Bairstow remainder with its Jacobian
+///
+/// The `bairstow_step` function returns the remainder `(R, S)` of `bairstow_remainder` together with
+/// its Jacobian with respect to `(b, c)`, both from the two synthetic divisions of `bairstow`. The
+/// Newton step solves `J * (db, dc) = (R, S)` and updates `b -= db`, `c -= dc`.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `b`: The linear coefficient of the monic quadratic `x^2 + b*x + c`.
+/// * `c`: The constant coefficient of the monic quadratic.
+///
+/// Returns:
+///
+/// The function `bairstow_step` returns `((R, S), J)` with `J = [[dR/db, dR/dc], [dS/db, dS/dc]]`.
+/// Below degree 2 the remainder is the polynomial itself and `J` is zero.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::bairstow_step;
+///
+/// // x^3 + 2x^2 - x - 2 divided by x^2 + 1
+/// let ((rem, rem0), jac) = bairstow_step(&[1.0, 2.0, -1.0, -2.0], 0.0, 1.0);
+///
+/// assert_eq!((rem, rem0), (-2.0, -4.0));
+/// assert_eq!(jac, [[-2.0, -1.0], [1.0, -2.0]]);
+/// ```
+pub fn bairstow_step(coeffs: &[f64], b: f64, c: f64) -> ((f64, f64), [[f64; 2]; 2]) {
+    if coeffs.len() < 3 {
+        return (bairstow_remainder(coeffs, b, c), [[0.0; 2]; 2]);
+    }
+    let degree = coeffs.len() - 1;
+    let vr = Vector2::<f64>::new(-b, -c);
+    let mut pb = coeffs.to_owned();
+    let vA = horner(&mut pb, degree, &vr);
+    let (p, s) = {
+        let vA1 = horner(&mut pb, degree - 2, &vr);
+        (vA1.x_, vA1.y_)
+    };
+    // the negated Jacobian in (r, q) = (-b, -c), see `make_adjoint`
+    ((vA.x_, vA.y_), [[p * b - s, -p], [p * c, -s]])
+}
+
 /// The `initial_guess` function in Rust calculates the initial guesses for the roots of a polynomial
 /// using Bairstow's method.
 /// 
@@ -310,7 +677,8 @@ pub fn horner(coeffs: &mut [f64], degree: usize, vr: &Vec2) -> Vec2 {
 /// Returns:
 /// 
 /// The function `initial_guess` returns a vector of `Vector2` structs, which represent the initial
-/// guesses for the roots of a polynomial equation.
+/// guesses for the roots of a polynomial equation, or the error of `poly::degree` for invalid
+/// coefficients.
 ///
 /// # Examples:
 ///
@@ -319,10 +687,10 @@ pub fn horner(coeffs: &mut [f64], degree: usize, vr: &Vec2) -> Vec2 {
 /// use bairstow::vector2::Vector2;
 ///
 /// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
-/// let vr0s = initial_guess(&coeffs);
+/// let vr0s = initial_guess(&coeffs).unwrap();
 /// ```
-pub fn initial_guess(coeffs: &[f64]) -> Vec<Vec2> {
-    let mut degree = coeffs.len() - 1;
+pub fn initial_guess(coeffs: &[f64]) -> Result<Vec<Vec2>, RootError> {
+    let mut degree = crate::poly::degree(coeffs)?;
     let center = -coeffs[1] / (coeffs[0] * degree as f64);
     let mut pb = coeffs.to_owned();
     let centroid = horner_eval(&mut pb, degree, center); // ???
@@ -338,7 +706,72 @@ pub fn initial_guess(coeffs: &[f64]) -> Vec<Vec2> {
         let t0 = m + 2.0 * center * temp;
         vr0s.push(Vector2::<f64>::new(r0, -t0));
     }
-    vr0s
+    Ok(vr0s)
+}
+
+/// Bairstow's method (single quadratic factor)
+///
+/// The `bairstow` function refines one quadratic factor `x^2 - r*x - q` of a polynomial. Each
+/// iteration computes the Newton step on `(r, q)`; unless `options.line_search` is disabled, the step
+/// is halved until the sum of squared remainders of the quadratic division decreases, which keeps
+/// the iteration from overshooting. If `MAX_HALVINGS` halvings do not decrease it, the full step is
+/// taken, as a vanishing step would only stall the iteration.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `vr`: The initial guess `(r, q)` of the quadratic factor, refined in place.
+/// * `options`: The stopping criteria and the `line_search` toggle.
+///
+/// Returns:
+///
+/// The function `bairstow` returns the number of iterations and whether it converged.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::rootfinding::{bairstow, Options};
+/// use bairstow::vector2::Vector2;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let mut vr = Vector2::new(-1.0, -2.0);
+/// let (_niter, found) = bairstow(&coeffs, &mut vr, &Options::default());
+///
+/// assert!(found);
+/// ```
+pub fn bairstow(coeffs: &[f64], vr: &mut Vec2, options: &Options) -> (usize, bool) {
+    if coeffs.len() < 3 {
+        return (0, false); // no quadratic factor to refine
+    }
+    let degree = coeffs.len() - 1;
+    if degree == 2 {
+        *vr = Vector2::<f64>::new(-coeffs[1], -coeffs[2]) / coeffs[0];
+        return (0, true);
+    }
+    let residual = |vr: &Vec2| {
+        let mut pb = coeffs.to_owned();
+        horner(&mut pb, degree, vr).norm_sqr()
+    };
+    for niter in 0..options.max_iters {
+        let mut pb = coeffs.to_owned();
+        let vA = horner(&mut pb, degree, vr);
+        if vA.x_.abs().max(vA.y_.abs()) < options.tol {
+            return (niter, true);
+        }
+        let vA1 = horner(&mut pb, degree - 2, vr);
+        let dt = delta(&vA, vr, &vA1);
+        if !options.line_search {
+            *vr -= dt;
+            continue;
+        }
+        let fx = vA.norm_sqr();
+        let step = (0..MAX_HALVINGS)
+            .map(|k| 0.5_f64.powi(k as i32))
+            .find(|step| residual(&(*vr - dt * *step)) < fx)
+            .unwrap_or(1.0);
+        *vr -= dt * step;
+    }
+    (options.max_iters, false)
 }
 
 /// Parallel Bairstow's method (even degree only)
@@ -361,12 +794,15 @@ pub fn initial_guess(coeffs: &[f64]) -> Vec<Vec2> {
 /// use bairstow::rootfinding::{initial_guess, pbairstow_even, Options};
 ///
 /// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
-/// let mut vrs = initial_guess(&coeffs);
+/// let mut vrs = initial_guess(&coeffs).unwrap();
 /// let (niter, _found) = pbairstow_even(&coeffs, &mut vrs, &Options::default());
 ///
 /// assert_eq!(niter, 5);
 /// ```
 pub fn pbairstow_even(coeffs: &[f64], vrs: &mut Vec<Vec2>, options: &Options) -> (usize, bool) {
+    if coeffs.len() < 3 {
+        return (0, false); // no quadratic factor to refine
+    }
     let degree = coeffs.len() - 1; // degree, assume even
     let m_rs = vrs.len();
     let mut converged = vec![false; m_rs];
@@ -426,7 +862,7 @@ pub fn pbairstow_even(coeffs: &[f64], vrs: &mut Vec<Vec2>, options: &Options) ->
 /// use bairstow::rootfinding::{initial_guess, pbairstow_even_mt, Options};
 ///
 /// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
-/// let mut vrs = initial_guess(&coeffs);
+/// let mut vrs = initial_guess(&coeffs).unwrap();
 /// let (niter, _found) = pbairstow_even_mt(&coeffs, &mut vrs, &Options::default());
 ///
 /// assert_eq!(niter, 8);
@@ -434,6 +870,9 @@ pub fn pbairstow_even(coeffs: &[f64], vrs: &mut Vec<Vec2>, options: &Options) ->
 pub fn pbairstow_even_mt(coeffs: &[f64], vrs: &mut Vec<Vec2>, options: &Options) -> (usize, bool) {
     use rayon::prelude::*;
 
+    if coeffs.len() < 3 {
+        return (0, false); // no quadratic factor to refine
+    }
     let m_rs = vrs.len();
     let mut vrsc = vec![Vec2::default(); m_rs];
     let mut converged = vec![false; m_rs];
@@ -488,7 +927,8 @@ pub fn pbairstow_even_mt(coeffs: &[f64], vrs: &mut Vec<Vec2>, options: &Options)
 /// 
 /// Returns:
 /// 
-/// The function `initial_autocorr` returns a vector of `Vec2` structs.
+/// The function `initial_autocorr` returns a vector of `Vec2` structs, or the error of
+/// `poly::degree` for invalid coefficients.
 ///
 /// # Examples:
 ///
@@ -497,10 +937,10 @@ pub fn pbairstow_even_mt(coeffs: &[f64], vrs: &mut Vec<Vec2>, options: &Options)
 /// use bairstow::vector2::Vector2;
 ///
 /// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
-/// let vr0s = initial_autocorr(&coeffs);
+/// let vr0s = initial_autocorr(&coeffs).unwrap();
 /// ```
-pub fn initial_autocorr(coeffs: &[f64]) -> Vec<Vec2> {
-    let mut degree = coeffs.len() - 1;
+pub fn initial_autocorr(coeffs: &[f64]) -> Result<Vec<Vec2>, RootError> {
+    let mut degree = crate::poly::degree(coeffs)?;
     let re = coeffs[degree].abs().powf(1.0 / (degree as f64));
     degree /= 2;
     let k = PI / (degree as f64);
@@ -509,7 +949,7 @@ pub fn initial_autocorr(coeffs: &[f64]) -> Vec<Vec2> {
     for i in (1..degree).step_by(2) {
         vr0s.push(Vector2::<f64>::new(2.0 * re * (k * i as f64).cos(), -m));
     }
-    vr0s
+    Ok(vr0s)
 }
 
 /// The `pbairstow_autocorr` function implements the simultaneous Bairstow's method for finding roots of
@@ -531,12 +971,15 @@ pub fn initial_autocorr(coeffs: &[f64]) -> Vec<Vec2> {
 /// use bairstow::rootfinding::{initial_autocorr, pbairstow_autocorr, Options};
 ///
 /// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
-/// let mut vrs = initial_autocorr(&coeffs);
+/// let mut vrs = initial_autocorr(&coeffs).unwrap();
 /// let (niter, _found) = pbairstow_autocorr(&coeffs, &mut vrs, &Options::default());
 ///
 /// assert_eq!(niter, 1);
 /// ```
 pub fn pbairstow_autocorr(coeffs: &[f64], vrs: &mut Vec<Vec2>, options: &Options) -> (usize, bool) {
+    if coeffs.len() < 3 {
+        return (0, false); // no quadratic factor to refine
+    }
     let m_rs = vrs.len();
     let mut converged = vec![false; m_rs];
 
@@ -602,7 +1045,7 @@ pub fn pbairstow_autocorr(coeffs: &[f64], vrs: &mut Vec<Vec2>, options: &Options
 /// use bairstow::rootfinding::{initial_autocorr, pbairstow_autocorr_mt, Options};
 ///
 /// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
-/// let mut vrs = initial_autocorr(&coeffs);
+/// let mut vrs = initial_autocorr(&coeffs).unwrap();
 /// let (niter, _found) = pbairstow_autocorr_mt(&coeffs, &mut vrs, &Options::default());
 ///
 /// assert_eq!(niter, 2);
@@ -614,6 +1057,9 @@ pub fn pbairstow_autocorr_mt(
 ) -> (usize, bool) {
     use rayon::prelude::*;
 
+    if coeffs.len() < 3 {
+        return (0, false); // no quadratic factor to refine
+    }
     let m_rs = vrs.len();
     let mut vrsc = vec![Vec2::default(); m_rs];
     let mut converged = vec![false; m_rs];
@@ -719,3 +1165,62 @@ pub fn extract_autocorr(vr: Vec2) -> Vec2 {
     // else no need to change
     vr
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bairstow_line_search() {
+        let coeffs = vec![1.0, -9.0, 6.0, 7.0, -2.0, -6.0];
+        let options = Options {
+            line_search: false,
+            ..Options::default()
+        };
+        let mut vr = Vector2::<f64>::new(0.5, -4.5);
+        let (_, found) = bairstow(&coeffs, &mut vr, &options);
+        assert!(!found);
+
+        let mut vr = Vector2::<f64>::new(0.5, -4.5);
+        let (_, found) = bairstow(&coeffs, &mut vr, &Options::default());
+        assert!(found);
+        let mut pb = coeffs.clone();
+        let vA = horner(&mut pb, 5, &vr);
+        assert!(vA.norm_sqr() < 1e-20);
+    }
+
+    #[test]
+    fn test_bairstow_remainder() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let mut vr = Vector2::<f64>::new(-1.0, -2.0);
+        assert!(bairstow(&coeffs, &mut vr, &Options::default()).1);
+        let (rem, rem0) = bairstow_remainder(&coeffs, -vr.x_, -vr.y_);
+        assert!(rem.abs() < 1e-10 && rem0.abs() < 1e-10);
+
+        let (rem, rem0) = bairstow_remainder(&coeffs, -vr.x_ + 0.1, -vr.y_);
+        assert!(rem.abs().max(rem0.abs()) > 1e-3);
+    }
+
+    #[test]
+    fn test_bairstow_step() {
+        let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+        let (b, c) = (0.7, 1.3);
+        let (rem, jac) = bairstow_step(&coeffs, b, c);
+        assert_eq!(rem, bairstow_remainder(&coeffs, b, c));
+
+        let h = 1e-6;
+        let (rb1, sb1) = bairstow_remainder(&coeffs, b + h, c);
+        let (rb0, sb0) = bairstow_remainder(&coeffs, b - h, c);
+        let (rc1, sc1) = bairstow_remainder(&coeffs, b, c + h);
+        let (rc0, sc0) = bairstow_remainder(&coeffs, b, c - h);
+        let fd = [
+            [(rb1 - rb0) / (2.0 * h), (rc1 - rc0) / (2.0 * h)],
+            [(sb1 - sb0) / (2.0 * h), (sc1 - sc0) / (2.0 * h)],
+        ];
+        for (row, fd_row) in jac.iter().zip(fd.iter()) {
+            for (d, fd_d) in row.iter().zip(fd_row.iter()) {
+                assert!((d - fd_d).abs() < 1e-5 * fd_d.abs().max(1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,1000 @@
+use crate::aberth::{aberth_into, find_roots, initial_aberth_slice, StopReason};
+use crate::error::{MultiplicityError, ParseError, RootError};
+use crate::rootfinding::Options;
+use num::Complex;
+use std::hash::{Hash, Hasher};
+
+/// The `roots_to_pairs` function converts complex roots into `(re, im)` pairs.
+///
+/// Arguments:
+///
+/// * `roots`: The roots to convert.
+///
+/// Returns:
+///
+/// The function `roots_to_pairs` returns one `(re, im)` tuple per root.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::find_roots;
+/// use bairstow::roots::{pairs_to_roots, roots_to_pairs};
+/// use bairstow::rootfinding::Options;
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let zs = find_roots(&coeffs, &Options::default()).unwrap();
+/// let pairs = roots_to_pairs(&zs);
+///
+/// assert_eq!(pairs[0], (zs[0].re, zs[0].im));
+/// assert_eq!(pairs_to_roots(&pairs), zs);
+/// ```
+pub fn roots_to_pairs(roots: &[Complex<f64>]) -> Vec<(f64, f64)> {
+    roots.iter().map(|z| (z.re, z.im)).collect()
+}
+
+/// The `pairs_to_roots` function converts `(re, im)` pairs back into complex roots.
+///
+/// Arguments:
+///
+/// * `pairs`: The `(re, im)` tuples to convert.
+///
+/// Returns:
+///
+/// The function `pairs_to_roots` returns one `Complex<f64>` per pair.
+pub fn pairs_to_roots(pairs: &[(f64, f64)]) -> Vec<Complex<f64>> {
+    pairs.iter().map(|&(re, im)| Complex::new(re, im)).collect()
+}
+
+/// The `roots_to_interleaved` function flattens complex roots into `[re0, im0, re1, im1, ...]`.
+///
+/// Arguments:
+///
+/// * `roots`: The roots to convert.
+///
+/// Returns:
+///
+/// The function `roots_to_interleaved` returns a vector twice as long as `roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::roots::{interleaved_to_roots, roots_to_interleaved};
+/// use num::Complex;
+///
+/// let zs = vec![Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)];
+/// let flat = roots_to_interleaved(&zs);
+///
+/// assert_eq!(flat, vec![1.0, 2.0, 3.0, -4.0]);
+/// assert_eq!(interleaved_to_roots(&flat), zs);
+/// ```
+pub fn roots_to_interleaved(roots: &[Complex<f64>]) -> Vec<f64> {
+    roots.iter().flat_map(|z| [z.re, z.im]).collect()
+}
+
+/// The `interleaved_to_roots` function converts `[re0, im0, re1, im1, ...]` back into complex
+/// roots. A trailing unpaired value is ignored.
+///
+/// Arguments:
+///
+/// * `flat`: The interleaved real and imaginary parts.
+///
+/// Returns:
+///
+/// The function `interleaved_to_roots` returns one `Complex<f64>` per `(re, im)` pair.
+pub fn interleaved_to_roots(flat: &[f64]) -> Vec<Complex<f64>> {
+    flat.chunks_exact(2)
+        .map(|pair| Complex::new(pair[0], pair[1]))
+        .collect()
+}
+
+/// The `format_root` function writes a root in `a + bi` form, e.g. `2.5 - 1.3i`. A zero
+/// imaginary part leaves only the real part, and a zero real part only the imaginary part. `0` is
+/// written for zero. The parts use the shortest representation that reads back to the same `f64`,
+/// so `parse_root` restores the root exactly. Only the sign of a zero part is lost.
+///
+/// Arguments:
+///
+/// * `z`: The root to format.
+///
+/// Returns:
+///
+/// The function `format_root` returns the text of the root.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::roots::format_root;
+/// use num::Complex;
+///
+/// assert_eq!(format_root(&Complex::new(2.5, -1.3)), "2.5 - 1.3i");
+/// assert_eq!(format_root(&Complex::new(-2.5, 0.0)), "-2.5");
+/// assert_eq!(format_root(&Complex::new(0.0, 1.0)), "1i");
+/// assert_eq!(format_root(&Complex::new(0.0, 0.0)), "0");
+/// ```
+pub fn format_root(z: &Complex<f64>) -> String {
+    match (z.re == 0.0, z.im == 0.0) {
+        (_, true) => format!("{}", z.re + 0.0),
+        (true, false) => format!("{}i", z.im),
+        (false, false) if z.im.is_sign_negative() => format!("{} - {}i", z.re, -z.im),
+        (false, false) => format!("{} + {}i", z.re, z.im),
+    }
+}
+
+/// The `parse_root` function reads a root in the `a + bi` form of `format_root`. Whitespace is
+/// ignored, either part may be missing, and a bare `i` stands for `1i`.
+///
+/// Arguments:
+///
+/// * `s`: The text of the root.
+///
+/// Returns:
+///
+/// The function `parse_root` returns the root, or a `ParseError` naming the part that is not a
+/// number, with index `0` for the real and `1` for the imaginary part.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::roots::parse_root;
+/// use num::Complex;
+///
+/// assert_eq!(parse_root("2.5 - 1.3i"), Ok(Complex::new(2.5, -1.3)));
+/// assert_eq!(parse_root("1e-3+2E+2i"), Ok(Complex::new(1e-3, 200.0)));
+/// assert_eq!(parse_root("-i"), Ok(Complex::new(0.0, -1.0)));
+/// assert_eq!(parse_root("2 + xi").unwrap_err().token, "+x");
+/// ```
+pub fn parse_root(s: &str) -> Result<Complex<f64>, ParseError> {
+    let text: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+    let part = |token: &str, index: usize| match token {
+        "" | "+" if index == 1 => Ok(1.0),
+        "-" if index == 1 => Ok(-1.0),
+        _ => token.parse::<f64>().map_err(|_| ParseError {
+            token: token.to_owned(),
+            index,
+        }),
+    };
+    let Some(body) = text.strip_suffix('i') else {
+        return Ok(Complex::new(part(&text, 0)?, 0.0));
+    };
+    // the sign between the parts, skipping the first character and exponent signs
+    let bytes = body.as_bytes();
+    let split = (1..bytes.len())
+        .rev()
+        .find(|&k| matches!(bytes[k], b'+' | b'-') && !matches!(bytes[k - 1], b'e' | b'E'));
+    match split {
+        Some(k) => Ok(Complex::new(part(&body[..k], 0)?, part(&body[k..], 1)?)),
+        None => Ok(Complex::new(0.0, part(body, 1)?)),
+    }
+}
+
+/// The `root_separation` function measures how far apart the roots are. Closely spaced roots slow
+/// down Aberth's method, so this is a cheap diagnostic to log alongside the results.
+///
+/// Arguments:
+///
+/// * `roots`: The roots to measure.
+///
+/// Returns:
+///
+/// The function `root_separation` returns the minimum and the mean distance over all pairs of
+/// roots, or `(f64::INFINITY, f64::INFINITY)` when there are fewer than two roots.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::find_roots;
+/// use bairstow::roots::root_separation;
+/// use bairstow::rootfinding::Options;
+///
+/// // the roots of x^6 - 1 are evenly spaced on the unit circle, one apart
+/// let zs = find_roots(&[1.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0], &Options::default()).unwrap();
+/// let (min, mean) = root_separation(&zs);
+///
+/// assert!((min - 1.0).abs() < 1e-12);
+/// // per root: two neighbours at 1, two at sqrt(3), one opposite at 2
+/// assert!((mean - (2.0 + 2.0 * 3.0_f64.sqrt() + 2.0) / 5.0).abs() < 1e-12);
+/// ```
+pub fn root_separation(roots: &[Complex<f64>]) -> (f64, f64) {
+    let mut min = f64::INFINITY;
+    let mut sum = 0.0;
+    let mut count = 0;
+    for (i, zi) in roots.iter().enumerate() {
+        for zj in roots[i + 1..].iter() {
+            let d = (zi - zj).norm();
+            min = min.min(d);
+            sum += d;
+            count += 1;
+        }
+    }
+    if count == 0 {
+        return (f64::INFINITY, f64::INFINITY);
+    }
+    (min, sum / count as f64)
+}
+
+/// The `cluster_roots` function groups root estimates that numerically coincide. A root joins the
+/// first cluster whose center is at most `options.zero_threshold * max(1, |center|)` away, and the
+/// center is the mean of its members. A root of multiplicity `m` is only found to `eps^(1/m)`
+/// accuracy, so merging its estimates needs a threshold well above the default.
+///
+/// Arguments:
+///
+/// * `roots`: The root estimates to group.
+/// * `options`: Provides `zero_threshold`.
+///
+/// Returns:
+///
+/// The function `cluster_roots` returns one `(center, count)` pair per cluster, in the order the
+/// clusters were first seen.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::roots::cluster_roots;
+/// use bairstow::rootfinding::Options;
+/// use num::Complex;
+///
+/// let zs = [Complex::new(1.0, 0.0), Complex::new(2.0, 0.0), Complex::new(1.0 + 1e-9, 0.0)];
+/// let options = Options::default().zero_threshold(1e-6);
+/// let clusters = cluster_roots(&zs, &options);
+///
+/// assert_eq!(clusters.len(), 2);
+/// assert_eq!(clusters[0].1, 2);
+/// assert_eq!(clusters[1], (Complex::new(2.0, 0.0), 1));
+/// ```
+pub fn cluster_roots(roots: &[Complex<f64>], options: &Options) -> Vec<(Complex<f64>, usize)> {
+    let mut clusters: Vec<(Complex<f64>, usize)> = Vec::new();
+    for z in roots {
+        let found = clusters
+            .iter_mut()
+            .find(|(c, _)| (z - *c).norm() <= options.zero_threshold * c.norm().max(1.0));
+        match found {
+            Some((c, n)) => {
+                *c = (*c * *n as f64 + z) / (*n + 1) as f64;
+                *n += 1;
+            }
+            None => clusters.push((*z, 1)),
+        }
+    }
+    clusters
+}
+
+/// The `taylor_c` function returns the Taylor coefficients `P^(k)(c) / k!` of the polynomial at
+/// `c`, lowest order first, by repeated synthetic division.
+fn taylor_c(coeffs: &[f64], c: Complex<f64>) -> Vec<Complex<f64>> {
+    let mut res: Vec<Complex<f64>> = coeffs.iter().map(|a| Complex::new(*a, 0.0)).collect();
+    let degree = res.len().saturating_sub(1);
+    for i in 0..degree {
+        for j in 1..=degree - i {
+            let prev = res[j - 1];
+            res[j] += c * prev;
+        }
+    }
+    res.reverse();
+    res
+}
+
+/// Root multiplicities with a degree check
+///
+/// The `multiplicities` function solves the polynomial with `find_roots`, groups the roots with
+/// `cluster_roots` at `zero_threshold = tol`, and reads the multiplicity of every cluster off the
+/// polynomial itself: a root of multiplicity `m` at `c` makes the Taylor coefficients
+/// `t_k = P^(k)(c) / k!` vanish for `k < m`, so the multiplicity is the number of leading `t_k`
+/// with `|t_k| <= tol * max(1, |c|) * |t_(k+1)|`, i.e. whose Newton step is shorter than the
+/// cluster radius. The multiplicities must add up to the degree. A `tol` below the accuracy of a
+/// multiple root, which is about `eps^(1/m)`, splits its cluster into estimates that are not
+/// multiple roots, and the sum falls short.
+///
+/// Arguments:
+///
+/// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+/// * `tol`: The relative cluster radius.
+///
+/// Returns:
+///
+/// The function `multiplicities` returns one `(center, multiplicity)` pair per cluster in order of
+/// increasing real part, `MultiplicityError::DegreeMismatch` when the multiplicities do not add up
+/// to the degree, or the error of `find_roots`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::roots::multiplicities;
+/// use bairstow::rootfinding::Options;
+///
+/// // (x - 1)^2 (x - 2)
+/// let found = multiplicities(&[1.0, -4.0, 5.0, -2.0], &Options::default(), 1e-4).unwrap();
+///
+/// assert_eq!(found.len(), 2);
+/// assert_eq!((found[0].1, found[1].1), (2, 1));
+/// assert!((found[0].0.re - 1.0).abs() < 1e-6);
+/// ```
+pub fn multiplicities(
+    coeffs: &[f64],
+    options: &Options,
+    tol: f64,
+) -> Result<Vec<(Complex<f64>, usize)>, MultiplicityError> {
+    let degree = crate::poly::degree(coeffs)?;
+    let zs = find_roots(coeffs, options)?;
+    let cluster_options = Options {
+        zero_threshold: tol,
+        cancel: options.cancel.clone(),
+        ..*options
+    };
+    let mut found: Vec<(Complex<f64>, usize)> = cluster_roots(&zs, &cluster_options)
+        .into_iter()
+        .map(|(c, _)| {
+            let radius = tol * c.norm().max(1.0);
+            let t = taylor_c(coeffs, c);
+            let m = t
+                .windows(2)
+                .take_while(|w| w[0].norm() <= radius * w[1].norm())
+                .count();
+            (c, m)
+        })
+        .collect();
+    let total: usize = found.iter().map(|(_, m)| m).sum();
+    if total != degree {
+        return Err(MultiplicityError::DegreeMismatch { degree, total });
+    }
+    found.sort_by(|a, b| {
+        (a.0.re, a.0.im)
+            .partial_cmp(&(b.0.re, b.0.im))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    Ok(found)
+}
+
+/// The `PoleZero` struct holds the zeros and poles of a rational function `B(z) / A(z)`.
+///
+/// Properties:
+///
+/// * `zeros`: The roots of the numerator `B`.
+/// * `poles`: The roots of the denominator `A`.
+/// * `cancellations`: The near-coincident pairs as `(zero index, pole index)`; every zero and every
+///   pole appears in at most one pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoleZero {
+    pub zeros: Vec<Complex<f64>>,
+    pub poles: Vec<Complex<f64>>,
+    pub cancellations: Vec<(usize, usize)>,
+}
+
+/// Zeros and poles of a rational function
+///
+/// The `pole_zero` function finds the zeros and the poles of `B(z) / A(z)` with `find_roots` and
+/// pairs every zero with the nearest unpaired pole within `zero_threshold * max(1, |zero|)`, the
+/// radius of `cluster_roots`. A paired zero and pole cancel, up to the accuracy of the roots, so
+/// the function is equal to the reduced one with both removed. A shared multiple root gives one
+/// pair per common multiplicity. Simple roots are accurate to about `1e-12` relative, so the
+/// default `zero_threshold` may be too tight to pair them; raise it for cancellation detection.
+///
+/// Arguments:
+///
+/// * `num`: The numerator coefficients `B`, ordered from highest degree to lowest degree.
+/// * `den`: The denominator coefficients `A`, ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`, and `zero_threshold`.
+///
+/// Returns:
+///
+/// The function `pole_zero` returns the zeros, the poles and the cancelable pairs, or the error of
+/// `find_roots` for the numerator or the denominator. A non-zero constant has no roots and
+/// contributes none.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::roots::pole_zero;
+/// use bairstow::rootfinding::Options;
+///
+/// // (z - 0.5)(z + 0.25) / ((z - 0.5)(z - 0.9))
+/// let options = Options::default().zero_threshold(1e-8);
+/// let pz = pole_zero(&[1.0, -0.25, -0.125], &[1.0, -1.4, 0.45], &options).unwrap();
+///
+/// assert_eq!((pz.zeros.len(), pz.poles.len()), (2, 2));
+/// assert_eq!(pz.cancellations.len(), 1);
+/// let (i, j) = pz.cancellations[0];
+/// assert!((pz.zeros[i].re - 0.5).abs() < 1e-10 && (pz.poles[j].re - 0.5).abs() < 1e-10);
+/// ```
+pub fn pole_zero(num: &[f64], den: &[f64], options: &Options) -> Result<PoleZero, RootError> {
+    let zeros = find_roots(num, options)?;
+    let poles = find_roots(den, options)?;
+    let mut paired = vec![false; poles.len()];
+    let mut cancellations = Vec::new();
+    for (i, z) in zeros.iter().enumerate() {
+        let radius = options.zero_threshold * z.norm().max(1.0);
+        let nearest = poles
+            .iter()
+            .enumerate()
+            .filter(|(j, p)| !paired[*j] && (z - *p).norm() <= radius)
+            .min_by(|a, b| (z - a.1).norm().total_cmp(&(z - b.1).norm()));
+        if let Some((j, _)) = nearest {
+            paired[j] = true;
+            cancellations.push((i, j));
+        }
+    }
+    Ok(PoleZero {
+        zeros,
+        poles,
+        cancellations,
+    })
+}
+
+/// The `Roots` struct holds up to `N` roots inline, without a heap allocation, for targets without
+/// an allocator.
+///
+/// The roots live in a `[Complex<f64>; N]` and only the first `len()` entries are roots.
+/// `Roots::solve` seeds and refines them in place with `initial_aberth_slice` and `aberth_into`.
+/// Its scratch buffers are stack arrays of length `N`, so a degree bounded by `N` at compile
+/// time never allocates.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::roots::Roots;
+/// use bairstow::rootfinding::Options;
+/// use bairstow::aberth::StopReason;
+///
+/// // (x - 1)(x - 2)(x - 4)(x - 5)
+/// let coeffs = [1.0, -12.0, 49.0, -78.0, 40.0];
+/// let (roots, reason) = Roots::<4>::solve(&coeffs, &Options::default()).unwrap();
+///
+/// assert_eq!(reason, StopReason::Converged);
+/// assert_eq!(roots.len(), 4);
+/// assert!(roots.as_slice().iter().any(|z| (z.re - 5.0).abs() < 1e-10));
+/// // the degree must fit into the capacity
+/// assert!(Roots::<3>::solve(&coeffs, &Options::default()).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Roots<const N: usize> {
+    roots: [Complex<f64>; N],
+    len: usize,
+}
+
+impl<const N: usize> Roots<N> {
+    /// The `solve` function finds the roots of `coeffs` with Aberth's method into a `Roots<N>`.
+    /// It returns the roots with the `StopReason` of `aberth_into`, `RootError::BufferLength`
+    /// when the degree exceeds `N`, or the error of `poly::degree`.
+    pub fn solve(coeffs: &[f64], options: &Options) -> Result<(Self, StopReason), RootError> {
+        let degree = crate::poly::degree(coeffs)?;
+        if degree > N {
+            return Err(RootError::BufferLength {
+                name: "roots",
+                expected: degree,
+                found: N,
+            });
+        }
+        let mut roots = [Complex::new(0.0, 0.0); N];
+        let mut pb = [0.0; N];
+        let mut converged = [false; N];
+        let mut snapshot = [Complex::new(0.0, 0.0); N];
+        let zs = &mut roots[..degree];
+        initial_aberth_slice(coeffs, zs)?;
+        let (_, reason) = aberth_into(
+            coeffs,
+            zs,
+            &mut pb[..degree],
+            &mut converged[..degree],
+            &mut snapshot[..degree],
+            options,
+        )?;
+        Ok((Roots { roots, len: degree }, reason))
+    }
+
+    /// The `as_slice` function returns the roots.
+    pub fn as_slice(&self) -> &[Complex<f64>] {
+        &self.roots[..self.len]
+    }
+
+    /// The `len` function returns the number of roots.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The `is_empty` function returns whether there are no roots.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// The `RootKey` struct wraps a root so that roots which are the same within a tolerance compare
+/// equal and hash alike, e.g. to collect the roots of several solves into a `HashSet`.
+///
+/// Tolerant equality `|z - w| <= tol` cannot back `Hash`: it is not transitive (`a ~ b` and `b ~ c`
+/// do not imply `a ~ c`), while `Eq` and `Hash` require an equivalence relation whose equal values
+/// hash alike. `RootKey` therefore snaps the real and imaginary parts to a grid of spacing `tol`
+/// and compares the grid cells. That is a true equivalence relation, and roots sharing a cell are
+/// less than `tol` apart in each part. The price is that two roots closer than `tol` still fall
+/// into neighbouring cells when they straddle a cell boundary; choosing `tol` well above the
+/// accuracy of the roots makes that rare. Keys put in one collection should share `tol`. The cells
+/// are compared by their bit patterns, so a key with a NaN part still equals itself.
+///
+/// Properties:
+///
+/// * `root`: The wrapped root.
+/// * `tol`: The grid spacing.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::roots::RootKey;
+/// use num::Complex;
+/// use std::collections::HashSet;
+///
+/// let roots = [
+///     Complex::new(1.0000001, 0.0),
+///     Complex::new(1.0, 0.0),
+///     Complex::new(2.0, 1.0),
+///     Complex::new(2.0, -1.0),
+///     Complex::new(2.0, 1.0 - 1e-9),
+/// ];
+/// let distinct: HashSet<RootKey> = roots.iter().map(|z| RootKey::new(*z, 1e-5)).collect();
+///
+/// assert_eq!(distinct.len(), 3);
+/// assert!(distinct.contains(&RootKey::new(Complex::new(1.0, 0.0), 1e-5)));
+/// // -0.0 and 0.0 share a cell
+/// let origin = RootKey::new(Complex::new(0.0, 0.0), 1e-5);
+/// assert_eq!(RootKey::new(Complex::new(-1e-9, 0.0), 1e-5), origin);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RootKey {
+    pub root: Complex<f64>,
+    pub tol: f64,
+}
+
+impl RootKey {
+    /// The `new` function wraps `root` with the grid spacing `tol`.
+    pub fn new(root: Complex<f64>, tol: f64) -> Self {
+        RootKey { root, tol }
+    }
+
+    /// The `cell` function returns the bit patterns of the grid cell coordinates. Adding `0.0`
+    /// maps a rounded `-0.0` to `0.0`, so both sides of the origin share a cell.
+    fn cell(&self) -> (u64, u64) {
+        let snap = |v: f64| ((v / self.tol).round() + 0.0).to_bits();
+        (snap(self.root.re), snap(self.root.im))
+    }
+}
+
+impl PartialEq for RootKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cell() == other.cell()
+    }
+}
+
+impl Eq for RootKey {}
+
+impl Hash for RootKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.cell().hash(state);
+    }
+}
+
+/// Integer polynomial of an approximate algebraic number
+///
+/// The `minimal_polynomial` function searches for the integer relation `c_0 + c_1 a + ... +
+/// c_d a^d = 0` of lowest degree `d <= max_degree` that `alpha` satisfies within `tol`. For every
+/// degree it LLL-reduces the lattice spanned by the rows `(e_k, C Re(a^k), C Im(a^k))` with
+/// `C = 1 / tol`: a short vector has small integer coefficients and a small `C |P(a)|`. A
+/// candidate is accepted when `|P(a)| <= tol` and when it is significant: with coefficients up to
+/// `h` in magnitude there are `(2h + 1)^(d+1)` candidate relations, and once that count reaches
+/// `1 / tol` the pigeonhole principle yields one within `tol` of zero for *any* number, so such a
+/// relation proves nothing. The count must stay below `0.1 / tol`. `tol` should sit well above
+/// the accuracy of `alpha`, about `eps * |a|^d` times the size of the coefficients, and well below
+/// one.
+///
+/// Arguments:
+///
+/// * `alpha`: The approximate algebraic number.
+/// * `max_degree`: The highest degree to try.
+/// * `tol`: The tolerance of the relation.
+///
+/// Returns:
+///
+/// The function `minimal_polynomial` returns the primitive integer coefficients, ordered from
+/// highest degree to lowest degree with a positive leading coefficient, or `None` when no
+/// relation of degree up to `max_degree` holds within `tol`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::roots::minimal_polynomial;
+/// use num::Complex;
+///
+/// let alpha = Complex::new(2.0f64.sqrt(), 0.0);
+///
+/// assert_eq!(minimal_polynomial(alpha, 4, 1e-10), Some(vec![1, 0, -2]));
+/// ```
+pub fn minimal_polynomial(alpha: Complex<f64>, max_degree: usize, tol: f64) -> Option<Vec<i64>> {
+    if !alpha.is_finite() || tol.is_nan() || tol <= 0.0 {
+        return None;
+    }
+    let scale = 1.0 / tol;
+    for degree in 1..=max_degree {
+        let mut power = Complex::new(1.0, 0.0);
+        let mut basis: Vec<Vec<f64>> = (0..=degree)
+            .map(|k| {
+                let mut row = vec![0.0; degree + 3];
+                row[k] = 1.0;
+                row[degree + 1] = scale * power.re;
+                row[degree + 2] = scale * power.im;
+                power *= alpha;
+                row
+            })
+            .collect();
+        lll_reduce(&mut basis);
+        for row in basis.iter() {
+            if row[degree] == 0.0 || row[..=degree].iter().any(|c| c.abs() >= 9.0e15) {
+                continue;
+            }
+            // `row[k]` multiplies `alpha^k`; the relation is evaluated from the top
+            let residual = row[..=degree]
+                .iter()
+                .rev()
+                .fold(Complex::new(0.0, 0.0), |acc, c| acc * alpha + c);
+            let height = row[..=degree].iter().fold(0.0, |h: f64, c| h.max(c.abs()));
+            let significant =
+                (degree + 1) as f64 * (2.0 * height + 1.0).log10() < -1.0 - tol.log10();
+            if residual.norm() <= tol && significant {
+                let mut coeffs: Vec<i64> = row[..=degree].iter().rev().map(|&c| c as i64).collect();
+                let g = coeffs.iter().fold(0, |g, &c| gcd_i64(g, c));
+                let sign = coeffs[0].signum();
+                for c in coeffs.iter_mut() {
+                    *c = sign * *c / g;
+                }
+                return Some(coeffs);
+            }
+        }
+    }
+    None
+}
+
+/// The `gcd_i64` function returns the non-negative greatest common divisor.
+fn gcd_i64(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The `lll_reduce` function LLL-reduces the rows of `basis` in place with `delta = 3/4`,
+/// recomputing the Gram-Schmidt orthogonalization after every change. That costs `O(n^4)` but
+/// keeps the code short for the small lattices of `minimal_polynomial`.
+fn lll_reduce(basis: &mut [Vec<f64>]) {
+    let n = basis.len();
+    let dot = |u: &[f64], v: &[f64]| u.iter().zip(v.iter()).map(|(a, b)| a * b).sum::<f64>();
+    let gram_schmidt = |basis: &[Vec<f64>]| {
+        let mut ortho: Vec<Vec<f64>> = Vec::with_capacity(basis.len());
+        let mut mu = vec![vec![0.0; basis.len()]; basis.len()];
+        for (i, b) in basis.iter().enumerate() {
+            let mut v = b.clone();
+            for (j, o) in ortho.iter().enumerate() {
+                let m = dot(b, o) / dot(o, o);
+                mu[i][j] = m;
+                for (vk, ok) in v.iter_mut().zip(o.iter()) {
+                    *vk -= m * ok;
+                }
+            }
+            ortho.push(v);
+        }
+        (ortho, mu)
+    };
+    let (mut ortho, mut mu) = gram_schmidt(basis);
+    let mut k = 1;
+    let mut rounds = 0;
+    while k < n && rounds < 1000 * n * n {
+        rounds += 1;
+        for j in (0..k).rev() {
+            let q = mu[k][j].round();
+            if q != 0.0 {
+                let bj = basis[j].clone();
+                for (a, b) in basis[k].iter_mut().zip(bj.iter()) {
+                    *a -= q * b;
+                }
+                (ortho, mu) = gram_schmidt(basis);
+            }
+        }
+        let lhs = dot(&ortho[k], &ortho[k]);
+        let rhs = (0.75 - mu[k][k - 1] * mu[k][k - 1]) * dot(&ortho[k - 1], &ortho[k - 1]);
+        if lhs >= rhs {
+            k += 1;
+        } else {
+            basis.swap(k, k - 1);
+            (ortho, mu) = gram_schmidt(basis);
+            k = (k - 1).max(1);
+        }
+    }
+}
+
+/// How far from the unit circle `spectral_factor` still takes a root to be on it: the square
+/// root of the `1e-12` relative accuracy of a simple root, which is the accuracy of a double one.
+const CIRCLE_BAND: f64 = 1e-6;
+
+/// Spectral factorization
+///
+/// The `spectral_factor` function factors a polynomial that is non-negative on the unit circle,
+/// `S(z) = z^-m P(z) = H(z) H(1/z)` with `P` of degree `2 m` and palindromic coefficients, into its
+/// minimum-phase factor `H` of degree `m`. The roots of `P` come in reciprocal pairs `(r, 1/r*)`,
+/// and `H` keeps the one inside the unit circle of each. A root on the circle is its own partner
+/// and is double. A double root is only about half as accurate as a simple one, so the two
+/// estimates it splits into lie within `CIRCLE_BAND` of the circle; they are paired by angle and
+/// replaced by their mean moved onto the circle. `H` is rebuilt with `from_roots` and
+/// scaled by the `c > 0` that makes `c^2 H(z) z^m H(1/z)` the least-squares fit of `P`.
+///
+/// Arguments:
+///
+/// * `coeffs`: The polynomial `P`, ordered from highest degree to lowest degree.
+/// * `options`: The stopping criteria passed on to `find_roots`.
+///
+/// Returns:
+///
+/// The function `spectral_factor` returns the `m + 1` coefficients of `H`, ordered from highest
+/// degree to lowest degree, or an empty vector when `P` has odd degree or is not non-negative on
+/// the circle: the roots do not pair up, or the fit needs `c^2 <= 0`. It returns the error of
+/// `poly::degree` for invalid coefficients, where a non-zero constant is valid, and the error of
+/// `find_roots` when `P` cannot be solved.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::roots::spectral_factor;
+/// use bairstow::rootfinding::Options;
+///
+/// // H(z) = 2 z - 1, so z P(1/z) = (2 z - 1)(2 - z)
+/// let h = spectral_factor(&[-2.0, 5.0, -2.0], &Options::default()).unwrap();
+///
+/// assert_eq!(h.len(), 2);
+/// assert!((h[0] - 2.0).abs() < 1e-12 && (h[1] + 1.0).abs() < 1e-12);
+/// ```
+pub fn spectral_factor(coeffs: &[f64], options: &Options) -> Result<Vec<f64>, RootError> {
+    // a constant is checked by find_roots and factors into its square root
+    if coeffs.len() != 1 {
+        crate::poly::degree(coeffs)?;
+    }
+    if coeffs.len().is_multiple_of(2) {
+        return Ok(vec![]);
+    }
+    let zs = find_roots(coeffs, options)?;
+    let m = zs.len() / 2;
+    let mut inside: Vec<Complex<f64>> = zs
+        .iter()
+        .filter(|z| z.norm() < 1.0 - CIRCLE_BAND)
+        .copied()
+        .collect();
+    let mut circle: Vec<Complex<f64>> = zs
+        .iter()
+        .filter(|z| (z.norm() - 1.0).abs() <= CIRCLE_BAND)
+        .copied()
+        .collect();
+    if !circle.len().is_multiple_of(2) || inside.len() + circle.len() / 2 != m {
+        return Ok(vec![]);
+    }
+    // pair neighbors in angle, cyclically, as a double root at -1 splits across the cut
+    circle.sort_by(|a, b| a.arg().total_cmp(&b.arg()));
+    let gap = |offset: usize| {
+        (0..circle.len() / 2)
+            .map(|i| {
+                let a = circle[(2 * i + offset) % circle.len()];
+                (a - circle[(2 * i + offset + 1) % circle.len()]).norm()
+            })
+            .fold(0.0, f64::max)
+    };
+    let offset = usize::from(circle.len() > 2 && gap(1) < gap(0));
+    for i in 0..circle.len() / 2 {
+        let a = circle[(2 * i + offset) % circle.len()];
+        let mean = a + circle[(2 * i + offset + 1) % circle.len()];
+        inside.push(mean / mean.norm());
+    }
+
+    let h = crate::poly::from_roots(&inside);
+    // H(z) z^m H(1/z) is the product of h with its reversal
+    let mut fit = vec![0.0; 2 * m + 1];
+    for (i, a) in h.iter().enumerate() {
+        for (j, b) in h.iter().rev().enumerate() {
+            fit[i + j] += a * b;
+        }
+    }
+    let dot = |u: &[f64], v: &[f64]| u.iter().zip(v).map(|(a, b)| a * b).sum::<f64>();
+    let scale = dot(coeffs, &fit) / dot(&fit, &fit);
+    if scale.is_nan() || scale <= 0.0 || scale.is_infinite() {
+        return Ok(vec![]);
+    }
+    Ok(h.iter().map(|a| a * scale.sqrt()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiplicities() {
+        // (x - 1)^2 (x - 2)^3
+        let coeffs = [1.0, -8.0, 25.0, -38.0, 28.0, -8.0];
+        let options = Options::default();
+        let found = multiplicities(&coeffs, &options, 1e-3).unwrap();
+        assert_eq!(found.len(), 2);
+        for ((c, m), (root, mult)) in found.iter().zip([(1.0, 2), (2.0, 3)]) {
+            // the residual tolerance 1e-12 pins an m-fold root down to about 1e-12^(1/m)
+            assert!((c - root).norm() < 1e-4);
+            assert_eq!(*m, mult);
+        }
+
+        // far below the eps^(1/3) spread of the triple root: its estimates are not multiple roots
+        assert!(matches!(
+            multiplicities(&coeffs, &options, 1e-12),
+            Err(MultiplicityError::DegreeMismatch { degree: 5, .. })
+        ));
+        assert_eq!(
+            multiplicities(&[1.0], &options, 1e-3),
+            Err(MultiplicityError::Root(
+                crate::RootError::ConstantPolynomial
+            ))
+        );
+    }
+
+    #[test]
+    fn test_pole_zero() {
+        // B = (z - 0.8)(z^2 + 0.81), A = (z - 0.8)(z + 0.3)(z^2 - z + 0.5)
+        let num = [1.0, -0.8, 0.81, -0.648];
+        let den = [1.0, -1.5, 0.76, -0.01, -0.12];
+        let options = Options {
+            zero_threshold: 1e-8,
+            ..Options::default()
+        };
+        let pz = pole_zero(&num, &den, &options).unwrap();
+        assert_eq!((pz.zeros.len(), pz.poles.len()), (3, 4));
+        assert_eq!(pz.cancellations.len(), 1);
+        let (i, j) = pz.cancellations[0];
+        assert!((pz.zeros[i] - Complex::new(0.8, 0.0)).norm() < 1e-10);
+        assert!((pz.poles[j] - Complex::new(0.8, 0.0)).norm() < 1e-10);
+
+        // nothing cancels against distinct roots
+        let pz = pole_zero(&num, &[1.0, 0.3], &options).unwrap();
+        assert!(pz.cancellations.is_empty());
+        assert_eq!(
+            pole_zero(&num, &[0.0, 0.0], &options),
+            Err(RootError::ZeroPolynomial)
+        );
+    }
+
+    #[test]
+    fn test_format_parse_root() {
+        let roots = [
+            Complex::new(2.5, -1.3),
+            Complex::new(-0.1, 1.0 / 3.0),
+            Complex::new(1e-20, -6.02e23),
+            Complex::new(-7.0, 0.0),
+            Complex::new(0.0, -2.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(-0.0, 1e300),
+        ];
+        for z in roots.iter() {
+            assert_eq!(parse_root(&format_root(z)), Ok(*z));
+        }
+        assert_eq!(format_root(&roots[0]), "2.5 - 1.3i");
+        assert!(parse_root("1 + 2j").is_err());
+        assert!(parse_root("").is_err());
+    }
+
+    #[test]
+    fn test_root_key() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |key: &RootKey| {
+            let mut hasher = DefaultHasher::new();
+            key.hash(&mut hasher);
+            hasher.finish()
+        };
+        let key = |re: f64, im: f64| RootKey::new(Complex::new(re, im), 1e-6);
+
+        // equal keys hash alike, on both sides of the origin in either part
+        let origin = key(0.0, 0.0);
+        for (re, im) in [
+            (-0.0, 0.0),
+            (0.0, -0.0),
+            (-0.0, -0.0),
+            (-1e-9, 1e-9),
+            (4e-7, -4e-7),
+        ] {
+            assert_eq!(key(re, im), origin);
+            assert_eq!(hash(&key(re, im)), hash(&origin));
+        }
+        assert_ne!(key(6e-7, 0.0), origin);
+        assert_ne!(key(-6e-7, 0.0), origin);
+        assert_ne!(key(6e-7, 0.0), key(-6e-7, 0.0));
+
+        // unlike f64, a NaN key equals itself, so it can be found again in a set
+        let nan = key(f64::NAN, 1.0);
+        assert_eq!(nan, nan);
+        assert_eq!(hash(&nan), hash(&key(f64::NAN, 1.0)));
+        assert_ne!(nan, key(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_minimal_polynomial() {
+        let golden = (1.0 + 5.0f64.sqrt()) / 2.0;
+        let cases = [
+            (Complex::new(2.0f64.sqrt(), 0.0), vec![1, 0, -2]),
+            (Complex::new(golden, 0.0), vec![1, -1, -1]),
+            (Complex::new(2.0f64.cbrt(), 0.0), vec![1, 0, 0, -2]),
+            (Complex::new(0.75, 0.0), vec![4, -3]),
+            (Complex::new(0.0, 1.0), vec![1, 0, 1]),
+            // a primitive cube root of unity: x^2 + x + 1
+            (Complex::new(-0.5, 3.0f64.sqrt() / 2.0), vec![1, 1, 1]),
+            // 1 + sqrt(3): x^2 - 2x - 2
+            (Complex::new(1.0 + 3.0f64.sqrt(), 0.0), vec![1, -2, -2]),
+        ];
+        for (alpha, expected) in cases {
+            assert_eq!(minimal_polynomial(alpha, 4, 1e-10), Some(expected));
+        }
+        // pi and e are transcendental
+        assert_eq!(
+            minimal_polynomial(Complex::new(std::f64::consts::PI, 0.0), 4, 1e-10),
+            None
+        );
+        assert_eq!(
+            minimal_polynomial(Complex::new(std::f64::consts::E, 0.0), 4, 1e-10),
+            None
+        );
+        // x^6 - 34 x^5 + ... + 10 is within 1e-8 of pi, but so is some sextic with coefficients
+        // that small for any number
+        assert_eq!(
+            minimal_polynomial(Complex::new(std::f64::consts::PI, 0.0), 8, 1e-8),
+            None
+        );
+        // the degree is capped
+        assert_eq!(
+            minimal_polynomial(Complex::new(2.0f64.cbrt(), 0.0), 2, 1e-10),
+            None
+        );
+    }
+
+    #[test]
+    fn test_spectral_factor() {
+        // P(z) = z^m H(z) H(1/z) of the minimum-phase H
+        let spectrum = |h: &[f64]| {
+            let mut p = vec![0.0; 2 * h.len() - 1];
+            for (i, a) in h.iter().enumerate() {
+                for (j, b) in h.iter().rev().enumerate() {
+                    p[i + j] += a * b;
+                }
+            }
+            p
+        };
+        let options = Options::default();
+        let factor = |h: &[f64], options: &Options| spectral_factor(&spectrum(h), options).unwrap();
+        let close = |found: &[f64], h: &[f64], tol: f64| {
+            found.len() == h.len() && found.iter().zip(h).all(|(a, b)| (a - b).abs() < tol)
+        };
+
+        // 3 (z - 0.5)(z + 0.25)(z^2 - 0.6 z + 0.25): all roots strictly inside
+        let h = [3.0, -2.55, 0.825, 0.0375, -0.09375];
+        // the mirrored roots 2 and 4 keep the residual just above 1e-12
+        let loose = Options {
+            tol: 1e-10,
+            ..Options::default()
+        };
+        let found = factor(&h, &loose);
+        assert!(close(&found, &h, 1e-10), "{found:?}");
+
+        // (z + 1)(z - 0.4) and (z^2 + 1)(z - 0.5): double roots on the circle, at -1 and at +-i
+        for h in [vec![1.0, 0.6, -0.4], vec![1.0, -0.5, 1.0, -0.5]] {
+            let found = factor(&h, &options);
+            assert!(close(&found, &h, 1e-6), "{found:?}");
+        }
+
+        assert_eq!(spectral_factor(&[4.0], &options), Ok(vec![2.0]));
+        // odd degree, and z^2 - 3 z + 1 which is negative at z = 1
+        assert_eq!(
+            spectral_factor(&[1.0, -3.0, 1.0, 2.0], &options),
+            Ok(vec![])
+        );
+        assert_eq!(spectral_factor(&[1.0, -3.0, 1.0], &options), Ok(vec![]));
+        assert_eq!(
+            spectral_factor(&[0.0, 1.0, 1.0], &options),
+            Err(RootError::LeadingZero)
+        );
+    }
+}
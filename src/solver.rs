@@ -0,0 +1,298 @@
+use crate::aberth::{
+    aberth, aberth_mt, horner_eval_c, initial_aberth, initial_aberth_annuli, initial_random,
+    nan_max, solve_by_deflation, solve_quadratic_cascade, zero_root_multiplicity,
+};
+use crate::error::RootError;
+use crate::poly::deflate_quadratic;
+use crate::rootfinding::{bairstow, initial_guess, Options};
+use num::Complex;
+
+/// The `Method` enum selects the iteration `SolverConfig::solve` runs.
+///
+/// Variants:
+///
+/// * `Aberth`: Aberth's method with Gauss-Seidel sweeps, see `aberth::aberth`.
+/// * `AberthMt`: Aberth's method with the sweeps split over threads, see `aberth::aberth_mt`. It
+///   always sweeps from a snapshot, whatever `options.scheme` says, and rejects the options it
+///   does not implement: `high_degree`, `auto_respread` and `interaction_neighbors`.
+/// * `Bairstow`: Bairstow's method with deflation: `rootfinding::bairstow` refines one quadratic
+///   factor at a time, seeded with `rootfinding::initial_guess` of what is left, and
+///   `poly::deflate_quadratic` divides it out. An odd degree leaves a final linear factor. The
+///   deflation error accumulates, so the last roots are the least accurate.
+/// * `Deflation`: Successive deflation polished by Aberth's method, see
+///   `aberth::solve_by_deflation`. It computes its own estimates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Method {
+    #[default]
+    Aberth,
+    AberthMt,
+    Bairstow,
+    Deflation,
+}
+
+/// The `Seeding` enum selects the initial guesses of the Aberth methods.
+///
+/// Variants:
+///
+/// * `Circle`: Evenly spread on one circle around the centroid, see `aberth::initial_aberth`.
+/// * `Annuli`: Spread on circles sized by the Newton polygon, see `aberth::initial_aberth_annuli`.
+/// * `Random`: Uniform in the Cauchy disk from the given seed, see `aberth::initial_random`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Seeding {
+    #[default]
+    Circle,
+    Annuli,
+    Random(u64),
+}
+
+/// The `SolverConfig` struct bundles the choice of method, the seeding and the stopping criteria
+/// into one entry point. It is built by chaining the setters on `SolverConfig::new()`, whose
+/// defaults (`Method::Aberth`, `Seeding::Circle` and `Options::default()`) match
+/// `aberth::find_roots` without the Graeffe fallback.
+///
+/// Properties:
+///
+/// * `method`: The iteration to run.
+/// * `seeding`: The initial guesses of `Method::Aberth` and `Method::AberthMt`; the other methods
+///   compute their own.
+/// * `options`: The stopping criteria passed on to the method.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::solver::{Method, Seeding, SolverConfig};
+///
+/// let coeffs = vec![10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+/// let zs = SolverConfig::new()
+///     .method(Method::Aberth)
+///     .seeding(Seeding::Annuli)
+///     .solve(&coeffs)
+///     .unwrap();
+///
+/// assert_eq!(zs.len(), 8);
+/// ```
+#[derive(Debug, Default)]
+pub struct SolverConfig {
+    pub method: Method,
+    pub seeding: Seeding,
+    pub options: Options,
+}
+
+impl SolverConfig {
+    /// The `new` function returns the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The `method` function sets the iteration to run.
+    pub fn method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// The `seeding` function sets the initial guesses of the Aberth methods.
+    pub fn seeding(mut self, seeding: Seeding) -> Self {
+        self.seeding = seeding;
+        self
+    }
+
+    /// The `options` function sets the stopping criteria.
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// The `solve` function finds the roots of `coeffs` with the configured method. As in
+    /// `aberth::find_roots`, trailing coefficients below `options.zero_threshold` relative to the
+    /// largest one are roots at zero, which are split off before the iteration and appended after
+    /// it.
+    ///
+    /// Arguments:
+    ///
+    /// * `coeffs`: A slice of coefficients ordered from highest degree to lowest degree.
+    ///
+    /// Returns:
+    ///
+    /// The function `solve` returns one root per degree, the error of `poly::degree`,
+    /// `RootError::UnsupportedOption` when the method would ignore a field of `options`, or
+    /// `RootError::NotConverged` when the method does not converge.
+    pub fn solve(&self, coeffs: &[f64]) -> Result<Vec<Complex<f64>>, RootError> {
+        crate::poly::degree(coeffs)?;
+        self.check_options()?;
+        let m = zero_root_multiplicity(coeffs, self.options.zero_threshold);
+        let reduced = &coeffs[..coeffs.len() - m];
+        let mut zs = if reduced.len() > 1 {
+            self.solve_nonzero(reduced)?
+        } else {
+            vec![]
+        };
+        zs.resize(zs.len() + m, Complex::new(0.0, 0.0));
+        Ok(zs)
+    }
+
+    /// The `check_options` function rejects the fields of `options` that the configured method
+    /// would silently ignore.
+    fn check_options(&self) -> Result<(), RootError> {
+        if self.method != Method::AberthMt {
+            return Ok(());
+        }
+        let options = &self.options;
+        for (option, set) in [
+            ("high_degree", options.high_degree),
+            ("auto_respread", options.auto_respread),
+            (
+                "interaction_neighbors",
+                options.interaction_neighbors.is_some(),
+            ),
+        ] {
+            if set {
+                return Err(RootError::UnsupportedOption {
+                    method: "aberth_mt",
+                    option,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// The `solve_nonzero` function runs the configured method on a polynomial without zero roots.
+    fn solve_nonzero(&self, coeffs: &[f64]) -> Result<Vec<Complex<f64>>, RootError> {
+        match self.method {
+            Method::Aberth | Method::AberthMt => {
+                let mut zs = match self.seeding {
+                    Seeding::Circle => initial_aberth(coeffs)?,
+                    Seeding::Annuli => initial_aberth_annuli(coeffs)?,
+                    Seeding::Random(seed) => initial_random(coeffs, seed)?,
+                };
+                let (niter, found) = if self.method == Method::Aberth {
+                    aberth(coeffs, &mut zs, &self.options)
+                } else {
+                    aberth_mt(coeffs, &mut zs, &self.options)
+                };
+                if !found {
+                    return Err(not_converged(coeffs, &zs, niter));
+                }
+                Ok(zs)
+            }
+            Method::Bairstow => {
+                let mut rest = coeffs.to_vec();
+                let mut zs = Vec::with_capacity(coeffs.len() - 1);
+                while rest.len() > 2 {
+                    let mut vr = initial_guess(&rest)?[0];
+                    let (niter, found) = bairstow(&rest, &mut vr, &self.options);
+                    let pair = solve_quadratic_cascade(&[[1.0, -vr.x_, -vr.y_]]);
+                    if !found {
+                        return Err(not_converged(&rest, &pair, niter));
+                    }
+                    zs.extend(pair);
+                    rest = deflate_quadratic(&rest, &vr);
+                }
+                if rest.len() == 2 {
+                    zs.push(Complex::new(-rest[1] / rest[0], 0.0));
+                }
+                Ok(zs)
+            }
+            Method::Deflation => solve_by_deflation(coeffs, &self.options),
+        }
+    }
+}
+
+/// The `not_converged` function reports the largest residual of the estimates `zs` of `coeffs`
+/// after `niter` iterations as `RootError::NotConverged`.
+fn not_converged(coeffs: &[f64], zs: &[Complex<f64>], niter: usize) -> RootError {
+    let tol = zs
+        .iter()
+        .map(|z| horner_eval_c(coeffs, z).l1_norm())
+        .fold(0.0, nan_max);
+    RootError::NotConverged { niter, tol }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solver_config() {
+        // (x^2 + 1)(x - 2)(x + 3)(x - 0.5) x^2
+        let coeffs = [1.0, 0.5, -5.5, 3.5, -6.5, 3.0, 0.0, 0.0];
+        for method in [
+            Method::Aberth,
+            Method::AberthMt,
+            Method::Bairstow,
+            Method::Deflation,
+        ] {
+            for seeding in [Seeding::Circle, Seeding::Annuli, Seeding::Random(7)] {
+                let zs = SolverConfig::new()
+                    .method(method)
+                    .seeding(seeding)
+                    .solve(&coeffs)
+                    .unwrap();
+                assert_eq!(zs.len(), 7);
+                assert_eq!(zs[5..], [Complex::new(0.0, 0.0); 2]);
+                for z in zs.iter() {
+                    assert!(horner_eval_c(&coeffs, z).norm() < 1e-10, "{method:?} {z}");
+                }
+            }
+        }
+        assert_eq!(
+            SolverConfig::new().solve(&[0.0, 1.0]),
+            Err(RootError::LeadingZero)
+        );
+
+        // a budget too small to converge is an error for every method
+        for method in [
+            Method::Aberth,
+            Method::AberthMt,
+            Method::Bairstow,
+            Method::Deflation,
+        ] {
+            let found = SolverConfig::new()
+                .method(method)
+                .options(Options {
+                    max_iters: 1,
+                    ..Options::default()
+                })
+                .solve(&coeffs);
+            assert!(
+                matches!(found, Err(RootError::NotConverged { .. })),
+                "{method:?}"
+            );
+        }
+
+        // the threaded sweeps reject what they would ignore
+        let unsupported = [
+            (
+                "high_degree",
+                Options {
+                    high_degree: true,
+                    ..Options::default()
+                },
+            ),
+            (
+                "auto_respread",
+                Options {
+                    auto_respread: true,
+                    ..Options::default()
+                },
+            ),
+            (
+                "interaction_neighbors",
+                Options {
+                    interaction_neighbors: Some(3),
+                    ..Options::default()
+                },
+            ),
+        ];
+        for (option, options) in unsupported {
+            let config = SolverConfig::new().options(options);
+            assert_eq!(config.solve(&coeffs).map(|zs| zs.len()), Ok(7));
+            assert_eq!(
+                config.method(Method::AberthMt).solve(&coeffs),
+                Err(RootError::UnsupportedOption {
+                    method: "aberth_mt",
+                    option
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use num::Complex;
+
+/// Compare two root sets up to order
+///
+/// The `roots_approx_eq` function pairs every root of `a` greedily with the nearest root of `b`
+/// that is still unpaired and within `tol * max(1, |z|)` of it, so the tolerance is absolute for
+/// small roots and relative for large ones. The sets are equal when every root of `a` finds a
+/// partner and no root of `b` is left over, whatever the order of either slice. Requires the
+/// `test-util` feature.
+///
+/// Arguments:
+///
+/// * `a`: The roots to match, e.g. the output of `find_roots`.
+/// * `b`: The expected roots.
+/// * `tol`: The matching tolerance.
+///
+/// Returns:
+///
+/// The function `roots_approx_eq` returns `true` when the sets match within `tol`.
+///
+/// # Examples:
+///
+/// ```
+/// use bairstow::aberth::find_roots;
+/// use bairstow::rootfinding::Options;
+/// use bairstow::testutil::roots_approx_eq;
+/// use num::Complex;
+///
+/// // (x - 1)(x - 2)(x - 4)
+/// let zs = find_roots(&[1.0, -7.0, 14.0, -8.0], &Options::default()).unwrap();
+/// let expected = [4.0, 1.0, 2.0].map(|r| Complex::new(r, 0.0));
+///
+/// assert!(roots_approx_eq(&zs, &expected, 1e-10));
+/// ```
+pub fn roots_approx_eq(a: &[Complex<f64>], b: &[Complex<f64>], tol: f64) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut paired = vec![false; b.len()];
+    for z in a.iter() {
+        let radius = tol * z.norm().max(1.0);
+        let nearest = b
+            .iter()
+            .enumerate()
+            .filter(|(j, w)| !paired[*j] && (z - *w).norm() <= radius)
+            .min_by(|u, v| (z - u.1).norm().total_cmp(&(z - v.1).norm()));
+        match nearest {
+            Some((j, _)) => paired[j] = true,
+            None => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roots_approx_eq() {
+        let roots = [
+            Complex::new(1.0, 2.0),
+            Complex::new(1.0, -2.0),
+            Complex::new(-3.0, 0.0),
+            Complex::new(1e6, 0.0),
+        ];
+        let mut shuffled = [roots[3], roots[1], roots[0], roots[2]];
+        shuffled[0] += 1e-4;
+        shuffled[2] += Complex::new(0.0, 1e-11);
+        assert!(roots_approx_eq(&roots, &shuffled, 1e-9));
+        assert!(roots_approx_eq(&shuffled, &roots, 1e-9));
+        assert!(!roots_approx_eq(&roots, &shuffled, 1e-12));
+
+        // a root missing, or replaced by a duplicate of another one
+        assert!(!roots_approx_eq(&roots[..3], &shuffled, 1e-9));
+        assert!(!roots_approx_eq(&shuffled, &roots[..3], 1e-9));
+        let doubled = [roots[0], roots[0], roots[2], roots[3]];
+        assert!(!roots_approx_eq(&doubled, &roots, 1e-9));
+        assert!(!roots_approx_eq(&roots, &doubled, 1e-9));
+    }
+}
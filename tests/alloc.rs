@@ -0,0 +1,132 @@
+use bairstow::aberth::{aberth_into, initial_aberth_slice, StopReason};
+use bairstow::rootfinding::{IterationScheme, Options};
+use num::Complex;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|n| n.get())
+}
+
+#[test]
+fn test_aberth_into_no_alloc() {
+    // (x^2 + 1)(x^2 - 3x + 2): roots i, -i, 1, 2
+    let coeffs = [1.0, -3.0, 3.0, -3.0, 2.0];
+    let mut zs = [
+        Complex::new(3.0, 0.5),
+        Complex::new(-0.5, 2.0),
+        Complex::new(-2.0, -1.0),
+        Complex::new(0.5, -2.5),
+    ];
+    let mut pb = [0.0; 4];
+    let mut converged = [false; 4];
+    let options = Options::default();
+
+    let before = allocations();
+    let result = aberth_into(&coeffs, &mut zs, &mut pb, &mut converged, &mut [], &options);
+    let after = allocations();
+
+    assert_eq!(after, before);
+    let (_, reason) = result.unwrap();
+    assert_eq!(reason, StopReason::Converged);
+    for z in zs.iter() {
+        let p = coeffs
+            .iter()
+            .fold(Complex::new(0.0, 0.0), |acc, c| acc * z + c);
+        assert!(p.norm() < 1e-10);
+    }
+}
+
+#[test]
+fn test_seeded_pipeline_no_alloc() {
+    // (x - 1)(x - 2)(x - 4)(x - 5)
+    let coeffs = [1.0, -12.0, 49.0, -78.0, 40.0];
+    let mut zs = [Complex::new(0.0, 0.0); 4];
+    let mut pb = [0.0; 4];
+    let mut converged = [false; 4];
+    let options = Options::default();
+
+    let before = allocations();
+    let seeded = initial_aberth_slice(&coeffs, &mut zs);
+    let result = aberth_into(&coeffs, &mut zs, &mut pb, &mut converged, &mut [], &options);
+    let after = allocations();
+
+    assert_eq!(after, before);
+    seeded.unwrap();
+    assert_eq!(result.unwrap().1, StopReason::Converged);
+}
+
+#[test]
+fn test_fixed_roots_no_alloc() {
+    use bairstow::roots::Roots;
+
+    // (x^2 + 1)(x^2 - 3x + 2): roots i, -i, 1, 2
+    let coeffs = [1.0, -3.0, 3.0, -3.0, 2.0];
+    for scheme in [IterationScheme::GaussSeidel, IterationScheme::Jacobi] {
+        let options = Options::default().scheme(scheme);
+
+        let before = allocations();
+        let solved = Roots::<4>::solve(&coeffs, &options);
+        let after = allocations();
+
+        assert_eq!(after, before);
+        let (roots, reason) = solved.unwrap();
+        assert_eq!(reason, StopReason::Converged);
+        assert_eq!(roots.len(), 4);
+        for r in [
+            Complex::new(0.0, 1.0),
+            Complex::new(0.0, -1.0),
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 0.0),
+        ] {
+            assert!(roots.as_slice().iter().any(|z| (z - r).norm() < 1e-10));
+        }
+    }
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_smallvec_scratch_no_alloc() {
+    use bairstow::aberth::{aberth_detailed, initial_aberth, initial_aberth_small};
+
+    let coeffs = [10.0, 34.0, 75.0, 94.0, 150.0, 94.0, 75.0, 34.0, 10.0];
+    let options = Options::default();
+
+    let before = allocations();
+    let mut zs = initial_aberth_small(&coeffs).unwrap();
+    let result = aberth_detailed(&coeffs, &mut zs, &options);
+    let after = allocations();
+
+    // the scratch stays inline; only `AberthResult::converged_at` is returned on the heap
+    assert_eq!(after, before + 1);
+    assert_eq!(result.reason, StopReason::Converged);
+    // the heap-backed guesses give the same roots
+    let mut expected = initial_aberth(&coeffs).unwrap();
+    assert_eq!(aberth_detailed(&coeffs, &mut expected, &options), result);
+    assert_eq!(zs.to_vec(), expected);
+}
@@ -0,0 +1,146 @@
+use bairstow::aberth::{
+    aberth, aberth_detailed, aberth_mt, critical_values, derivative_root_tower, distinct_roots,
+    find_roots, find_roots_checked, find_roots_complex, find_roots_factored, horner_eval_c,
+    horner_eval_f, imaginary_axis_roots, initial_aberth, initial_aberth_annuli, initial_aberth_par,
+    initial_aberth_slice, initial_aberth_with, initial_from_approx, initial_random,
+    interlace_bounds, nearest_root, poly_condition, respread_unconverged, root_gradients,
+    solve_by_deflation_traced,
+};
+use bairstow::poly::{
+    cauchy_bound, count_real_roots, degree, discriminant, graeffe_step, poly_divmod, resultant,
+    Polynomial,
+};
+use bairstow::rootfinding::{
+    bairstow, initial_autocorr, initial_guess, pbairstow_autocorr, pbairstow_autocorr_mt,
+    pbairstow_even, pbairstow_even_mt, Options,
+};
+use bairstow::roots::{pole_zero, spectral_factor};
+use bairstow::{RootError, Vector2};
+use num::Complex;
+
+/// Every malformed input paired with the error it must produce.
+fn malformed() -> Vec<(Vec<f64>, RootError)> {
+    vec![
+        (vec![], RootError::Empty),
+        (vec![5.0], RootError::ConstantPolynomial),
+        (vec![0.0, 1.0, 2.0], RootError::LeadingZero),
+        (vec![1.0, f64::NAN], RootError::NonFinite),
+        (vec![1.0, f64::INFINITY, 2.0], RootError::NonFinite),
+    ]
+}
+
+#[test]
+fn test_malformed_seeding() {
+    for (coeffs, err) in malformed() {
+        assert_eq!(degree(&coeffs), Err(err.clone()));
+        assert_eq!(initial_aberth(&coeffs), Err(err.clone()));
+        assert_eq!(
+            initial_aberth_with(&coeffs, Some(1.0), 0.0),
+            Err(err.clone())
+        );
+        assert_eq!(initial_aberth_annuli(&coeffs), Err(err.clone()));
+        assert_eq!(initial_aberth_slice(&coeffs, &mut []), Err(err.clone()));
+        assert_eq!(initial_aberth_par(&coeffs), Err(err.clone()));
+        let options = Options::default();
+        assert_eq!(initial_from_approx(&coeffs, 1, &options), Err(err.clone()));
+        assert_eq!(
+            respread_unconverged(&coeffs, &mut [], &[]),
+            Err(err.clone())
+        );
+        assert_eq!(initial_random(&coeffs, 42), Err(err.clone()));
+        assert_eq!(initial_guess(&coeffs), Err(err.clone()));
+        assert_eq!(initial_autocorr(&coeffs), Err(err));
+    }
+}
+
+#[test]
+fn test_malformed_solvers() {
+    let options = Options::default();
+    for (coeffs, err) in malformed() {
+        // A non-zero constant has no roots rather than being an error for `find_roots`, and
+        // leading zeros are dropped.
+        let expected = match err {
+            RootError::ConstantPolynomial => Ok(vec![]),
+            RootError::LeadingZero => find_roots(&coeffs[1..], &options),
+            _ => Err(err.clone()),
+        };
+        assert_eq!(find_roots(&coeffs, &options), expected);
+        assert_eq!(find_roots_checked(&coeffs, &options), expected);
+        // The constant 5 is the spectrum of the constant factor sqrt(5).
+        let factor = match err {
+            RootError::ConstantPolynomial => Ok(vec![5.0_f64.sqrt()]),
+            _ => Err(err.clone()),
+        };
+        assert_eq!(spectral_factor(&coeffs, &options), factor);
+        let pz = pole_zero(&coeffs, &[1.0, -0.5], &options);
+        assert_eq!(pz.map(|pz| pz.zeros), expected);
+        assert_eq!(
+            solve_by_deflation_traced(&coeffs, &options),
+            Err(err.clone())
+        );
+        assert_eq!(derivative_root_tower(&coeffs, &options), Err(err.clone()));
+        assert_eq!(distinct_roots(&coeffs, &options), Err(err.clone()));
+        assert_eq!(interlace_bounds(&coeffs, &options), Err(err.clone()));
+        assert_eq!(
+            imaginary_axis_roots(&coeffs, 1e-10, &options),
+            Err(err.clone())
+        );
+        assert_eq!(
+            find_roots_factored(&[Polynomial(coeffs.clone())], &options),
+            Err(err.clone())
+        );
+        assert_eq!(poly_condition(&coeffs, &options), Err(err.clone()));
+        let z = Complex::new(0.5, 0.5);
+        assert_eq!(nearest_root(&coeffs, z, &options), Err(err.clone()));
+        assert_eq!(critical_values(&coeffs, &options), Err(err.clone()));
+        assert_eq!(root_gradients(&coeffs, &[z]), Err(err.clone()));
+        assert_eq!(discriminant(&coeffs), Err(err.clone()));
+        let complex: Vec<Complex<f64>> = coeffs.iter().map(|c| Complex::new(*c, 0.0)).collect();
+        assert_eq!(find_roots_complex(&complex, &options), Err(err));
+    }
+}
+
+#[test]
+fn test_malformed_kernels_do_not_panic() {
+    let options = Options::default().max_iters(10);
+    for (coeffs, _) in malformed() {
+        let z = Complex::new(0.5, 0.5);
+        horner_eval_f(&coeffs, 0.5);
+        horner_eval_c(&coeffs, &z);
+        aberth(&coeffs, &mut [z], &options);
+        aberth_detailed(&coeffs, &mut [z], &options);
+        aberth_mt(&coeffs, &mut [z], &options);
+
+        let vr = Vector2::new(1.0, -1.0);
+        bairstow(&coeffs, &mut vr.clone(), &options);
+        pbairstow_even(&coeffs, &mut vec![vr], &options);
+        pbairstow_even_mt(&coeffs, &mut vec![vr], &options);
+        pbairstow_autocorr(&coeffs, &mut vec![vr], &options);
+        pbairstow_autocorr_mt(&coeffs, &mut vec![vr], &options);
+
+        graeffe_step(&coeffs);
+        cauchy_bound(&coeffs);
+        count_real_roots(&coeffs);
+    }
+}
+
+#[test]
+fn test_degenerate_values() {
+    assert_eq!(horner_eval_f(&[], 2.0), 0.0);
+    assert_eq!(count_real_roots(&[5.0]), 0);
+    assert_eq!(count_real_roots(&[0.0, 0.0, 1.0, -1.0]), 1);
+    assert_eq!(resultant(&[], &[1.0, 2.0]), Ok(0.0));
+    assert_eq!(
+        resultant(&[1.0, 2.0], &[f64::INFINITY]),
+        Err(RootError::NonFinite)
+    );
+    assert!(graeffe_step(&[]).is_empty());
+    assert_eq!(
+        poly_divmod(&[1.0, 2.0], &[0.0, 0.0]),
+        Err(RootError::DivisionByZero)
+    );
+    // a quadratic is the smallest polynomial the parallel kernels refine
+    let mut vrs = vec![Vector2::new(1.0, -1.0)];
+    let (_, found) = pbairstow_even(&[1.0, -3.0, 2.0], &mut vrs, &Options::default());
+    assert!(found);
+}